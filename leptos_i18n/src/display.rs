@@ -10,6 +10,57 @@ pub trait DisplayComponent {
     fn fmt<T>(&self, f: &mut fmt::Formatter<'_>, children: T) -> fmt::Result
     where
         T: Fn(&mut fmt::Formatter<'_>) -> fmt::Result;
+
+    /// Same as `fmt` but also takes the attributes declared in the translation, `<a href="/about">`.
+    ///
+    /// The default implementation ignores the attributes.
+    fn fmt_with_attrs<T>(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        attrs: &[(&str, &str)],
+        children: T,
+    ) -> fmt::Result
+    where
+        T: Fn(&mut fmt::Formatter<'_>) -> fmt::Result,
+    {
+        let _ = attrs;
+        self.fmt(f, children)
+    }
+
+    /// Format a self-closing component, `<br/>`.
+    ///
+    /// The default implementation formats the component with no children.
+    fn fmt_self_closing(&self, f: &mut fmt::Formatter<'_>, attrs: &[(&str, &str)]) -> fmt::Result {
+        self.fmt_with_attrs(f, attrs, |_| Ok(()))
+    }
+}
+
+// Write the attributes declared in a translation, the values are escaped as they are not controlled by the code.
+fn write_attrs(f: &mut fmt::Formatter<'_>, attrs: &[(&str, &str)]) -> fmt::Result {
+    for (attr_name, attr) in attrs {
+        write!(f, " {}=\"", attr_name)?;
+        let mut rest = *attr;
+        while let Some(index) = rest.find(['"', '&', '<']) {
+            f.write_str(&rest[..index])?;
+            f.write_str(match rest.as_bytes()[index] {
+                b'"' => "&quot;",
+                b'&' => "&amp;",
+                _ => "&lt;",
+            })?;
+            rest = &rest[index + 1..];
+        }
+        f.write_str(rest)?;
+        f.write_str("\"")?;
+    }
+    Ok(())
+}
+
+// The attributes given by the code are written as is.
+fn write_raw_attrs(f: &mut fmt::Formatter<'_>, attrs: &[(&str, &str)]) -> fmt::Result {
+    for (attr_name, attr) in attrs {
+        write!(f, " {}=\"{}\"", attr_name, attr)?;
+    }
+    Ok(())
 }
 
 impl<F> DisplayComponent for F
@@ -29,10 +80,30 @@ impl DisplayComponent for &str {
     where
         T: Fn(&mut fmt::Formatter<'_>) -> fmt::Result,
     {
-        write!(f, "<{}>", self)?;
+        self.fmt_with_attrs(f, &[], children)
+    }
+
+    fn fmt_with_attrs<T>(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        attrs: &[(&str, &str)],
+        children: T,
+    ) -> fmt::Result
+    where
+        T: Fn(&mut fmt::Formatter<'_>) -> fmt::Result,
+    {
+        write!(f, "<{}", self)?;
+        write_attrs(f, attrs)?;
+        f.write_str(">")?;
         children(f)?;
         write!(f, "</{}>", self)
     }
+
+    fn fmt_self_closing(&self, f: &mut fmt::Formatter<'_>, attrs: &[(&str, &str)]) -> fmt::Result {
+        write!(f, "<{}", self)?;
+        write_attrs(f, attrs)?;
+        f.write_str(" />")
+    }
 }
 
 impl DisplayComponent for String {
@@ -43,6 +114,24 @@ impl DisplayComponent for String {
     {
         self.as_str().fmt(f, children)
     }
+
+    #[inline]
+    fn fmt_with_attrs<T>(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        attrs: &[(&str, &str)],
+        children: T,
+    ) -> fmt::Result
+    where
+        T: Fn(&mut fmt::Formatter<'_>) -> fmt::Result,
+    {
+        self.as_str().fmt_with_attrs(f, attrs, children)
+    }
+
+    #[inline]
+    fn fmt_self_closing(&self, f: &mut fmt::Formatter<'_>, attrs: &[(&str, &str)]) -> fmt::Result {
+        self.as_str().fmt_self_closing(f, attrs)
+    }
 }
 
 /// This struct is made to be used with the `t_string!` macro when interpolating a component
@@ -80,15 +169,34 @@ impl<'a> DisplayComp<'a> {
 
 impl DisplayComponent for DisplayComp<'_> {
     fn fmt<T>(&self, f: &mut fmt::Formatter<'_>, children: T) -> fmt::Result
+    where
+        T: Fn(&mut fmt::Formatter<'_>) -> fmt::Result,
+    {
+        self.fmt_with_attrs(f, &[], children)
+    }
+
+    /// The attributes of the translation are written after the ones given to `DisplayComp::new`.
+    fn fmt_with_attrs<T>(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        attrs: &[(&str, &str)],
+        children: T,
+    ) -> fmt::Result
     where
         T: Fn(&mut fmt::Formatter<'_>) -> fmt::Result,
     {
         write!(f, "<{}", self.comp_name)?;
-        for (attr_name, attr) in self.attrs {
-            write!(f, " {}=\"{}\"", attr_name, attr)?;
-        }
+        write_raw_attrs(f, self.attrs)?;
+        write_attrs(f, attrs)?;
         f.write_str(">")?;
         children(f)?;
         write!(f, "</{}>", self.comp_name)
     }
+
+    fn fmt_self_closing(&self, f: &mut fmt::Formatter<'_>, attrs: &[(&str, &str)]) -> fmt::Result {
+        write!(f, "<{}", self.comp_name)?;
+        write_raw_attrs(f, self.attrs)?;
+        write_attrs(f, attrs)?;
+        f.write_str(" />")
+    }
 }
@@ -186,22 +186,35 @@ pub mod reexports {
     pub mod icu {
         #[cfg(feature = "format_datetime")]
         pub use icu_calendar as calendar;
+        #[cfg(feature = "collation")]
+        pub use icu_collator as collator;
         #[cfg(feature = "format_datetime")]
         pub use icu_datetime as datetime;
         #[cfg(feature = "format_nums")]
         pub use icu_decimal as decimal;
+        #[cfg(feature = "format_compact_nums")]
+        pub use icu_experimental::compactdecimal;
         #[cfg(feature = "format_currency")]
         pub use icu_experimental::dimension::currency;
+        #[cfg(feature = "format_display_names")]
+        pub use icu_experimental::displaynames;
+        #[cfg(feature = "format_relative_time")]
+        pub use icu_experimental::relativetime;
         #[cfg(feature = "format_list")]
         pub use icu_list as list;
         #[cfg(feature = "plurals")]
         pub use icu_plurals as plurals;
+        #[cfg(feature = "format_zoned_datetime")]
+        pub use icu_timezone as timezone;
 
         #[cfg(any(
             feature = "format_nums",
             feature = "format_datetime",
             feature = "format_list",
             feature = "format_currency",
+            feature = "format_relative_time",
+            feature = "format_display_names",
+            feature = "collation",
             feature = "plurals"
         ))]
         pub use icu_provider as provider;
@@ -49,6 +49,14 @@ pub trait Locale<L: Locale = Self>:
     /// Return the direction of the locale.
     fn direction(self) -> Direction;
 
+    /// Return the name of the locale in its own language, such as "français" for `fr`.
+    #[cfg(feature = "format_display_names")]
+    fn native_name(self) -> &'static str;
+
+    /// Return the name of the locale in the language of `other`, such as "French" for `fr` in `en`.
+    #[cfg(feature = "format_display_names")]
+    fn display_name_in(self, other: L) -> &'static str;
+
     /// Return a static reference to a `LanguageIdentifier`
     fn as_langid(self) -> &'static LanguageIdentifier {
         Locale::as_icu_locale(self).as_ref()
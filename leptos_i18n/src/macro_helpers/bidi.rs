@@ -0,0 +1,124 @@
+use core::fmt::{self, Display};
+
+use leptos::{either::Either, IntoView};
+
+use crate::{locale_traits::Direction, Locale};
+
+/// U+2068 FIRST STRONG ISOLATE
+const FSI: char = '\u{2068}';
+/// U+2069 POP DIRECTIONAL ISOLATE
+const PDI: char = '\u{2069}';
+
+fn is_rtl_char(c: char) -> bool {
+    matches!(c,
+        '\u{0590}'..='\u{08FF}' // Hebrew, Arabic, Syriac, Thaana, NKo, Samaritan, Mandaic
+        | '\u{FB1D}'..='\u{FDFF}' // Hebrew and Arabic presentation forms A
+        | '\u{FE70}'..='\u{FEFF}' // Arabic presentation forms B
+        | '\u{10800}'..='\u{10FFF}' // Cypriot to Old Uyghur
+        | '\u{1E800}'..='\u{1EFFF}' // Mende Kikakui to Arabic mathematical symbols
+    )
+}
+
+/// Direction of the first strongly directional character of the text.
+/// Text without any, such as numbers, is displayed left to right.
+fn text_direction(text: &str) -> Direction {
+    text.chars()
+        .find_map(|c| {
+            if is_rtl_char(c) {
+                Some(Direction::RightToLeft)
+            } else if c.is_alphabetic() {
+                Some(Direction::LeftToRight)
+            } else {
+                None
+            }
+        })
+        .unwrap_or(Direction::LeftToRight)
+}
+
+fn needs_isolation(locale_direction: Direction, text: &str) -> bool {
+    if text.is_empty() {
+        return false;
+    }
+    match locale_direction {
+        Direction::Auto => true,
+        locale_direction => text_direction(text) != locale_direction,
+    }
+}
+
+struct FmtFn<F>(F);
+
+impl<F: Fn(&mut fmt::Formatter<'_>) -> fmt::Result> Display for FmtFn<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (self.0)(f)
+    }
+}
+
+#[doc(hidden)]
+pub fn fmt_bidi_isolated<L: Locale>(
+    f: &mut fmt::Formatter<'_>,
+    locale: L,
+    fmt_value: impl Fn(&mut fmt::Formatter<'_>) -> fmt::Result,
+) -> fmt::Result {
+    let value = FmtFn(fmt_value).to_string();
+    if needs_isolation(locale.direction(), &value) {
+        write!(f, "{}{}{}", FSI, value, PDI)
+    } else {
+        f.write_str(&value)
+    }
+}
+
+// Text content of rendered HTML, tags and character references are skipped.
+fn html_text(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut rest = html;
+    while let Some(index) = rest.find(['<', '&']) {
+        text.push_str(&rest[..index]);
+        let end = if rest[index..].starts_with('<') {
+            '>'
+        } else {
+            ';'
+        };
+        rest = rest[index..].split_once(end).map_or("", |(_, after)| after);
+    }
+    text.push_str(rest);
+    text
+}
+
+#[doc(hidden)]
+pub fn bidi_isolate_view<L: Locale, V: IntoView + Clone + Send + Sync + 'static>(
+    locale: L,
+    view: V,
+) -> impl IntoView + Clone {
+    use leptos::html::ElementChild;
+    move || {
+        let text = html_text(&view.clone().to_html());
+        if needs_isolation(locale.direction(), &text) {
+            Either::Left(leptos::html::bdi().child(view.clone()))
+        } else {
+            Either::Right(view.clone())
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_needs_isolation() {
+        assert!(!needs_isolation(Direction::LeftToRight, "John"));
+        assert!(!needs_isolation(Direction::LeftToRight, "1,234"));
+        assert!(needs_isolation(Direction::LeftToRight, "محمد"));
+        assert!(needs_isolation(Direction::RightToLeft, "John"));
+        assert!(needs_isolation(Direction::RightToLeft, "1,234"));
+        assert!(!needs_isolation(Direction::RightToLeft, "(محمد)"));
+        assert!(needs_isolation(Direction::Auto, "John"));
+        assert!(!needs_isolation(Direction::RightToLeft, ""));
+    }
+
+    #[test]
+    fn test_html_text() {
+        assert_eq!(html_text("<span>محمد</span>"), "محمد");
+        assert_eq!(html_text("&amp;<!>John &lt;3"), "John 3");
+    }
+}
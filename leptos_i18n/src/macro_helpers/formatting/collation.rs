@@ -0,0 +1,34 @@
+use core::cmp::Ordering;
+
+use crate::Locale;
+
+/// Compare two strings following the collation rules of the given locale.
+///
+/// ```rust,ignore
+/// # use leptos_i18n::formatting::compare_by_locale;
+/// assert_eq!(compare_by_locale(Locale::de, "Äpfel", "Birnen"), Ordering::Less);
+/// ```
+pub fn compare_by_locale<L: Locale>(locale: L, left: &str, right: &str) -> Ordering {
+    let collator = super::get_collator(locale);
+    collator.compare(left, right)
+}
+
+/// Sort a slice following the collation rules of the given locale,
+/// `key_fn` returning the string each item is compared by.
+///
+/// The sort is stable, see [`slice::sort_by`].
+///
+/// ```rust,ignore
+/// # use leptos_i18n::formatting::sort_by_locale;
+/// let mut countries = vec!["Zimbabwe", "Ägypten", "Österreich", "Deutschland"];
+/// sort_by_locale(Locale::de, &mut countries, |country| *country);
+/// assert_eq!(countries, ["Ägypten", "Deutschland", "Österreich", "Zimbabwe"]);
+/// ```
+pub fn sort_by_locale<L: Locale, T>(
+    locale: L,
+    items: &mut [T],
+    mut key_fn: impl FnMut(&T) -> &str,
+) {
+    let collator = super::get_collator(locale);
+    items.sort_by(|left, right| collator.compare(key_fn(left), key_fn(right)));
+}
@@ -0,0 +1,85 @@
+use core::fmt::{self, Display};
+
+use icu_decimal::options::{FixedDecimalFormatterOptions, GroupingStrategy};
+use icu_experimental::compactdecimal::CompactDecimalFormatter;
+use leptos::IntoView;
+use writeable::Writeable;
+
+use super::data_provider::IcuDataProvider;
+use super::{IntoFixedDecimal, NumberFormatterInputFn};
+use crate::Locale;
+
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum CompactDisplay {
+    Short,
+    Long,
+}
+
+impl CompactDisplay {
+    pub(crate) fn new_formatter(
+        self,
+        provider: &impl IcuDataProvider,
+        locale: &icu_locid::Locale,
+    ) -> CompactDecimalFormatter {
+        // same default as `CompactDecimalFormatterOptions`, which is not exported by `icu_experimental`.
+        let options = FixedDecimalFormatterOptions::from(GroupingStrategy::Min2);
+        match self {
+            CompactDisplay::Short => provider
+                .try_new_short_compact_num_formatter(&locale.into(), options)
+                .expect("A CompactDecimalFormatter"),
+            CompactDisplay::Long => provider
+                .try_new_long_compact_num_formatter(&locale.into(), options)
+                .expect("A CompactDecimalFormatter"),
+        }
+    }
+}
+
+#[doc(hidden)]
+pub fn format_compact_number_to_view<L: Locale>(
+    locale: L,
+    number: impl NumberFormatterInputFn,
+    compact_display: CompactDisplay,
+) -> impl IntoView + Clone {
+    let compact_formatter = super::get_compact_num_formatter(locale, compact_display);
+
+    move || {
+        let value = number.to_fixed_decimal();
+        compact_formatter
+            .format_fixed_decimal(value)
+            .write_to_string()
+            .into_owned()
+    }
+}
+
+#[doc(hidden)]
+pub fn format_compact_number_to_formatter<L: Locale>(
+    f: &mut fmt::Formatter<'_>,
+    locale: L,
+    number: impl IntoFixedDecimal,
+    compact_display: CompactDisplay,
+) -> fmt::Result {
+    let compact_formatter = super::get_compact_num_formatter(locale, compact_display);
+    let fixed_dec = number.to_fixed_decimal();
+    compact_formatter
+        .format_fixed_decimal(fixed_dec)
+        .write_to(f)
+}
+
+/// This function is a lie.
+/// The only reason it exist is for the `format` macros.
+/// It does NOT return a `impl Display` struct with no allocation like the other
+/// This directly return a `String` of the formatted num, because borrow issues.
+#[doc(hidden)]
+pub fn format_compact_number_to_display<L: Locale>(
+    locale: L,
+    number: impl IntoFixedDecimal,
+    compact_display: CompactDisplay,
+) -> impl Display {
+    let compact_formatter = super::get_compact_num_formatter(locale, compact_display);
+    let fixed_dec = number.to_fixed_decimal();
+    compact_formatter
+        .format_fixed_decimal(fixed_dec)
+        .write_to_string()
+        .into_owned()
+}
@@ -0,0 +1,185 @@
+use std::fmt::{self, Display};
+
+use icu_calendar::{AnyCalendar, Date, DateTime, Time};
+use icu_datetime::{
+    input::{DateInput, IsoTimeInput},
+    options::{components, preferences},
+};
+use leptos::IntoView;
+
+use super::{
+    AsIcuDate, AsIcuDateTime, AsIcuTime, DateFormatterInputFn, DateTimeFormatterInputFn,
+    TimeFormatterInputFn,
+};
+use crate::Locale;
+
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DateTimeComponents {
+    pub era: Option<components::Text>,
+    pub year: Option<components::Year>,
+    pub month: Option<components::Month>,
+    pub day: Option<components::Day>,
+    pub weekday: Option<components::Text>,
+    pub hour: Option<components::Numeric>,
+    pub minute: Option<components::Numeric>,
+    pub second: Option<components::Numeric>,
+    pub hour_cycle: Option<preferences::HourCycle>,
+    pub time_zone_name: Option<components::TimeZoneName>,
+}
+
+impl From<DateTimeComponents> for components::Bag {
+    fn from(value: DateTimeComponents) -> Self {
+        let mut bag = components::Bag::empty();
+        bag.era = value.era;
+        bag.year = value.year;
+        bag.month = value.month;
+        bag.day = value.day;
+        bag.weekday = value.weekday;
+        bag.hour = value.hour;
+        bag.minute = value.minute;
+        bag.second = value.second;
+        bag.preferences = value.hour_cycle.map(preferences::Bag::from_hour_cycle);
+        bag.time_zone_name = value.time_zone_name;
+        bag
+    }
+}
+
+// The `DateTimeFormatter` needs a datetime, the parser ensures the missing half is never displayed.
+fn date_to_datetime(date: &impl DateInput<Calendar = AnyCalendar>) -> DateTime<AnyCalendar> {
+    DateTime::new(date.to_iso(), Time::midnight()).to_any()
+}
+
+fn time_to_datetime(time: &impl IsoTimeInput) -> DateTime<AnyCalendar> {
+    let time = Time::new(
+        time.hour().unwrap_or_default(),
+        time.minute().unwrap_or_default(),
+        time.second().unwrap_or_default(),
+        time.nanosecond().unwrap_or_default(),
+    );
+    DateTime::new(Date::unix_epoch(), time).to_any()
+}
+
+fn format_components<L: Locale>(
+    locale: L,
+    datetime: &DateTime<AnyCalendar>,
+    components: DateTimeComponents,
+) -> impl Display {
+    let formatter = super::get_components_formatter(locale, components);
+    formatter
+        .format(datetime)
+        .expect("The datetime formatter to return a formatted datetime.")
+}
+
+#[doc(hidden)]
+pub fn format_date_components_to_view<L: Locale>(
+    locale: L,
+    date: impl DateFormatterInputFn,
+    components: DateTimeComponents,
+) -> impl IntoView + Clone {
+    let formatter = super::get_components_formatter(locale, components);
+
+    move || {
+        let datetime = date_to_datetime(&date.to_icu_date());
+        formatter
+            .format_to_string(&datetime)
+            .expect("The datetime formatter to return a formatted datetime.")
+    }
+}
+
+#[doc(hidden)]
+pub fn format_date_components_to_formatter<L: Locale>(
+    f: &mut fmt::Formatter<'_>,
+    locale: L,
+    date: &impl AsIcuDate,
+    components: DateTimeComponents,
+) -> fmt::Result {
+    let formatted_date = format_date_components_to_display(locale, date, components);
+    Display::fmt(&formatted_date, f)
+}
+
+#[doc(hidden)]
+pub fn format_date_components_to_display<L: Locale>(
+    locale: L,
+    date: &impl AsIcuDate,
+    components: DateTimeComponents,
+) -> impl Display {
+    let datetime = date_to_datetime(date.as_icu_date());
+    format_components(locale, &datetime, components)
+}
+
+#[doc(hidden)]
+pub fn format_time_components_to_view<L: Locale>(
+    locale: L,
+    time: impl TimeFormatterInputFn,
+    components: DateTimeComponents,
+) -> impl IntoView + Clone {
+    let formatter = super::get_components_formatter(locale, components);
+
+    move || {
+        let datetime = time_to_datetime(&time.to_icu_time());
+        formatter
+            .format_to_string(&datetime)
+            .expect("The datetime formatter to return a formatted datetime.")
+    }
+}
+
+#[doc(hidden)]
+pub fn format_time_components_to_formatter<L: Locale>(
+    f: &mut fmt::Formatter<'_>,
+    locale: L,
+    time: &impl AsIcuTime,
+    components: DateTimeComponents,
+) -> fmt::Result {
+    let formatted_time = format_time_components_to_display(locale, time, components);
+    Display::fmt(&formatted_time, f)
+}
+
+#[doc(hidden)]
+pub fn format_time_components_to_display<L: Locale>(
+    locale: L,
+    time: &impl AsIcuTime,
+    components: DateTimeComponents,
+) -> impl Display {
+    let datetime = time_to_datetime(time.as_icu_time());
+    format_components(locale, &datetime, components)
+}
+
+#[doc(hidden)]
+pub fn format_datetime_components_to_view<L: Locale>(
+    locale: L,
+    datetime: impl DateTimeFormatterInputFn,
+    components: DateTimeComponents,
+) -> impl IntoView + Clone {
+    let formatter = super::get_components_formatter(locale, components);
+
+    move || {
+        let datetime = datetime.to_icu_datetime();
+        formatter
+            .format_to_string(&datetime)
+            .expect("The datetime formatter to return a formatted datetime.")
+    }
+}
+
+#[doc(hidden)]
+pub fn format_datetime_components_to_formatter<L: Locale>(
+    f: &mut fmt::Formatter<'_>,
+    locale: L,
+    datetime: &impl AsIcuDateTime,
+    components: DateTimeComponents,
+) -> fmt::Result {
+    let formatted_datetime = format_datetime_components_to_display(locale, datetime, components);
+    Display::fmt(&formatted_datetime, f)
+}
+
+#[doc(hidden)]
+pub fn format_datetime_components_to_display<L: Locale>(
+    locale: L,
+    datetime: &impl AsIcuDateTime,
+    components: DateTimeComponents,
+) -> impl Display {
+    let formatter = super::get_components_formatter(locale, components);
+    formatter
+        .format(datetime.as_icu_datetime())
+        .expect("The datetime formatter to return a formatted datetime.")
+}
@@ -1,12 +1,15 @@
-use super::{IntoFixedDecimal, NumberFormatterInputFn};
+use super::{IntoFixedDecimal, NumberPrecision};
 use crate::Locale;
 use core::fmt::{self, Display};
+use fixed_decimal::FixedDecimal;
 use icu_experimental::dimension::currency::{
-    formatter::CurrencyCode, options::Width as CurrencyWidth,
+    formatter::{CurrencyCode, CurrencyFormatter},
+    options::Width as CurrencyWidth,
 };
 use leptos::IntoView;
 
 use serde::{Deserialize, Serialize};
+use tinystr::TinyAsciiStr;
 use writeable::Writeable;
 
 // TODO: this struct should be removed in version ICU4x v2
@@ -39,20 +42,130 @@ impl From<CurrencyWidth> for Width {
     }
 }
 
+/// Marker trait for types that can be formatted as a currency.
+///
+/// Implemented for any `T: IntoFixedDecimal`, formatted with the `currency_code` of the formatter,
+/// and for `(amount, code)` tuples carrying their own ISO 4217 code, such as `(12.5, "EUR")`.
+/// String codes are case insensitive. A code that is not three ASCII letters can't be formatted by ICU4X,
+/// the amount is then rendered followed by the code as is, and a warning is logged in debug builds.
+pub trait IntoCurrency: Clone {
+    /// Consume self to produce the amount and its currency code, `None` to use the one of the formatter.
+    fn to_currency(self) -> (FixedDecimal, Option<RuntimeCurrencyCode>);
+}
+
+/// Currency code supplied with the amount.
+#[derive(Clone)]
+pub enum RuntimeCurrencyCode {
+    /// A valid ISO 4217 code.
+    Code(CurrencyCode),
+    /// A code that is not three ASCII letters, kept as is.
+    Invalid(String),
+}
+
+impl From<CurrencyCode> for RuntimeCurrencyCode {
+    fn from(value: CurrencyCode) -> Self {
+        Self::Code(value)
+    }
+}
+
+impl<T: IntoFixedDecimal> IntoCurrency for T {
+    fn to_currency(self) -> (FixedDecimal, Option<RuntimeCurrencyCode>) {
+        (self.to_fixed_decimal(), None)
+    }
+}
+
+impl<T: IntoFixedDecimal> IntoCurrency for (T, CurrencyCode) {
+    fn to_currency(self) -> (FixedDecimal, Option<RuntimeCurrencyCode>) {
+        (self.0.to_fixed_decimal(), Some(self.1.into()))
+    }
+}
+
+impl<T: IntoFixedDecimal> IntoCurrency for (T, TinyAsciiStr<3>) {
+    fn to_currency(self) -> (FixedDecimal, Option<RuntimeCurrencyCode>) {
+        (self.0.to_fixed_decimal(), Some(CurrencyCode(self.1).into()))
+    }
+}
+
+// ISO 4217 codes are three uppercase letters, other codes are kept as is.
+fn parse_currency_code(code: &str) -> RuntimeCurrencyCode {
+    match TinyAsciiStr::<3>::from_str(code) {
+        Ok(parsed) if parsed.is_ascii_alphabetic() => {
+            RuntimeCurrencyCode::Code(CurrencyCode(parsed.to_ascii_uppercase()))
+        }
+        _ => RuntimeCurrencyCode::Invalid(code.to_string()),
+    }
+}
+
+impl<T: IntoFixedDecimal> IntoCurrency for (T, &str) {
+    fn to_currency(self) -> (FixedDecimal, Option<RuntimeCurrencyCode>) {
+        (self.0.to_fixed_decimal(), Some(parse_currency_code(self.1)))
+    }
+}
+
+impl<T: IntoFixedDecimal> IntoCurrency for (T, String) {
+    fn to_currency(self) -> (FixedDecimal, Option<RuntimeCurrencyCode>) {
+        IntoCurrency::to_currency((self.0, self.1.as_str()))
+    }
+}
+
+/// Marker trait for types that produce a `T: IntoCurrency`.
+pub trait CurrencyFormatterInputFn: Clone + Send + Sync + 'static {
+    /// Produce the amount and its currency code, if any.
+    fn to_currency(&self) -> (FixedDecimal, Option<RuntimeCurrencyCode>);
+}
+
+impl<T: IntoCurrency, F: Fn() -> T + Clone + Send + Sync + 'static> CurrencyFormatterInputFn for F {
+    fn to_currency(&self) -> (FixedDecimal, Option<RuntimeCurrencyCode>) {
+        IntoCurrency::to_currency(self())
+    }
+}
+
+fn write_currency<W: fmt::Write + ?Sized>(
+    w: &mut W,
+    currency_formatter: &CurrencyFormatter,
+    amount: &FixedDecimal,
+    code: Option<RuntimeCurrencyCode>,
+    currency_code: CurrencyCode,
+) -> fmt::Result {
+    match code {
+        None => currency_formatter
+            .format_fixed_decimal(amount, currency_code)
+            .write_to(w),
+        Some(RuntimeCurrencyCode::Code(code)) => currency_formatter
+            .format_fixed_decimal(amount, code)
+            .write_to(w),
+        Some(RuntimeCurrencyCode::Invalid(code)) => {
+            leptos::logging::debug_warn!(
+                "invalid currency code {:?}, expected three ASCII letters",
+                code
+            );
+            write!(w, "{} {}", amount, code)
+        }
+    }
+}
+
 #[doc(hidden)]
 pub fn format_currency_to_view<L: Locale>(
     locale: L,
-    number: impl NumberFormatterInputFn,
+    currency: impl CurrencyFormatterInputFn,
     width: CurrencyWidth,
     currency_code: CurrencyCode,
+    precision: NumberPrecision,
 ) -> impl IntoView + Clone {
     let currency_formatter = super::get_currency_formatter(locale, width);
 
     move || {
-        let fixed_dec = number.to_fixed_decimal();
-        let currency = currency_formatter.format_fixed_decimal(&fixed_dec, currency_code);
+        let (amount, code) = currency.to_currency();
+        let fixed_dec = precision.apply(amount);
         let mut formatted_currency = String::new();
-        currency.write_to(&mut formatted_currency).unwrap();
+        write_currency(
+            &mut formatted_currency,
+            currency_formatter,
+            &fixed_dec,
+            code,
+            currency_code,
+        )
+        .unwrap();
         formatted_currency
     }
 }
@@ -61,14 +174,15 @@ pub fn format_currency_to_view<L: Locale>(
 pub fn format_currency_to_formatter<L: Locale>(
     f: &mut fmt::Formatter<'_>,
     locale: L,
-    number: impl IntoFixedDecimal,
+    currency: impl IntoCurrency,
     width: CurrencyWidth,
     currency_code: CurrencyCode,
+    precision: NumberPrecision,
 ) -> fmt::Result {
     let currency_formatter = super::get_currency_formatter(locale, width);
-    let fixed_dec = number.to_fixed_decimal();
-    let formatted_currency = currency_formatter.format_fixed_decimal(&fixed_dec, currency_code);
-    formatted_currency.write_to(f)
+    let (amount, code) = currency.to_currency();
+    let fixed_dec = precision.apply(amount);
+    write_currency(f, currency_formatter, &fixed_dec, code, currency_code)
 }
 
 /// This function is a lie.
@@ -78,14 +192,22 @@ pub fn format_currency_to_formatter<L: Locale>(
 #[doc(hidden)]
 pub fn format_currency_to_display<L: Locale>(
     locale: L,
-    number: impl IntoFixedDecimal,
+    currency: impl IntoCurrency,
     width: CurrencyWidth,
     currency_code: CurrencyCode,
+    precision: NumberPrecision,
 ) -> impl Display {
     let currency_formatter = super::get_currency_formatter(locale, width);
-    let fixed_dec = number.to_fixed_decimal();
-    let currency = currency_formatter.format_fixed_decimal(&fixed_dec, currency_code);
+    let (amount, code) = currency.to_currency();
+    let fixed_dec = precision.apply(amount);
     let mut formatted_currency = String::new();
-    currency.write_to(&mut formatted_currency).unwrap();
+    write_currency(
+        &mut formatted_currency,
+        currency_formatter,
+        &fixed_dec,
+        code,
+        currency_code,
+    )
+    .unwrap();
     formatted_currency
 }
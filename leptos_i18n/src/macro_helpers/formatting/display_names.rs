@@ -0,0 +1,208 @@
+use std::fmt::{self, Display};
+
+use icu_experimental::displaynames::{DisplayNamesOptions, Style};
+use icu_locid::{subtags::Region, LanguageIdentifier, Locale as IcuLocale};
+use leptos::IntoView;
+
+use crate::Locale;
+
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum DisplayNameStyle {
+    Long,
+    Short,
+}
+
+impl From<DisplayNameStyle> for DisplayNamesOptions {
+    fn from(value: DisplayNameStyle) -> Self {
+        let mut options = DisplayNamesOptions::default();
+        options.style = Some(match value {
+            DisplayNameStyle::Long => Style::Long,
+            DisplayNameStyle::Short => Style::Short,
+        });
+        options
+    }
+}
+
+/// Marker trait for types that can be displayed as a language name.
+///
+/// Implemented for `icu::locid::Locale`, `icu::locid::LanguageIdentifier`
+/// and string codes such as `"fr"` or `"pt-BR"`. Invalid codes are displayed as is.
+pub trait IntoLanguageCode: Clone {
+    /// Consume self to produce a locale, or the code to display if it is not a valid locale.
+    fn to_language_code(self) -> Result<IcuLocale, String>;
+}
+
+impl IntoLanguageCode for IcuLocale {
+    fn to_language_code(self) -> Result<IcuLocale, String> {
+        Ok(self)
+    }
+}
+
+impl IntoLanguageCode for LanguageIdentifier {
+    fn to_language_code(self) -> Result<IcuLocale, String> {
+        Ok(self.into())
+    }
+}
+
+impl IntoLanguageCode for &str {
+    fn to_language_code(self) -> Result<IcuLocale, String> {
+        self.parse().map_err(|_| self.to_string())
+    }
+}
+
+impl IntoLanguageCode for String {
+    fn to_language_code(self) -> Result<IcuLocale, String> {
+        self.parse().map_err(|_| self)
+    }
+}
+
+/// Marker trait for types that produce a `T: IntoLanguageCode`.
+pub trait LanguageNameFormatterInputFn: Clone + Send + Sync + 'static {
+    /// Produce a locale, or the code to display if it is not a valid locale.
+    fn to_language_code(&self) -> Result<IcuLocale, String>;
+}
+
+impl<T: IntoLanguageCode, F: Fn() -> T + Clone + Send + Sync + 'static> LanguageNameFormatterInputFn
+    for F
+{
+    fn to_language_code(&self) -> Result<IcuLocale, String> {
+        IntoLanguageCode::to_language_code(self())
+    }
+}
+
+/// Marker trait for types that can be displayed as a region name.
+///
+/// Implemented for `icu::locid::subtags::Region` and string codes such as `"FR"` or `"419"`.
+/// Invalid codes are displayed as is.
+pub trait IntoRegionCode: Clone {
+    /// Consume self to produce a region, or the code to display if it is not a valid region.
+    fn to_region_code(self) -> Result<Region, String>;
+}
+
+impl IntoRegionCode for Region {
+    fn to_region_code(self) -> Result<Region, String> {
+        Ok(self)
+    }
+}
+
+impl IntoRegionCode for &str {
+    fn to_region_code(self) -> Result<Region, String> {
+        self.parse().map_err(|_| self.to_string())
+    }
+}
+
+impl IntoRegionCode for String {
+    fn to_region_code(self) -> Result<Region, String> {
+        self.parse().map_err(|_| self)
+    }
+}
+
+/// Marker trait for types that produce a `T: IntoRegionCode`.
+pub trait RegionNameFormatterInputFn: Clone + Send + Sync + 'static {
+    /// Produce a region, or the code to display if it is not a valid region.
+    fn to_region_code(&self) -> Result<Region, String>;
+}
+
+impl<T: IntoRegionCode, F: Fn() -> T + Clone + Send + Sync + 'static> RegionNameFormatterInputFn
+    for F
+{
+    fn to_region_code(&self) -> Result<Region, String> {
+        IntoRegionCode::to_region_code(self())
+    }
+}
+
+fn language_name<L: Locale>(
+    locale: L,
+    code: Result<IcuLocale, String>,
+    style: DisplayNameStyle,
+) -> String {
+    match code {
+        Ok(code) => {
+            let formatter = super::get_language_names_formatter(locale, style);
+            formatter.of(&code).into_owned()
+        }
+        Err(code) => code,
+    }
+}
+
+fn region_name<L: Locale>(
+    locale: L,
+    code: Result<Region, String>,
+    style: DisplayNameStyle,
+) -> String {
+    match code {
+        Ok(region) => {
+            let formatter = super::get_region_names_formatter(locale, style);
+            formatter
+                .of(region)
+                .map(ToString::to_string)
+                .unwrap_or_else(|| region.to_string())
+        }
+        Err(code) => code,
+    }
+}
+
+#[doc(hidden)]
+pub fn format_language_name_to_view<L: Locale>(
+    locale: L,
+    code: impl LanguageNameFormatterInputFn,
+    style: DisplayNameStyle,
+) -> impl IntoView + Clone {
+    move || language_name(locale, code.to_language_code(), style)
+}
+
+#[doc(hidden)]
+pub fn format_language_name_to_formatter<L: Locale>(
+    f: &mut fmt::Formatter<'_>,
+    locale: L,
+    code: impl IntoLanguageCode,
+    style: DisplayNameStyle,
+) -> fmt::Result {
+    f.write_str(&language_name(locale, code.to_language_code(), style))
+}
+
+/// This function is a lie.
+/// The only reason it exist is for the `format` macros.
+/// It does NOT return a `impl Display` struct with no allocation like the other
+/// This directly return a `String` of the language name, because borrow issues.
+#[doc(hidden)]
+pub fn format_language_name_to_display<L: Locale>(
+    locale: L,
+    code: impl IntoLanguageCode,
+    style: DisplayNameStyle,
+) -> impl Display {
+    language_name(locale, code.to_language_code(), style)
+}
+
+#[doc(hidden)]
+pub fn format_region_name_to_view<L: Locale>(
+    locale: L,
+    code: impl RegionNameFormatterInputFn,
+    style: DisplayNameStyle,
+) -> impl IntoView + Clone {
+    move || region_name(locale, code.to_region_code(), style)
+}
+
+#[doc(hidden)]
+pub fn format_region_name_to_formatter<L: Locale>(
+    f: &mut fmt::Formatter<'_>,
+    locale: L,
+    code: impl IntoRegionCode,
+    style: DisplayNameStyle,
+) -> fmt::Result {
+    f.write_str(&region_name(locale, code.to_region_code(), style))
+}
+
+/// This function is a lie.
+/// The only reason it exist is for the `format` macros.
+/// It does NOT return a `impl Display` struct with no allocation like the other
+/// This directly return a `String` of the region name, because borrow issues.
+#[doc(hidden)]
+pub fn format_region_name_to_display<L: Locale>(
+    locale: L,
+    code: impl IntoRegionCode,
+    style: DisplayNameStyle,
+) -> impl Display {
+    region_name(locale, code.to_region_code(), style)
+}
@@ -115,3 +115,54 @@ where
     let list_formatter = super::get_list_formatter(locale, list_type, length);
     list_formatter.format(list.into_iter())
 }
+
+#[cfg(feature = "collation")]
+fn sort_list<L: Locale>(locale: L, list: impl WriteableList) -> Vec<String> {
+    let mut items = list
+        .into_iter()
+        .map(|item| item.write_to_string().into_owned())
+        .collect::<Vec<_>>();
+    super::sort_by_locale(locale, &mut items, |item| item.as_str());
+    items
+}
+
+#[cfg(feature = "collation")]
+#[doc(hidden)]
+pub fn format_sorted_list_to_view<L: Locale>(
+    locale: L,
+    list: impl ListFormatterInputFn,
+    list_type: ListType,
+    length: ListLength,
+) -> impl IntoView + Clone {
+    let list_formatter = super::get_list_formatter(locale, list_type, length);
+
+    move || {
+        let list = sort_list(locale, list.to_list());
+        list_formatter.format_to_string(list.into_iter())
+    }
+}
+
+#[cfg(feature = "collation")]
+#[doc(hidden)]
+pub fn format_sorted_list_to_formatter<L: Locale>(
+    f: &mut fmt::Formatter<'_>,
+    locale: L,
+    list: impl WriteableList,
+    list_type: ListType,
+    length: ListLength,
+) -> fmt::Result {
+    let formatted_list = format_sorted_list_to_display(locale, list, list_type, length);
+    Display::fmt(&formatted_list, f)
+}
+
+#[cfg(feature = "collation")]
+#[doc(hidden)]
+pub fn format_sorted_list_to_display<L: Locale>(
+    locale: L,
+    list: impl WriteableList,
+    list_type: ListType,
+    length: ListLength,
+) -> impl Display {
+    let list_formatter = super::get_list_formatter(locale, list_type, length);
+    list_formatter.format(sort_list(locale, list).into_iter())
+}
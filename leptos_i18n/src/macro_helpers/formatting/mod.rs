@@ -1,25 +1,51 @@
 //! This module contain traits and helper functions for formatting
 //! different kind of value based on a locale.
 
+#[cfg(feature = "collation")]
+mod collation;
+#[cfg(feature = "format_compact_nums")]
+mod compact_nums;
+#[cfg(feature = "format_datetime_components")]
+mod components;
 #[cfg(feature = "format_currency")]
 mod currency;
 #[cfg(feature = "format_datetime")]
 mod date;
 #[cfg(feature = "format_datetime")]
 mod datetime;
+#[cfg(feature = "format_display_names")]
+mod display_names;
 #[cfg(feature = "format_list")]
 mod list;
 #[cfg(feature = "format_nums")]
 mod nums;
+#[cfg(feature = "format_percent")]
+mod percent;
+#[cfg(feature = "format_relative_time")]
+mod relative_time;
 #[cfg(feature = "format_datetime")]
 mod time;
+#[cfg(feature = "format_zoned_datetime")]
+mod zoned_datetime;
 
+#[cfg(feature = "collation")]
+pub use collation::*;
+#[cfg(feature = "format_compact_nums")]
+pub use compact_nums::*;
+#[cfg(feature = "format_datetime_components")]
+pub use components::*;
 #[cfg(feature = "format_currency")]
 pub use currency::*;
 #[cfg(feature = "format_datetime")]
 pub use date::*;
 #[cfg(feature = "format_datetime")]
 pub use datetime::*;
+#[cfg(feature = "format_display_names")]
+pub use display_names::*;
+#[cfg(feature = "collation")]
+use icu_collator::Collator;
+#[cfg(feature = "format_zoned_datetime")]
+use icu_datetime::ZonedDateTimeFormatter;
 #[cfg(feature = "format_datetime")]
 use icu_datetime::{options::length, DateFormatter, DateTimeFormatter, TimeFormatter};
 #[cfg(feature = "format_list")]
@@ -30,8 +56,14 @@ use icu_plurals::{PluralRuleType, PluralRules};
 pub use list::*;
 #[cfg(feature = "format_nums")]
 pub use nums::*;
+#[cfg(feature = "format_percent")]
+pub use percent::*;
+#[cfg(feature = "format_relative_time")]
+pub use relative_time::*;
 #[cfg(feature = "format_datetime")]
 pub use time::*;
+#[cfg(feature = "format_zoned_datetime")]
+pub use zoned_datetime::*;
 
 #[cfg(any(
     feature = "format_nums",
@@ -39,6 +71,9 @@ pub use time::*;
     feature = "format_list",
     feature = "plurals",
     feature = "format_currency",
+    feature = "format_relative_time",
+    feature = "format_display_names",
+    feature = "collation",
 ))]
 use crate::Locale;
 #[cfg(feature = "format_nums")]
@@ -47,12 +82,18 @@ use icu_decimal::options::FixedDecimalFormatterOptions;
 use icu_decimal::options::GroupingStrategy;
 #[cfg(feature = "format_nums")]
 use icu_decimal::FixedDecimalFormatter;
+#[cfg(feature = "format_compact_nums")]
+use icu_experimental::compactdecimal::{CompactDecimalError, CompactDecimalFormatter};
 #[cfg(feature = "format_currency")]
 use icu_experimental::dimension::currency::formatter::CurrencyFormatter;
 #[cfg(feature = "format_currency")]
 use icu_experimental::dimension::currency::options::CurrencyFormatterOptions;
 #[cfg(feature = "format_currency")]
 use icu_experimental::dimension::currency::options::Width as CurrencyWidth;
+#[cfg(feature = "format_display_names")]
+use icu_experimental::displaynames::{LocaleDisplayNamesFormatter, RegionDisplayNames};
+#[cfg(feature = "format_relative_time")]
+use icu_experimental::relativetime::{options::Numeric, RelativeTimeFormatter};
 
 pub use leptos_i18n_macro::{
     t_format, t_format_display, t_format_string, td_format, td_format_display, td_format_string,
@@ -104,6 +145,41 @@ fn get_num_formatter<L: Locale>(
     })
 }
 
+#[cfg(feature = "format_compact_nums")]
+fn get_compact_num_formatter<L: Locale>(
+    locale: L,
+    compact_display: CompactDisplay,
+) -> &'static CompactDecimalFormatter {
+    inner::FORMATTERS.with_mut(|formatters| {
+        let locale = locale.as_icu_locale();
+        let compact_formatters = formatters.compact_num.entry(locale).or_default();
+        let compact_formatter = compact_formatters
+            .entry(compact_display)
+            .or_insert_with(|| {
+                let formatter = compact_display.new_formatter(&formatters.provider, locale);
+                Box::leak(Box::new(formatter))
+            });
+        *compact_formatter
+    })
+}
+
+#[cfg(feature = "format_percent")]
+fn get_percent_formatter<L: Locale>(locale: L) -> &'static PercentFormatter {
+    use data_provider::IcuDataProvider;
+
+    inner::FORMATTERS.with_mut(|formatters| {
+        let locale = locale.as_icu_locale();
+        let percent_formatter = formatters.percent.entry(locale).or_insert_with(|| {
+            let formatter = formatters
+                .provider
+                .try_new_percent_formatter(&locale.into())
+                .expect("A PercentFormatter");
+            Box::leak(Box::new(formatter))
+        });
+        *percent_formatter
+    })
+}
+
 #[cfg(feature = "format_datetime")]
 fn get_date_formatter<L: Locale>(locale: L, length: length::Date) -> &'static DateFormatter {
     use data_provider::IcuDataProvider;
@@ -165,6 +241,49 @@ fn get_datetime_formatter<L: Locale>(
     })
 }
 
+#[cfg(feature = "format_datetime_components")]
+fn get_components_formatter<L: Locale>(
+    locale: L,
+    components: DateTimeComponents,
+) -> &'static DateTimeFormatter {
+    use data_provider::IcuDataProvider;
+
+    inner::FORMATTERS.with_mut(|formatters| {
+        let locale = locale.as_icu_locale();
+        let components_formatters = formatters.components.entry(locale).or_default();
+        let components_formatter = components_formatters.entry(components).or_insert_with(|| {
+            let formatter = formatters
+                .provider
+                .try_new_components_formatter(&locale.into(), components.into())
+                .expect("A DateTimeFormatter");
+            Box::leak(Box::new(formatter))
+        });
+        *components_formatter
+    })
+}
+
+#[cfg(feature = "format_zoned_datetime")]
+fn get_zoned_datetime_formatter<L: Locale>(
+    locale: L,
+    options: ZonedDateTimeOptions,
+) -> &'static ZonedDateTimeFormatter {
+    use data_provider::IcuDataProvider;
+
+    inner::FORMATTERS.with_mut(|formatters| {
+        let locale = locale.as_icu_locale();
+        let zoned_datetime_formatters = formatters.zoned_datetime.entry(locale).or_default();
+        let zoned_datetime_formatter =
+            zoned_datetime_formatters.entry(options).or_insert_with(|| {
+                let formatter = formatters
+                    .provider
+                    .try_new_zoned_datetime_formatter(&locale.into(), options.into())
+                    .expect("A ZonedDateTimeFormatter");
+                Box::leak(Box::new(formatter))
+            });
+        *zoned_datetime_formatter
+    })
+}
+
 #[cfg(feature = "format_list")]
 fn get_list_formatter<L: Locale>(
     locale: L,
@@ -184,6 +303,86 @@ fn get_list_formatter<L: Locale>(
     })
 }
 
+#[cfg(feature = "format_relative_time")]
+fn get_relative_time_formatter<L: Locale>(
+    locale: L,
+    style: RelativeTimeStyle,
+    unit: RelativeTimeUnit,
+    numeric: Numeric,
+) -> &'static RelativeTimeFormatter {
+    inner::FORMATTERS.with_mut(|formatters| {
+        let locale = locale.as_icu_locale();
+        let relative_time_formatters = formatters.relative_time.entry(locale).or_default();
+        // `Numeric` is not `Hash`, `true` stands for `Numeric::Auto`.
+        let key = (style, unit, numeric == Numeric::Auto);
+        let relative_time_formatter = relative_time_formatters.entry(key).or_insert_with(|| {
+            let formatter = style.new_formatter(&formatters.provider, locale, unit, numeric);
+            Box::leak(Box::new(formatter))
+        });
+        *relative_time_formatter
+    })
+}
+
+#[cfg(feature = "format_display_names")]
+fn get_language_names_formatter<L: Locale>(
+    locale: L,
+    style: DisplayNameStyle,
+) -> &'static LocaleDisplayNamesFormatter {
+    use data_provider::IcuDataProvider;
+
+    inner::FORMATTERS.with_mut(|formatters| {
+        let locale = locale.as_icu_locale();
+        let language_names_formatters = formatters.language_names.entry(locale).or_default();
+        let language_names_formatter =
+            language_names_formatters.entry(style).or_insert_with(|| {
+                let formatter = formatters
+                    .provider
+                    .try_new_language_names_formatter(&locale.into(), style.into())
+                    .expect("A LocaleDisplayNamesFormatter");
+                Box::leak(Box::new(formatter))
+            });
+        *language_names_formatter
+    })
+}
+
+#[cfg(feature = "format_display_names")]
+fn get_region_names_formatter<L: Locale>(
+    locale: L,
+    style: DisplayNameStyle,
+) -> &'static RegionDisplayNames {
+    use data_provider::IcuDataProvider;
+
+    inner::FORMATTERS.with_mut(|formatters| {
+        let locale = locale.as_icu_locale();
+        let region_names_formatters = formatters.region_names.entry(locale).or_default();
+        let region_names_formatter = region_names_formatters.entry(style).or_insert_with(|| {
+            let formatter = formatters
+                .provider
+                .try_new_region_names_formatter(&locale.into(), style.into())
+                .expect("A RegionDisplayNames");
+            Box::leak(Box::new(formatter))
+        });
+        *region_names_formatter
+    })
+}
+
+#[cfg(feature = "collation")]
+fn get_collator<L: Locale>(locale: L) -> &'static Collator {
+    use data_provider::IcuDataProvider;
+
+    inner::FORMATTERS.with_mut(|formatters| {
+        let locale = locale.as_icu_locale();
+        let collator = formatters.collator.entry(locale).or_insert_with(|| {
+            let collator = formatters
+                .provider
+                .try_new_collator(&locale.into(), Default::default())
+                .expect("A Collator");
+            Box::leak(Box::new(collator))
+        });
+        *collator
+    })
+}
+
 #[cfg(feature = "plurals")]
 #[doc(hidden)]
 pub fn get_plural_rules<L: Locale>(
@@ -212,6 +411,9 @@ pub fn get_plural_rules<L: Locale>(
     feature = "format_list",
     feature = "plurals",
     feature = "format_currency",
+    feature = "format_relative_time",
+    feature = "format_display_names",
+    feature = "collation",
 ))]
 pub(crate) mod inner {
     use super::*;
@@ -256,6 +458,11 @@ pub(crate) mod inner {
         #[cfg(feature = "format_nums")]
         pub num:
             HashMap<&'static IcuLocale, HashMap<GroupingStrategy, &'static FixedDecimalFormatter>>,
+        #[cfg(feature = "format_compact_nums")]
+        pub compact_num:
+            HashMap<&'static IcuLocale, HashMap<CompactDisplay, &'static CompactDecimalFormatter>>,
+        #[cfg(feature = "format_percent")]
+        pub percent: HashMap<&'static IcuLocale, &'static PercentFormatter>,
         #[cfg(feature = "format_datetime")]
         pub date: HashMap<&'static IcuLocale, HashMap<length::Date, &'static DateFormatter>>,
         #[cfg(feature = "format_datetime")]
@@ -265,6 +472,14 @@ pub(crate) mod inner {
             &'static IcuLocale,
             HashMap<(length::Date, length::Time), &'static DateTimeFormatter>,
         >,
+        #[cfg(feature = "format_datetime_components")]
+        pub components:
+            HashMap<&'static IcuLocale, HashMap<DateTimeComponents, &'static DateTimeFormatter>>,
+        #[cfg(feature = "format_zoned_datetime")]
+        pub zoned_datetime: HashMap<
+            &'static IcuLocale,
+            HashMap<ZonedDateTimeOptions, &'static ZonedDateTimeFormatter>,
+        >,
         #[cfg(feature = "format_list")]
         pub list: HashMap<
             &'static IcuLocale,
@@ -272,6 +487,21 @@ pub(crate) mod inner {
         >,
         #[cfg(feature = "plurals")]
         pub plural_rule: HashMap<&'static IcuLocale, HashMap<PluralRuleType, &'static PluralRules>>,
+        #[cfg(feature = "format_relative_time")]
+        pub relative_time: HashMap<
+            &'static IcuLocale,
+            HashMap<(RelativeTimeStyle, RelativeTimeUnit, bool), &'static RelativeTimeFormatter>,
+        >,
+        #[cfg(feature = "format_display_names")]
+        pub language_names: HashMap<
+            &'static IcuLocale,
+            HashMap<DisplayNameStyle, &'static LocaleDisplayNamesFormatter>,
+        >,
+        #[cfg(feature = "format_display_names")]
+        pub region_names:
+            HashMap<&'static IcuLocale, HashMap<DisplayNameStyle, &'static RegionDisplayNames>>,
+        #[cfg(feature = "collation")]
+        pub collator: HashMap<&'static IcuLocale, &'static Collator>,
         pub provider: data_provider::BakedDataProvider,
     }
 
@@ -294,6 +524,8 @@ pub(crate) mod inner {
     feature = "format_list",
     feature = "plurals",
     feature = "format_currency",
+    feature = "format_display_names",
+    feature = "collation",
 )))]
 pub(crate) mod inner {
     /// Supply a custom ICU data provider
@@ -310,6 +542,9 @@ pub(crate) mod data_provider {
         feature = "format_list",
         feature = "plurals",
         feature = "format_currency",
+        feature = "format_relative_time",
+        feature = "format_display_names",
+        feature = "collation",
     ))]
     use super::*;
 
@@ -319,6 +554,9 @@ pub(crate) mod data_provider {
         feature = "format_list",
         feature = "plurals",
         feature = "format_currency",
+        feature = "format_relative_time",
+        feature = "format_display_names",
+        feature = "collation",
     ))]
     use icu_provider::DataLocale;
 
@@ -332,6 +570,29 @@ pub(crate) mod data_provider {
             options: icu_decimal::options::FixedDecimalFormatterOptions,
         ) -> Result<FixedDecimalFormatter, icu_decimal::DecimalError>;
 
+        /// Tries to create a new short `CompactDecimalFormatter` with the given options
+        #[cfg(feature = "format_compact_nums")]
+        fn try_new_short_compact_num_formatter(
+            &self,
+            locale: &DataLocale,
+            options: FixedDecimalFormatterOptions,
+        ) -> Result<CompactDecimalFormatter, CompactDecimalError>;
+
+        /// Tries to create a new long `CompactDecimalFormatter` with the given options
+        #[cfg(feature = "format_compact_nums")]
+        fn try_new_long_compact_num_formatter(
+            &self,
+            locale: &DataLocale,
+            options: FixedDecimalFormatterOptions,
+        ) -> Result<CompactDecimalFormatter, CompactDecimalError>;
+
+        /// Tries to create a new `PercentFormatter`
+        #[cfg(feature = "format_percent")]
+        fn try_new_percent_formatter(
+            &self,
+            locale: &DataLocale,
+        ) -> Result<PercentFormatter, icu_provider::DataError>;
+
         /// Tries to create a new `DateFormatter` with the given options
         #[cfg(feature = "format_datetime")]
         fn try_new_date_formatter(
@@ -356,6 +617,22 @@ pub(crate) mod data_provider {
             options: icu_datetime::options::DateTimeFormatterOptions,
         ) -> Result<DateTimeFormatter, icu_datetime::DateTimeError>;
 
+        /// Tries to create a new `DateTimeFormatter` displaying the given components
+        #[cfg(feature = "format_datetime_components")]
+        fn try_new_components_formatter(
+            &self,
+            locale: &DataLocale,
+            options: icu_datetime::options::components::Bag,
+        ) -> Result<DateTimeFormatter, icu_datetime::DateTimeError>;
+
+        /// Tries to create a new `ZonedDateTimeFormatter` with the given options
+        #[cfg(feature = "format_zoned_datetime")]
+        fn try_new_zoned_datetime_formatter(
+            &self,
+            locale: &DataLocale,
+            options: icu_datetime::options::DateTimeFormatterOptions,
+        ) -> Result<ZonedDateTimeFormatter, icu_datetime::DateTimeError>;
+
         /// Tries to create a and `ListFormatter` with the given options
         #[cfg(feature = "format_list")]
         fn try_new_and_list_formatter(
@@ -395,6 +672,40 @@ pub(crate) mod data_provider {
             locale: &DataLocale,
             options: CurrencyFormatterOptions,
         ) -> Result<CurrencyFormatter, icu_provider::DataError>;
+
+        /// Tries to create a new `RelativeTimeFormatter` for the given style and unit
+        #[cfg(feature = "format_relative_time")]
+        fn try_new_relative_time_formatter(
+            &self,
+            locale: &DataLocale,
+            style: RelativeTimeStyle,
+            unit: RelativeTimeUnit,
+            options: icu_experimental::relativetime::options::RelativeTimeFormatterOptions,
+        ) -> Result<RelativeTimeFormatter, icu_experimental::relativetime::RelativeTimeError>;
+
+        /// Tries to create a new `LocaleDisplayNamesFormatter` with the given options
+        #[cfg(feature = "format_display_names")]
+        fn try_new_language_names_formatter(
+            &self,
+            locale: &DataLocale,
+            options: icu_experimental::displaynames::DisplayNamesOptions,
+        ) -> Result<LocaleDisplayNamesFormatter, icu_provider::DataError>;
+
+        /// Tries to create a new `RegionDisplayNames` with the given options
+        #[cfg(feature = "format_display_names")]
+        fn try_new_region_names_formatter(
+            &self,
+            locale: &DataLocale,
+            options: icu_experimental::displaynames::DisplayNamesOptions,
+        ) -> Result<RegionDisplayNames, icu_provider::DataError>;
+
+        /// Tries to create a new `Collator` with the given options
+        #[cfg(feature = "collation")]
+        fn try_new_collator(
+            &self,
+            locale: &DataLocale,
+            options: icu_collator::CollatorOptions,
+        ) -> Result<Collator, icu_collator::CollatorError>;
     }
 
     #[cfg(feature = "icu_compiled_data")]
@@ -412,6 +723,32 @@ pub(crate) mod data_provider {
             FixedDecimalFormatter::try_new(locale, options)
         }
 
+        #[cfg(feature = "format_compact_nums")]
+        fn try_new_short_compact_num_formatter(
+            &self,
+            locale: &DataLocale,
+            options: FixedDecimalFormatterOptions,
+        ) -> Result<CompactDecimalFormatter, CompactDecimalError> {
+            CompactDecimalFormatter::try_new_short(locale, options.into())
+        }
+
+        #[cfg(feature = "format_compact_nums")]
+        fn try_new_long_compact_num_formatter(
+            &self,
+            locale: &DataLocale,
+            options: FixedDecimalFormatterOptions,
+        ) -> Result<CompactDecimalFormatter, CompactDecimalError> {
+            CompactDecimalFormatter::try_new_long(locale, options.into())
+        }
+
+        #[cfg(feature = "format_percent")]
+        fn try_new_percent_formatter(
+            &self,
+            locale: &DataLocale,
+        ) -> Result<PercentFormatter, icu_provider::DataError> {
+            PercentFormatter::try_new(locale)
+        }
+
         #[cfg(feature = "format_datetime")]
         fn try_new_date_formatter(
             &self,
@@ -439,6 +776,24 @@ pub(crate) mod data_provider {
             DateTimeFormatter::try_new(locale, options)
         }
 
+        #[cfg(feature = "format_datetime_components")]
+        fn try_new_components_formatter(
+            &self,
+            locale: &DataLocale,
+            options: icu_datetime::options::components::Bag,
+        ) -> Result<DateTimeFormatter, icu_datetime::DateTimeError> {
+            DateTimeFormatter::try_new_experimental(locale, options.into())
+        }
+
+        #[cfg(feature = "format_zoned_datetime")]
+        fn try_new_zoned_datetime_formatter(
+            &self,
+            locale: &DataLocale,
+            options: icu_datetime::options::DateTimeFormatterOptions,
+        ) -> Result<ZonedDateTimeFormatter, icu_datetime::DateTimeError> {
+            ZonedDateTimeFormatter::try_new_experimental(locale, options, Default::default())
+        }
+
         #[cfg(feature = "format_list")]
         fn try_new_and_list_formatter(
             &self,
@@ -483,6 +838,45 @@ pub(crate) mod data_provider {
         ) -> Result<CurrencyFormatter, icu_provider::DataError> {
             CurrencyFormatter::try_new(locale, options)
         }
+
+        #[cfg(feature = "format_relative_time")]
+        fn try_new_relative_time_formatter(
+            &self,
+            locale: &DataLocale,
+            style: RelativeTimeStyle,
+            unit: RelativeTimeUnit,
+            options: icu_experimental::relativetime::options::RelativeTimeFormatterOptions,
+        ) -> Result<RelativeTimeFormatter, icu_experimental::relativetime::RelativeTimeError>
+        {
+            relative_time::try_new_relative_time_formatter(locale, style, unit, options)
+        }
+
+        #[cfg(feature = "format_display_names")]
+        fn try_new_language_names_formatter(
+            &self,
+            locale: &DataLocale,
+            options: icu_experimental::displaynames::DisplayNamesOptions,
+        ) -> Result<LocaleDisplayNamesFormatter, icu_provider::DataError> {
+            LocaleDisplayNamesFormatter::try_new(locale, options)
+        }
+
+        #[cfg(feature = "format_display_names")]
+        fn try_new_region_names_formatter(
+            &self,
+            locale: &DataLocale,
+            options: icu_experimental::displaynames::DisplayNamesOptions,
+        ) -> Result<RegionDisplayNames, icu_provider::DataError> {
+            RegionDisplayNames::try_new(locale, options)
+        }
+
+        #[cfg(feature = "collation")]
+        fn try_new_collator(
+            &self,
+            locale: &DataLocale,
+            options: icu_collator::CollatorOptions,
+        ) -> Result<Collator, icu_collator::CollatorError> {
+            Collator::try_new(locale, options)
+        }
     }
 
     #[cfg(not(feature = "icu_compiled_data"))]
@@ -508,6 +902,34 @@ pub(crate) mod data_provider {
             self.get_provider().try_new_num_formatter(locale, options)
         }
 
+        #[cfg(feature = "format_compact_nums")]
+        fn try_new_short_compact_num_formatter(
+            &self,
+            locale: &DataLocale,
+            options: FixedDecimalFormatterOptions,
+        ) -> Result<CompactDecimalFormatter, CompactDecimalError> {
+            self.get_provider()
+                .try_new_short_compact_num_formatter(locale, options)
+        }
+
+        #[cfg(feature = "format_compact_nums")]
+        fn try_new_long_compact_num_formatter(
+            &self,
+            locale: &DataLocale,
+            options: FixedDecimalFormatterOptions,
+        ) -> Result<CompactDecimalFormatter, CompactDecimalError> {
+            self.get_provider()
+                .try_new_long_compact_num_formatter(locale, options)
+        }
+
+        #[cfg(feature = "format_percent")]
+        fn try_new_percent_formatter(
+            &self,
+            locale: &DataLocale,
+        ) -> Result<PercentFormatter, icu_provider::DataError> {
+            self.get_provider().try_new_percent_formatter(locale)
+        }
+
         #[cfg(feature = "format_datetime")]
         fn try_new_date_formatter(
             &self,
@@ -536,6 +958,26 @@ pub(crate) mod data_provider {
                 .try_new_datetime_formatter(locale, options)
         }
 
+        #[cfg(feature = "format_datetime_components")]
+        fn try_new_components_formatter(
+            &self,
+            locale: &DataLocale,
+            options: icu_datetime::options::components::Bag,
+        ) -> Result<DateTimeFormatter, icu_datetime::DateTimeError> {
+            self.get_provider()
+                .try_new_components_formatter(locale, options)
+        }
+
+        #[cfg(feature = "format_zoned_datetime")]
+        fn try_new_zoned_datetime_formatter(
+            &self,
+            locale: &DataLocale,
+            options: icu_datetime::options::DateTimeFormatterOptions,
+        ) -> Result<ZonedDateTimeFormatter, icu_datetime::DateTimeError> {
+            self.get_provider()
+                .try_new_zoned_datetime_formatter(locale, options)
+        }
+
         #[cfg(feature = "format_list")]
         fn try_new_and_list_formatter(
             &self,
@@ -583,5 +1025,47 @@ pub(crate) mod data_provider {
             self.get_provider()
                 .try_new_currency_formatter(locale, options)
         }
+
+        #[cfg(feature = "format_relative_time")]
+        fn try_new_relative_time_formatter(
+            &self,
+            locale: &DataLocale,
+            style: RelativeTimeStyle,
+            unit: RelativeTimeUnit,
+            options: icu_experimental::relativetime::options::RelativeTimeFormatterOptions,
+        ) -> Result<RelativeTimeFormatter, icu_experimental::relativetime::RelativeTimeError>
+        {
+            self.get_provider()
+                .try_new_relative_time_formatter(locale, style, unit, options)
+        }
+
+        #[cfg(feature = "format_display_names")]
+        fn try_new_language_names_formatter(
+            &self,
+            locale: &DataLocale,
+            options: icu_experimental::displaynames::DisplayNamesOptions,
+        ) -> Result<LocaleDisplayNamesFormatter, icu_provider::DataError> {
+            self.get_provider()
+                .try_new_language_names_formatter(locale, options)
+        }
+
+        #[cfg(feature = "format_display_names")]
+        fn try_new_region_names_formatter(
+            &self,
+            locale: &DataLocale,
+            options: icu_experimental::displaynames::DisplayNamesOptions,
+        ) -> Result<RegionDisplayNames, icu_provider::DataError> {
+            self.get_provider()
+                .try_new_region_names_formatter(locale, options)
+        }
+
+        #[cfg(feature = "collation")]
+        fn try_new_collator(
+            &self,
+            locale: &DataLocale,
+            options: icu_collator::CollatorOptions,
+        ) -> Result<Collator, icu_collator::CollatorError> {
+            self.get_provider().try_new_collator(locale, options)
+        }
     }
 }
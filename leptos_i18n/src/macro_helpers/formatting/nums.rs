@@ -72,16 +72,79 @@ impl<T: IntoFixedDecimal, F: Fn() -> T + Clone + Send + Sync + 'static> NumberFo
     }
 }
 
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum RoundingMode {
+    Ceil,
+    Floor,
+    Expand,
+    Trunc,
+    HalfCeil,
+    HalfFloor,
+    HalfExpand,
+    HalfTrunc,
+    HalfEven,
+}
+
+impl RoundingMode {
+    fn round(self, number: &mut FixedDecimal, position: i16) {
+        match self {
+            RoundingMode::Ceil => number.ceil(position),
+            RoundingMode::Floor => number.floor(position),
+            RoundingMode::Expand => number.expand(position),
+            RoundingMode::Trunc => number.trunc(position),
+            RoundingMode::HalfCeil => number.half_ceil(position),
+            RoundingMode::HalfFloor => number.half_floor(position),
+            RoundingMode::HalfExpand => number.half_expand(position),
+            RoundingMode::HalfTrunc => number.half_trunc(position),
+            RoundingMode::HalfEven => number.half_even(position),
+        }
+    }
+}
+
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct NumberPrecision {
+    pub minimum_integer_digits: Option<u8>,
+    pub minimum_fraction_digits: Option<u8>,
+    pub maximum_fraction_digits: Option<u8>,
+    pub rounding_mode: RoundingMode,
+}
+
+impl NumberPrecision {
+    pub(crate) fn apply(self, mut number: FixedDecimal) -> FixedDecimal {
+        let minimum_fraction_digits = self.minimum_fraction_digits.map(i16::from);
+        if let Some(maximum_fraction_digits) = self.maximum_fraction_digits {
+            // the minimum wins if both are set and contradict each other.
+            let maximum_fraction_digits = minimum_fraction_digits
+                .unwrap_or(0)
+                .max(maximum_fraction_digits.into());
+            self.rounding_mode
+                .round(&mut number, -maximum_fraction_digits);
+            // like `Intl.NumberFormat`, only `minimum_fraction_digits` can keep trailing zeros once rounded.
+            number.trim_end();
+        }
+        if let Some(minimum_fraction_digits) = minimum_fraction_digits {
+            number.pad_end(-minimum_fraction_digits);
+        }
+        if let Some(minimum_integer_digits) = self.minimum_integer_digits {
+            number.pad_start(minimum_integer_digits.into());
+        }
+        number
+    }
+}
+
 #[doc(hidden)]
 pub fn format_number_to_view<L: Locale>(
     locale: L,
     number: impl NumberFormatterInputFn,
     grouping_strategy: GroupingStrategy,
+    precision: NumberPrecision,
 ) -> impl IntoView + Clone {
     let num_formatter = super::get_num_formatter(locale, grouping_strategy);
 
     move || {
-        let value = number.to_fixed_decimal();
+        let value = precision.apply(number.to_fixed_decimal());
         num_formatter.format_to_string(&value)
     }
 }
@@ -92,9 +155,10 @@ pub fn format_number_to_formatter<L: Locale>(
     locale: L,
     number: impl IntoFixedDecimal,
     grouping_strategy: GroupingStrategy,
+    precision: NumberPrecision,
 ) -> fmt::Result {
     let num_formatter = super::get_num_formatter(locale, grouping_strategy);
-    let fixed_dec = number.to_fixed_decimal();
+    let fixed_dec = precision.apply(number.to_fixed_decimal());
     let formatted_num = num_formatter.format(&fixed_dec);
     Display::fmt(&formatted_num, f)
 }
@@ -108,8 +172,9 @@ pub fn format_number_to_display<L: Locale>(
     locale: L,
     number: impl IntoFixedDecimal,
     grouping_strategy: GroupingStrategy,
+    precision: NumberPrecision,
 ) -> impl Display {
     let num_formatter = super::get_num_formatter(locale, grouping_strategy);
-    let fixed_dec = number.to_fixed_decimal();
+    let fixed_dec = precision.apply(number.to_fixed_decimal());
     num_formatter.format_to_string(&fixed_dec)
 }
@@ -0,0 +1,175 @@
+use core::fmt::{self, Display};
+
+use fixed_decimal::{FixedDecimal, Sign};
+use icu_decimal::{options::GroupingStrategy, FixedDecimalFormatter};
+use icu_experimental::dimension::provider::percent::{
+    PercentEssentialsV1, PercentEssentialsV1Marker,
+};
+use icu_provider::{DataError, DataLocale, DataPayload, DataProvider, DataRequest};
+use leptos::IntoView;
+
+use super::{IntoFixedDecimal, NumberFormatterInputFn};
+use crate::Locale;
+
+/// Formatter for percentages, `0.45` is formatted as "45%" in english.
+///
+/// Built from the `percent/essentials@1` data of `icu_experimental`,
+/// the number itself is formatted with a `FixedDecimalFormatter`.
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct PercentFormatter {
+    essentials: DataPayload<PercentEssentialsV1Marker>,
+}
+
+impl PercentFormatter {
+    /// Create a new `PercentFormatter` from compiled data.
+    #[cfg(feature = "icu_compiled_data")]
+    pub fn try_new(locale: &DataLocale) -> Result<Self, DataError> {
+        Self::try_new_unstable(&icu_experimental::provider::Baked, locale)
+    }
+
+    /// Create a new `PercentFormatter` from the given provider.
+    pub fn try_new_unstable<D>(provider: &D, locale: &DataLocale) -> Result<Self, DataError>
+    where
+        D: DataProvider<PercentEssentialsV1Marker> + ?Sized,
+    {
+        let essentials = provider
+            .load(DataRequest {
+                locale,
+                metadata: Default::default(),
+            })?
+            .take_payload()?;
+        Ok(PercentFormatter { essentials })
+    }
+
+    fn format<'a>(
+        &'a self,
+        num_formatter: &'a FixedDecimalFormatter,
+        number: &'a FixedDecimal,
+    ) -> FormattedPercent<'a> {
+        FormattedPercent {
+            essentials: self.essentials.get(),
+            num_formatter,
+            number,
+        }
+    }
+}
+
+struct FormattedPercent<'a> {
+    essentials: &'a PercentEssentialsV1<'a>,
+    num_formatter: &'a FixedDecimalFormatter,
+    number: &'a FixedDecimal,
+}
+
+impl Display for FormattedPercent<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let essentials = self.essentials;
+        let affixes = &essentials.percent_sign_affixes;
+        // the sign is placed where the pattern puts it, with the spacing around it.
+        if essentials.number_index <= essentials.percent_symbol_index {
+            return write!(
+                f,
+                "{}{}{}",
+                self.num_formatter.format(self.number),
+                affixes.prefix,
+                essentials.percent_sign_symbol
+            );
+        }
+        // CLDR negative patterns are the positive pattern with the minus sign in front,
+        // so the minus sign of the number moves before the percent sign: "-%45" and not "%-45".
+        let formatted_number = self.num_formatter.format_to_string(self.number);
+        let unsigned_number = self
+            .num_formatter
+            .format_to_string(&self.number.clone().with_sign(Sign::None));
+        let (sign, number) = match formatted_number.strip_suffix(&unsigned_number) {
+            Some(sign) => (sign, unsigned_number.as_str()),
+            None => ("", formatted_number.as_str()),
+        };
+        write!(
+            f,
+            "{}{}{}{}",
+            sign, essentials.percent_sign_symbol, affixes.suffix, number
+        )
+    }
+}
+
+fn to_percent(number: impl IntoFixedDecimal) -> FixedDecimal {
+    // `0.45` would otherwise be displayed as `045`.
+    number
+        .to_fixed_decimal()
+        .multiplied_pow10(2)
+        .trimmed_start()
+}
+
+#[doc(hidden)]
+pub fn format_percent_to_view<L: Locale>(
+    locale: L,
+    number: impl NumberFormatterInputFn,
+    grouping_strategy: GroupingStrategy,
+) -> impl IntoView + Clone {
+    let num_formatter = super::get_num_formatter(locale, grouping_strategy);
+    let percent_formatter = super::get_percent_formatter(locale);
+
+    move || {
+        let value = to_percent(number.to_fixed_decimal());
+        let formatted_percent = percent_formatter.format(num_formatter, &value).to_string();
+        formatted_percent
+    }
+}
+
+#[doc(hidden)]
+pub fn format_percent_to_formatter<L: Locale>(
+    f: &mut fmt::Formatter<'_>,
+    locale: L,
+    number: impl IntoFixedDecimal,
+    grouping_strategy: GroupingStrategy,
+) -> fmt::Result {
+    let num_formatter = super::get_num_formatter(locale, grouping_strategy);
+    let percent_formatter = super::get_percent_formatter(locale);
+    let fixed_dec = to_percent(number);
+    let formatted_percent = percent_formatter.format(num_formatter, &fixed_dec);
+    Display::fmt(&formatted_percent, f)
+}
+
+/// This function is a lie.
+/// The only reason it exist is for the `format` macros.
+/// It does NOT return a `impl Display` struct with no allocation like the other
+/// This directly return a `String` of the formatted num, because borrow issues.
+#[doc(hidden)]
+pub fn format_percent_to_display<L: Locale>(
+    locale: L,
+    number: impl IntoFixedDecimal,
+    grouping_strategy: GroupingStrategy,
+) -> impl Display {
+    let num_formatter = super::get_num_formatter(locale, grouping_strategy);
+    let percent_formatter = super::get_percent_formatter(locale);
+    let fixed_dec = to_percent(number);
+    let formatted_percent = percent_formatter
+        .format(num_formatter, &fixed_dec)
+        .to_string();
+    formatted_percent
+}
+
+#[cfg(all(test, feature = "icu_compiled_data"))]
+mod test {
+    use super::*;
+
+    fn format_percent(locale: &str, number: f64) -> String {
+        let locale = locale.parse::<icu_locid::Locale>().unwrap();
+        let num_formatter =
+            FixedDecimalFormatter::try_new(&(&locale).into(), Default::default()).unwrap();
+        let percent_formatter = PercentFormatter::try_new(&(&locale).into()).unwrap();
+        let number = to_percent(number);
+        percent_formatter
+            .format(&num_formatter, &number)
+            .to_string()
+    }
+
+    #[test]
+    fn test_negative_percent() {
+        assert_eq!(format_percent("en", 0.45), "45%");
+        assert_eq!(format_percent("en", -0.45), "-45%");
+        assert_eq!(format_percent("tr", 0.45), "%45");
+        assert_eq!(format_percent("tr", -0.45), "-%45");
+    }
+}
@@ -0,0 +1,231 @@
+use core::fmt::{self, Display};
+use core::time::Duration;
+
+use super::data_provider::IcuDataProvider;
+use super::IntoFixedDecimal;
+use fixed_decimal::FixedDecimal;
+use icu_experimental::relativetime::{options::Numeric, RelativeTimeFormatter};
+use leptos::IntoView;
+use writeable::Writeable;
+
+use crate::Locale;
+
+/// Unit of a relative time, such as the "days" of "3 days ago".
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum RelativeTimeUnit {
+    /// "in 5 seconds", "5 seconds ago"
+    Second,
+    /// "in 5 minutes", "5 minutes ago"
+    Minute,
+    /// "in 5 hours", "5 hours ago"
+    Hour,
+    /// "in 5 days", "5 days ago"
+    Day,
+    /// "in 5 weeks", "5 weeks ago"
+    Week,
+    /// "in 5 months", "5 months ago"
+    Month,
+    /// "in 5 quarters", "5 quarters ago"
+    Quarter,
+    /// "in 5 years", "5 years ago"
+    Year,
+}
+
+impl RelativeTimeUnit {
+    /// Return the largest unit fitting in the given amount of seconds, and the value in that unit.
+    /// The value is negative for a time in the past.
+    ///
+    /// ```rust
+    /// # use leptos_i18n::formatting::RelativeTimeUnit;
+    /// assert_eq!(RelativeTimeUnit::best_fit(-90), (-1, RelativeTimeUnit::Minute));
+    /// assert_eq!(RelativeTimeUnit::best_fit(3 * 24 * 3600), (3, RelativeTimeUnit::Day));
+    /// ```
+    pub const fn best_fit(seconds: i64) -> (i64, RelativeTimeUnit) {
+        const MINUTE: i64 = 60;
+        const HOUR: i64 = 60 * MINUTE;
+        const DAY: i64 = 24 * HOUR;
+        const WEEK: i64 = 7 * DAY;
+        const MONTH: i64 = 30 * DAY;
+        const YEAR: i64 = 365 * DAY;
+        let abs = seconds.unsigned_abs();
+        let (unit, unit_seconds) = if abs < MINUTE as u64 {
+            (RelativeTimeUnit::Second, 1)
+        } else if abs < HOUR as u64 {
+            (RelativeTimeUnit::Minute, MINUTE)
+        } else if abs < DAY as u64 {
+            (RelativeTimeUnit::Hour, HOUR)
+        } else if abs < WEEK as u64 {
+            (RelativeTimeUnit::Day, DAY)
+        } else if abs < MONTH as u64 {
+            (RelativeTimeUnit::Week, WEEK)
+        } else if abs < YEAR as u64 {
+            (RelativeTimeUnit::Month, MONTH)
+        } else {
+            (RelativeTimeUnit::Year, YEAR)
+        };
+        (seconds / unit_seconds, unit)
+    }
+}
+
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum RelativeTimeStyle {
+    Long,
+    Short,
+    Narrow,
+}
+
+/// Marker trait for types that can be formatted as a relative time.
+///
+/// Implemented for `(value, unit)` tuples, a negative value being in the past,
+/// and for `Duration`, always in the future and expressed in its largest unit.
+pub trait IntoRelativeTime: Clone {
+    /// Consume self to produce the value and its unit.
+    fn to_relative_time(self) -> (FixedDecimal, RelativeTimeUnit);
+}
+
+impl<T: IntoFixedDecimal> IntoRelativeTime for (T, RelativeTimeUnit) {
+    fn to_relative_time(self) -> (FixedDecimal, RelativeTimeUnit) {
+        (self.0.to_fixed_decimal(), self.1)
+    }
+}
+
+impl IntoRelativeTime for Duration {
+    fn to_relative_time(self) -> (FixedDecimal, RelativeTimeUnit) {
+        let seconds = i64::try_from(self.as_secs()).unwrap_or(i64::MAX);
+        let (value, unit) = RelativeTimeUnit::best_fit(seconds);
+        (value.into(), unit)
+    }
+}
+
+/// Marker trait for types that produce a `T: IntoRelativeTime`.
+pub trait RelativeTimeFormatterInputFn: Clone + Send + Sync + 'static {
+    /// Produce the value and its unit.
+    fn to_relative_time(&self) -> (FixedDecimal, RelativeTimeUnit);
+}
+
+impl<T: IntoRelativeTime, F: Fn() -> T + Clone + Send + Sync + 'static> RelativeTimeFormatterInputFn
+    for F
+{
+    fn to_relative_time(&self) -> (FixedDecimal, RelativeTimeUnit) {
+        IntoRelativeTime::to_relative_time(self())
+    }
+}
+
+#[doc(hidden)]
+pub fn format_relative_time_to_view<L: Locale>(
+    locale: L,
+    relative_time: impl RelativeTimeFormatterInputFn,
+    style: RelativeTimeStyle,
+    numeric: Numeric,
+) -> impl IntoView + Clone {
+    move || {
+        let (value, unit) = relative_time.to_relative_time();
+        let formatter = super::get_relative_time_formatter(locale, style, unit, numeric);
+        let mut formatted = String::new();
+        formatter.format(value).write_to(&mut formatted).unwrap();
+        formatted
+    }
+}
+
+#[doc(hidden)]
+pub fn format_relative_time_to_formatter<L: Locale>(
+    f: &mut fmt::Formatter<'_>,
+    locale: L,
+    relative_time: impl IntoRelativeTime,
+    style: RelativeTimeStyle,
+    numeric: Numeric,
+) -> fmt::Result {
+    let (value, unit) = relative_time.to_relative_time();
+    let formatter = super::get_relative_time_formatter(locale, style, unit, numeric);
+    formatter.format(value).write_to(f)
+}
+
+/// This function is a lie.
+/// The only reason it exist is for the `format` macros.
+/// It does NOT return a `impl Display` struct with no allocation like the other
+/// This directly return a `String` of the formatted relative time, because borrow issues.
+#[doc(hidden)]
+pub fn format_relative_time_to_display<L: Locale>(
+    locale: L,
+    relative_time: impl IntoRelativeTime,
+    style: RelativeTimeStyle,
+    numeric: Numeric,
+) -> impl Display {
+    let (value, unit) = relative_time.to_relative_time();
+    let formatter = super::get_relative_time_formatter(locale, style, unit, numeric);
+    let mut formatted = String::new();
+    formatter.format(value).write_to(&mut formatted).unwrap();
+    formatted
+}
+
+#[cfg(feature = "icu_compiled_data")]
+pub(crate) fn try_new_relative_time_formatter(
+    locale: &icu_provider::DataLocale,
+    style: RelativeTimeStyle,
+    unit: RelativeTimeUnit,
+    options: icu_experimental::relativetime::options::RelativeTimeFormatterOptions,
+) -> Result<RelativeTimeFormatter, icu_experimental::relativetime::RelativeTimeError> {
+    // ICU4X has one constructor per style and unit.
+    macro_rules! new_relative_time_formatter {
+        ($style:expr, $unit:expr, $locale:expr, $options:expr, $($style_variant:ident => [$($unit_variant:ident => $constructor:ident,)*],)*) => {
+            match ($style, $unit) {
+                $($(
+                    (RelativeTimeStyle::$style_variant, RelativeTimeUnit::$unit_variant) => {
+                        RelativeTimeFormatter::$constructor($locale, $options)
+                    }
+                )*)*
+            }
+        };
+    }
+
+    new_relative_time_formatter!(
+        style, unit, locale, options,
+        Long => [
+            Second => try_new_long_second,
+            Minute => try_new_long_minute,
+            Hour => try_new_long_hour,
+            Day => try_new_long_day,
+            Week => try_new_long_week,
+            Month => try_new_long_month,
+            Quarter => try_new_long_quarter,
+            Year => try_new_long_year,
+        ],
+        Short => [
+            Second => try_new_short_second,
+            Minute => try_new_short_minute,
+            Hour => try_new_short_hour,
+            Day => try_new_short_day,
+            Week => try_new_short_week,
+            Month => try_new_short_month,
+            Quarter => try_new_short_quarter,
+            Year => try_new_short_year,
+        ],
+        Narrow => [
+            Second => try_new_narrow_second,
+            Minute => try_new_narrow_minute,
+            Hour => try_new_narrow_hour,
+            Day => try_new_narrow_day,
+            Week => try_new_narrow_week,
+            Month => try_new_narrow_month,
+            Quarter => try_new_narrow_quarter,
+            Year => try_new_narrow_year,
+        ],
+    )
+}
+
+impl RelativeTimeStyle {
+    pub(crate) fn new_formatter(
+        self,
+        provider: &impl IcuDataProvider,
+        locale: &icu_locid::Locale,
+        unit: RelativeTimeUnit,
+        numeric: Numeric,
+    ) -> RelativeTimeFormatter {
+        let options =
+            icu_experimental::relativetime::options::RelativeTimeFormatterOptions { numeric };
+        provider
+            .try_new_relative_time_formatter(&locale.into(), self, unit, options)
+            .expect("A RelativeTimeFormatter")
+    }
+}
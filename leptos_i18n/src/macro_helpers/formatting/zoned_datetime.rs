@@ -0,0 +1,136 @@
+use std::fmt::{self, Display};
+
+use icu_calendar::AnyCalendar;
+use icu_datetime::{
+    input::{DateTimeInput, TimeZoneInput},
+    options::{length, DateTimeFormatterOptions},
+};
+use leptos::IntoView;
+
+use crate::Locale;
+
+use super::{AsIcuDateTime, DateTimeComponents, IntoIcuDateTime};
+
+/// Marker trait for types that can lend a reference to a datetime and its time zone,
+/// `T: icu::datetime::input::DateTimeInput<Calendar = icu::calendar::AnyCalendar>` and `Z: icu::datetime::input::TimeZoneInput`.
+pub trait AsIcuZonedDateTime {
+    /// The returned `T: DateTimeInput<Calendar = AnyCalendar>`.
+    type DateTime: DateTimeInput<Calendar = AnyCalendar>;
+    /// The returned `Z: TimeZoneInput`.
+    type TimeZone: TimeZoneInput;
+
+    /// Lend a reference to `Self::DateTime` and `Self::TimeZone`.
+    fn as_icu_zoned_datetime(&self) -> (&Self::DateTime, &Self::TimeZone);
+}
+
+impl<T: AsIcuDateTime, Z: TimeZoneInput> AsIcuZonedDateTime for (T, Z) {
+    type DateTime = T::DateTime;
+    type TimeZone = Z;
+
+    fn as_icu_zoned_datetime(&self) -> (&Self::DateTime, &Self::TimeZone) {
+        (self.0.as_icu_datetime(), &self.1)
+    }
+}
+
+/// Marker trait for types that can be turned into a datetime and its time zone,
+/// `T: icu::datetime::input::DateTimeInput<Calendar = icu::calendar::AnyCalendar>` and `Z: icu::datetime::input::TimeZoneInput`.
+pub trait IntoIcuZonedDateTime {
+    /// The returned `T: DateTimeInput<Calendar = AnyCalendar>`.
+    type DateTime: DateTimeInput<Calendar = AnyCalendar>;
+    /// The returned `Z: TimeZoneInput`.
+    type TimeZone: TimeZoneInput;
+
+    /// Consume self and return a `T: DateTimeInput<Calendar = AnyCalendar>` and a `Z: TimeZoneInput`.
+    fn into_icu_zoned_datetime(self) -> (Self::DateTime, Self::TimeZone);
+}
+
+impl<T: IntoIcuDateTime, Z: TimeZoneInput> IntoIcuZonedDateTime for (T, Z) {
+    type DateTime = T::DateTime;
+    type TimeZone = Z;
+
+    fn into_icu_zoned_datetime(self) -> (Self::DateTime, Self::TimeZone) {
+        (self.0.into_icu_datetime(), self.1)
+    }
+}
+
+/// Marker trait for types that produce a datetime and its time zone.
+pub trait ZonedDateTimeFormatterInputFn: 'static + Clone + Send + Sync {
+    /// The returned `T: DateTimeInput<Calendar = AnyCalendar>`.
+    type DateTime: DateTimeInput<Calendar = AnyCalendar>;
+    /// The returned `Z: TimeZoneInput`.
+    type TimeZone: TimeZoneInput;
+
+    /// Produce a `Self::DateTime` and a `Self::TimeZone`.
+    fn to_icu_zoned_datetime(&self) -> (Self::DateTime, Self::TimeZone);
+}
+
+impl<T: IntoIcuZonedDateTime, F: Fn() -> T + Clone + Send + Sync + 'static>
+    ZonedDateTimeFormatterInputFn for F
+{
+    type DateTime = T::DateTime;
+    type TimeZone = T::TimeZone;
+
+    fn to_icu_zoned_datetime(&self) -> (Self::DateTime, Self::TimeZone) {
+        IntoIcuZonedDateTime::into_icu_zoned_datetime(self())
+    }
+}
+
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ZonedDateTimeOptions {
+    Length(length::Date, length::Time),
+    Components(DateTimeComponents),
+}
+
+impl From<ZonedDateTimeOptions> for DateTimeFormatterOptions {
+    fn from(value: ZonedDateTimeOptions) -> Self {
+        match value {
+            ZonedDateTimeOptions::Length(date_length, time_length) => {
+                length::Bag::from_date_time_style(date_length, time_length).into()
+            }
+            ZonedDateTimeOptions::Components(components) => {
+                icu_datetime::options::components::Bag::from(components).into()
+            }
+        }
+    }
+}
+
+#[doc(hidden)]
+pub fn format_zoned_datetime_to_view<L: Locale>(
+    locale: L,
+    zoned_datetime: impl ZonedDateTimeFormatterInputFn,
+    options: ZonedDateTimeOptions,
+) -> impl IntoView + Clone {
+    let zoned_datetime_formatter = super::get_zoned_datetime_formatter(locale, options);
+
+    move || {
+        let (datetime, time_zone) = zoned_datetime.to_icu_zoned_datetime();
+        zoned_datetime_formatter
+            .format_to_string(&datetime, &time_zone)
+            .expect("The zoned datetime formatter to return a formatted zoned datetime.")
+    }
+}
+
+#[doc(hidden)]
+pub fn format_zoned_datetime_to_formatter<L: Locale>(
+    f: &mut fmt::Formatter<'_>,
+    locale: L,
+    zoned_datetime: &impl AsIcuZonedDateTime,
+    options: ZonedDateTimeOptions,
+) -> fmt::Result {
+    let formatted_date = format_zoned_datetime_to_display(locale, zoned_datetime, options);
+    Display::fmt(&formatted_date, f)
+}
+
+#[doc(hidden)]
+pub fn format_zoned_datetime_to_display<L: Locale>(
+    locale: L,
+    zoned_datetime: &impl AsIcuZonedDateTime,
+    options: ZonedDateTimeOptions,
+) -> impl Display {
+    let zoned_datetime_formatter = super::get_zoned_datetime_formatter(locale, options);
+    let (datetime, time_zone) = zoned_datetime.as_icu_zoned_datetime();
+    zoned_datetime_formatter
+        .format(datetime, time_zone)
+        .expect("The zoned datetime formatter to return a formatted zoned datetime.")
+}
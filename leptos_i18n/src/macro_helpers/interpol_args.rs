@@ -1,3 +1,7 @@
+use leptos::attr::custom::custom_attribute;
+use leptos::children::ChildrenFn;
+use leptos::tachys::html::attribute::any_attribute::{AnyAttribute, IntoAnyAttribute};
+use leptos::tachys::view::add_attr::AddAnyAttr;
 use leptos::IntoView;
 
 /// Marker trait for a type that can be used as an interpolation variable.
@@ -5,17 +9,117 @@ pub trait InterpolateVar: IntoView + Clone + 'static + Send + Sync {}
 
 impl<T: IntoView + Clone + 'static + Send + Sync> InterpolateVar for T {}
 
-/// Marker trait for a type that can be used as an interpolation component.
-pub trait InterpolateComp<O: IntoView + 'static>:
-    Fn(leptos::children::ChildrenFn) -> O + Clone + 'static + Send + Sync
+type Attrs<'a> = &'a [(&'static str, &'static str)];
+
+/// Trait for a type that can be used as an interpolation component.
+pub trait InterpolateComp<O: IntoView + 'static>: Clone + 'static + Send + Sync {
+    /// Render the component with the given children.
+    fn to_view(&self, children: ChildrenFn) -> O;
+}
+
+impl<O: IntoView + 'static, T: Fn(ChildrenFn) -> O + Clone + 'static + Send + Sync>
+    InterpolateComp<O> for T
+{
+    fn to_view(&self, children: ChildrenFn) -> O {
+        self(children)
+    }
+}
+
+/// Trait for a type that can be used as a self-closing interpolation component, such as `<br/>`.
+pub trait InterpolateCompSelfClosing<O: IntoView + 'static>: Clone + 'static + Send + Sync {
+    /// Render the component.
+    fn to_view(&self) -> O;
+}
+
+impl<O: IntoView + 'static, T: Fn() -> O + Clone + 'static + Send + Sync>
+    InterpolateCompSelfClosing<O> for T
+{
+    fn to_view(&self) -> O {
+        self()
+    }
+}
+
+/// Trait for a type that can be used as an interpolation component
+/// receiving the attributes declared in the translation, such as `<a href="/about">`.
+pub trait InterpolateCompWithAttrs<O: IntoView + 'static>: Clone + 'static + Send + Sync {
+    /// Render the component with the given children and attributes.
+    fn to_view(&self, children: ChildrenFn, attrs: Attrs) -> O;
+}
+
+impl<O: IntoView + 'static, T: Fn(ChildrenFn, Attrs) -> O + Clone + 'static + Send + Sync>
+    InterpolateCompWithAttrs<O> for T
 {
+    fn to_view(&self, children: ChildrenFn, attrs: Attrs) -> O {
+        self(children, attrs)
+    }
 }
 
-impl<
-        O: IntoView + 'static,
-        T: Fn(leptos::children::ChildrenFn) -> O + Clone + 'static + Send + Sync,
-    > InterpolateComp<O> for T
+/// Trait for a type that can be used as a self-closing interpolation component
+/// receiving the attributes declared in the translation, such as `<img src="/logo.png"/>`.
+pub trait InterpolateCompSelfClosingWithAttrs<O: IntoView + 'static>:
+    Clone + 'static + Send + Sync
 {
+    /// Render the component with the given attributes.
+    fn to_view(&self, attrs: Attrs) -> O;
+}
+
+impl<O: IntoView + 'static, T: Fn(Attrs) -> O + Clone + 'static + Send + Sync>
+    InterpolateCompSelfClosingWithAttrs<O> for T
+{
+    fn to_view(&self, attrs: Attrs) -> O {
+        self(attrs)
+    }
+}
+
+/// Component created by the `t!` macro from an element, `<b> = <span class="bold"/>`.
+///
+/// It can be used for any component, the attributes declared in the translation are added to the element.
+#[derive(Clone, Copy)]
+pub struct DirectComp<F>(pub F);
+
+fn attrs_to_any(attrs: Attrs) -> Vec<AnyAttribute> {
+    attrs
+        .iter()
+        .map(|(name, value)| custom_attribute(*name, *value).into_any_attr())
+        .collect()
+}
+
+impl<O: IntoView + 'static, F: Fn(ChildrenFn) -> O + Clone + 'static + Send + Sync>
+    InterpolateComp<O> for DirectComp<F>
+{
+    fn to_view(&self, children: ChildrenFn) -> O {
+        (self.0)(children)
+    }
+}
+
+impl<O: IntoView + 'static, F: Fn() -> O + Clone + 'static + Send + Sync>
+    InterpolateCompSelfClosing<O> for DirectComp<F>
+{
+    fn to_view(&self) -> O {
+        (self.0)()
+    }
+}
+
+impl<V, F> InterpolateCompWithAttrs<V::Output<Vec<AnyAttribute>>> for DirectComp<F>
+where
+    V: AddAnyAttr,
+    V::Output<Vec<AnyAttribute>>: IntoView + 'static,
+    F: Fn(ChildrenFn) -> V + Clone + 'static + Send + Sync,
+{
+    fn to_view(&self, children: ChildrenFn, attrs: Attrs) -> V::Output<Vec<AnyAttribute>> {
+        (self.0)(children).add_any_attr(attrs_to_any(attrs))
+    }
+}
+
+impl<V, F> InterpolateCompSelfClosingWithAttrs<V::Output<Vec<AnyAttribute>>> for DirectComp<F>
+where
+    V: AddAnyAttr,
+    V::Output<Vec<AnyAttribute>>: IntoView + 'static,
+    F: Fn() -> V + Clone + 'static + Send + Sync,
+{
+    fn to_view(&self, attrs: Attrs) -> V::Output<Vec<AnyAttribute>> {
+        (self.0)().add_any_attr(attrs_to_any(attrs))
+    }
 }
 
 /// Marker trait for a type that can be used to produce a count for a range key.
@@ -23,6 +127,18 @@ pub trait InterpolateRangeCount<T>: Fn() -> T + Clone + 'static + Send + Sync {}
 
 impl<T, F: Fn() -> T + Clone + 'static + Send + Sync> InterpolateRangeCount<T> for F {}
 
+/// Marker trait for a type that can be used to produce the selector of a select key.
+pub trait InterpolateSelect<S>: Clone + 'static + Send + Sync {
+    /// Return the selector.
+    fn select(&self) -> S;
+}
+
+impl<S, T: Into<S>, F: Fn() -> T + Clone + 'static + Send + Sync> InterpolateSelect<S> for F {
+    fn select(&self) -> S {
+        self().into()
+    }
+}
+
 /// Marker trait for a type that can produce a `icu::plurals::PluralOperands`
 #[cfg(feature = "plurals")]
 pub trait InterpolatePluralCount: Fn() -> Self::Count + Clone + 'static + Send + Sync {
@@ -1,11 +1,13 @@
 use core::fmt;
 use std::{fmt::Display, future::Future, marker::PhantomData};
 
+mod bidi;
 pub mod formatting;
 mod interpol_args;
 mod scope;
 
 use crate::Locale;
+pub use bidi::*;
 pub use formatting::*;
 pub use interpol_args::*;
 use leptos::IntoView;
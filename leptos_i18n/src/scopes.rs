@@ -166,6 +166,16 @@ impl<L: Locale, S: Scope<L>> Locale<L> for ScopedLocale<L, S> {
         <L as Locale>::direction(self.locale)
     }
 
+    #[cfg(feature = "format_display_names")]
+    fn native_name(self) -> &'static str {
+        <L as Locale>::native_name(self.locale)
+    }
+
+    #[cfg(feature = "format_display_names")]
+    fn display_name_in(self, other: L) -> &'static str {
+        <L as Locale>::display_name_in(self.locale, other)
+    }
+
     fn as_icu_locale(self) -> &'static IcuLocale {
         <L as Locale>::as_icu_locale(self.locale)
     }
@@ -0,0 +1,410 @@
+//! Helpers shared by the translation catalogs exporters and importers.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::io::{Error as IoError, ErrorKind};
+use std::path::PathBuf;
+
+use icu_plurals::{PluralRuleType as IcuRuleType, PluralRules};
+use leptos_i18n_parser::parse_locales::locale::{
+    find_file, BuildersKeys, BuildersKeysInner, FileFormat, InterpolOrLit, Locale, LocaleValue,
+};
+use leptos_i18n_parser::parse_locales::metadata::KeyMetadata;
+use leptos_i18n_parser::parse_locales::parsed_value::{escape_literal, Literal, ParsedValue};
+use leptos_i18n_parser::parse_locales::plurals::{PluralForm, PluralRuleType, Plurals};
+use leptos_i18n_parser::utils::Key;
+use serde_json::{Map, Value};
+
+pub(crate) fn strip_key_prefix<'a>(key: &'a Key, prefix: &str) -> &'a str {
+    key.name.strip_prefix(prefix).unwrap_or(&key.name)
+}
+
+// Opening tag of a component with its attributes, `<a href="/about">` or `<br/>` if self-closing.
+pub(crate) fn opening_tag(name: &str, attrs: &[(String, String)], self_closing: bool) -> String {
+    let mut tag = format!("<{}", name);
+    for (attr_name, value) in attrs {
+        let quote = if value.contains('"') { '\'' } else { '"' };
+        let _ = write!(tag, " {}={}{}{}", attr_name, quote, value, quote);
+    }
+    tag.push_str(if self_closing { "/>" } else { ">" });
+    tag
+}
+
+// Write the value back in the syntax used in translations,
+// return `None` if the value can't be represented as a single string.
+// `before_token` is set if the value is followed by more syntax, like a closing tag.
+fn value_to_source(value: &ParsedValue, buff: &mut String, before_token: bool) -> Option<()> {
+    match value {
+        ParsedValue::Literal(Literal::String(s, _)) => {
+            buff.push_str(&escape_literal(s, before_token))
+        }
+        ParsedValue::Literal(lit) => write!(buff, "{}", lit).ok()?,
+        ParsedValue::Variable { key, formatter } => {
+            let name = strip_key_prefix(key, "var_");
+            let formatter = formatter.to_string();
+            if formatter.is_empty() {
+                write!(buff, "{{{{ {} }}}}", name).ok()?
+            } else {
+                write!(buff, "{{{{ {}, {} }}}}", name, formatter).ok()?
+            }
+        }
+        ParsedValue::Component { key, inner, attrs } => {
+            let name = strip_key_prefix(key, "comp_");
+            buff.push_str(&opening_tag(name, attrs, inner.is_none()));
+            if let Some(inner) = inner {
+                value_to_source(inner, buff, true)?;
+                write!(buff, "</{}>", name).ok()?;
+            }
+        }
+        ParsedValue::Bloc(values) => {
+            for (index, value) in values.iter().enumerate() {
+                let before_token = values.get(index + 1).map_or(before_token, |next| {
+                    !matches!(next, ParsedValue::Literal(_) | ParsedValue::Default)
+                });
+                value_to_source(value, buff, before_token)?;
+            }
+        }
+        ParsedValue::ForeignKey(foreign_key) => value_to_source(
+            foreign_key.borrow().as_inner("value_to_source"),
+            buff,
+            before_token,
+        )?,
+        ParsedValue::Plurals(plurals) => inline_plural_source(plurals, buff)?,
+        ParsedValue::Default
+        | ParsedValue::Ranges(_)
+        | ParsedValue::Subkeys(_)
+        | ParsedValue::Select(_) => return None,
+    }
+    Some(())
+}
+
+// Write a plural in its inline form, `{{ count, plural(=0: 'none'; one: 'one'; other: 'many') }}`.
+fn inline_plural_source(plurals: &Plurals, buff: &mut String) -> Option<()> {
+    let name = strip_key_prefix(&plurals.count_key, "var_");
+    let mut args = vec![];
+    if plurals.rule_type == PluralRuleType::Ordinal {
+        args.push(format!("type: {}", plurals.rule_type));
+    }
+    let exact = plurals
+        .exact
+        .iter()
+        .map(|(number, value)| (format!("={}", number), value));
+    let forms = plurals
+        .forms
+        .iter()
+        .map(|(form, value)| (form_name(*form), value));
+    let other = std::iter::once(("other".to_string(), &*plurals.other));
+    for (arg_name, value) in exact.chain(forms).chain(other) {
+        let value = to_source(value)?;
+        // values are quoted without escapes, so they can't contain both quotes.
+        let quote = match (value.contains('\''), value.contains('"')) {
+            (false, _) => '\'',
+            (true, false) => '"',
+            (true, true) => return None,
+        };
+        args.push(format!("{}: {}{}{}", arg_name, quote, value, quote));
+    }
+    write!(buff, "{{{{ {}, plural({}) }}}}", name, args.join("; ")).ok()
+}
+
+pub(crate) fn to_source(value: &ParsedValue) -> Option<String> {
+    let mut buff = String::new();
+    value_to_source(value, &mut buff, false).map(|_| buff)
+}
+
+pub(crate) fn is_exportable(value: &ParsedValue) -> bool {
+    match value {
+        ParsedValue::Literal(lit) => lit.is_string().is_some(),
+        // exact-number overrides have no equivalent in catalogs.
+        ParsedValue::Plurals(plurals) => {
+            catalog_plurals(value).is_none() || plurals.exact.is_empty()
+        }
+        _ => true,
+    }
+}
+
+/// Plurals counted by `count`, exported with the plural support of the catalog.
+/// Inline plurals on other variables are exported in their `plural(...)` source form.
+pub(crate) fn catalog_plurals(value: &ParsedValue) -> Option<&Plurals> {
+    match value {
+        ParsedValue::Plurals(plurals) if plurals.count_key == Key::count() => Some(plurals),
+        _ => None,
+    }
+}
+
+/// Return the CLDR plural categories of the locale, or the error message on failure.
+pub(crate) fn plural_categories(
+    locale: &str,
+    rule_type: PluralRuleType,
+) -> Result<Vec<PluralForm>, String> {
+    let icu_locale = locale
+        .parse::<icu_locid::Locale>()
+        .map_err(|err| err.to_string())?;
+    let rule_type = match rule_type {
+        PluralRuleType::Cardinal => IcuRuleType::Cardinal,
+        PluralRuleType::Ordinal => IcuRuleType::Ordinal,
+    };
+    let plural_rules =
+        PluralRules::try_new(&icu_locale.into(), rule_type).map_err(|err| err.to_string())?;
+    Ok(plural_rules
+        .categories()
+        .map(PluralForm::from_icu_category)
+        .collect())
+}
+
+pub(crate) fn form_name(form: PluralForm) -> String {
+    // Display for PluralForm output the key suffix ("_one")
+    form.to_string().trim_start_matches('_').to_string()
+}
+
+/// Key suffix used when writing back a plural form, such as `_one` or `_ordinal_one`.
+pub(crate) fn form_key_suffix(rule_type: PluralRuleType, form: PluralForm) -> String {
+    match rule_type {
+        PluralRuleType::Cardinal => form.to_string(),
+        PluralRuleType::Ordinal => format!("_ordinal{}", form),
+    }
+}
+
+pub(crate) fn plural_form_source(plurals: &Plurals, form: PluralForm) -> Option<String> {
+    match form {
+        PluralForm::Other => to_source(&plurals.other),
+        form => to_source(plurals.forms.get(&form).unwrap_or(&plurals.other)),
+    }
+}
+
+/// Notes for translators built from the metadata of a key.
+pub(crate) fn metadata_notes(metadata: Option<&KeyMetadata>) -> Vec<String> {
+    let Some(metadata) = metadata else {
+        return vec![];
+    };
+    let mut notes = vec![];
+    notes.extend(metadata.description.clone());
+    if let Some(max_length) = metadata.max_length {
+        notes.push(format!("max length: {}", max_length));
+    }
+    if let Some(screenshot) = &metadata.screenshot {
+        notes.push(format!("screenshot: {}", screenshot));
+    }
+    notes
+}
+
+pub(crate) fn top_locales(builder_keys: &BuildersKeys) -> &[Locale] {
+    match builder_keys {
+        BuildersKeys::NameSpaces { namespaces, .. } => namespaces
+            .first()
+            .map(|ns| ns.locales.as_slice())
+            .unwrap_or_default(),
+        BuildersKeys::Locales { locales, .. } => locales,
+    }
+}
+
+pub(crate) fn locale_index(builder_keys: &BuildersKeys, locale: &str) -> Option<usize> {
+    top_locales(builder_keys)
+        .iter()
+        .position(|l| &*l.name.name == locale)
+}
+
+fn find_default_value_inner<'a>(
+    keys: &'a BuildersKeysInner,
+    locales: &'a [Locale],
+    path: &[&str],
+) -> Option<(&'a ParsedValue, &'a InterpolOrLit)> {
+    let (first, rest) = path.split_first()?;
+    let key = Key::new(first)?;
+    match keys.0.get(&key)? {
+        LocaleValue::Subkeys { locales, keys } => find_default_value_inner(keys, locales, rest),
+        LocaleValue::Value { value, .. } if rest.is_empty() => {
+            let default_value = locales.first()?.keys.get(&key)?;
+            Some((default_value, value))
+        }
+        LocaleValue::Value { .. } => None,
+    }
+}
+
+/// Find the value of the default locale at the given path,
+/// along with the interpolation keys of that key across all locales.
+pub(crate) fn find_default_value<'a>(
+    builder_keys: &'a BuildersKeys,
+    namespace: Option<&str>,
+    path: &[&str],
+) -> Option<(&'a ParsedValue, &'a InterpolOrLit)> {
+    match (builder_keys, namespace) {
+        (BuildersKeys::NameSpaces { namespaces, keys }, Some(namespace)) => {
+            let ns = namespaces.iter().find(|ns| &*ns.key.name == namespace)?;
+            let keys = keys.get(&ns.key)?;
+            find_default_value_inner(keys, &ns.locales, path)
+        }
+        (BuildersKeys::Locales { locales, keys }, None) => {
+            find_default_value_inner(keys, locales, path)
+        }
+        _ => None,
+    }
+}
+
+/// Translations imported from a translation catalog, ready to be written in the locales directory.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImportedTranslations {
+    locale: String,
+    values: BTreeMap<Option<String>, Value>,
+}
+
+// Merge the imported values into the content of a locale file, keys missing from `imported` are left untouched.
+fn merge_into(target: &mut Value, imported: &Value) {
+    match (target, imported) {
+        (Value::Object(target), Value::Object(imported)) => {
+            for (key, value) in imported {
+                match target.get_mut(key) {
+                    Some(existing) => merge_into(existing, value),
+                    None => {
+                        target.insert(key.clone(), value.clone());
+                    }
+                }
+            }
+        }
+        (target, imported) => *target = imported.clone(),
+    }
+}
+
+fn invalid_data(err: impl ToString) -> IoError {
+    IoError::new(ErrorKind::InvalidData, err.to_string())
+}
+
+fn read_locale_file(content: &str, format: FileFormat) -> std::io::Result<Value> {
+    match format {
+        FileFormat::Json => serde_json::from_str(content).map_err(invalid_data),
+        FileFormat::Yaml => serde_yaml::from_str(content).map_err(invalid_data),
+        #[cfg(feature = "json5_files")]
+        FileFormat::Json5 => json5::from_str(content).map_err(invalid_data),
+        _ => Err(IoError::new(
+            ErrorKind::Unsupported,
+            "translations can only be written in json, json5 or yaml files.",
+        )),
+    }
+}
+
+fn write_locale_file(value: &Value, format: FileFormat) -> std::io::Result<String> {
+    match format {
+        FileFormat::Yaml => serde_yaml::to_string(value).map_err(invalid_data),
+        // json is valid json5
+        _ => serde_json::to_string_pretty(value).map_err(invalid_data),
+    }
+}
+
+fn insert_at(map: &mut Map<String, Value>, path: &[&str], key: String, value: String) {
+    match path.split_first() {
+        None => {
+            map.insert(key, Value::String(value));
+        }
+        Some((first, rest)) => {
+            let entry = map
+                .entry(first.to_string())
+                .or_insert_with(|| Value::Object(Map::new()));
+            if let Value::Object(inner) = entry {
+                insert_at(inner, rest, key, value);
+            }
+        }
+    }
+}
+
+impl ImportedTranslations {
+    pub(crate) fn new(locale: &str) -> Self {
+        ImportedTranslations {
+            locale: locale.to_string(),
+            values: BTreeMap::new(),
+        }
+    }
+
+    pub(crate) fn insert(
+        &mut self,
+        namespace: Option<&str>,
+        parents: &[&str],
+        key: String,
+        value: String,
+    ) {
+        let map = self
+            .values
+            .entry(namespace.map(str::to_string))
+            .or_insert_with(|| Value::Object(Map::new()));
+        if let Value::Object(map) = map {
+            insert_at(map, parents, key, value);
+        }
+    }
+
+    /// Return the name of the locale of these translations.
+    pub fn locale(&self) -> &str {
+        &self.locale
+    }
+
+    /// Return the imported translations of the given namespace, or of the locale if `None`.
+    pub fn get(&self, namespace: Option<&str>) -> Option<&Value> {
+        self.values.get(&namespace.map(str::to_string))
+    }
+
+    /// Write the translations in the given locales directory,
+    /// merging them into the locale files the `load_locales!` macro reads.
+    ///
+    /// Each file keeps its path and format, and the keys absent from the catalog,
+    /// such as ranges or non-string values, are left untouched.
+    /// Only json, json5 and yaml files can be written, comments in the files are not kept.
+    pub fn write_to_dir<P: Into<PathBuf>>(&self, locales_dir: P) -> std::io::Result<()> {
+        let locales_dir: PathBuf = locales_dir.into();
+        for (namespace, imported) in &self.values {
+            let mut path = locales_dir.join(&self.locale);
+            if let Some(namespace) = namespace {
+                // nested namespaces ("admin/users") are in sub directories
+                path.extend(namespace.split('/'));
+            }
+            let (_, format) = find_file(&mut path)
+                .map_err(|err| IoError::new(ErrorKind::NotFound, err.to_string()))?;
+            let mut value = read_locale_file(&std::fs::read_to_string(&path)?, format)?;
+            merge_into(&mut value, imported);
+            let content = write_locale_file(&value, format)?;
+            // write next to the file then rename it, so a failure never leaves a truncated locale file
+            let mut tmp_path = path.clone().into_os_string();
+            tmp_path.push(".tmp");
+            std::fs::write(&tmp_path, content)?;
+            std::fs::rename(&tmp_path, &path)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_utils {
+    use std::path::{Path, PathBuf};
+
+    // Test project directory removed on drop, the pid keeps concurrent test runs apart.
+    pub(crate) struct TempDir(PathBuf);
+
+    impl TempDir {
+        pub(crate) fn new(name: &str) -> Self {
+            let path = std::env::temp_dir().join(format!(
+                "leptos_i18n_build_{}_{}",
+                name,
+                std::process::id()
+            ));
+            let _ = std::fs::remove_dir_all(&path);
+            std::fs::create_dir_all(path.join("locales")).unwrap();
+            std::fs::write(
+                path.join("Cargo.toml"),
+                "[package]\nname = \"roundtrip\"\n\n[package.metadata.leptos-i18n]\ndefault = \"en\"\nlocales = [\"en\", \"fr\"]\n",
+            )
+            .unwrap();
+            TempDir(path)
+        }
+
+        pub(crate) fn locales_dir(&self) -> PathBuf {
+            self.0.join("locales")
+        }
+
+        pub(crate) fn path(&self) -> &Path {
+            &self.0
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.0);
+        }
+    }
+}
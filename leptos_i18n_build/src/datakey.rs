@@ -3,7 +3,7 @@ use std::collections::HashSet;
 use icu_datagen::prelude::DataKey;
 use leptos_i18n_parser::{
     parse_locales::locale::{BuildersKeysInner, InterpolOrLit, LocaleValue, RangeOrPlural},
-    utils::formatter::Formatter,
+    utils::formatter::{DateTimeKind, Formatter, ListSort},
 };
 
 /// This enum represent the different `Fromatters` and options your translations could be using.
@@ -13,12 +13,24 @@ pub enum Options {
     Plurals,
     /// Use of the `date`, `time` or `datetime` formatter.
     FormatDateTime,
+    /// Use of the `zoned_datetime` formatter.
+    FormatZonedDateTime,
     /// Use of the `list` formatter.
     FormatList,
     /// Use of the `number` formatter.
     FormatNums,
+    /// Use of the `number` formatter with the compact notation.
+    FormatCompactNums,
+    /// Use of the `percent` formatter.
+    FormatPercent,
     /// Use of the `currency` formatter.
     FormatCurrency,
+    /// Use of the `relative_time` formatter.
+    FormatRelativeTime,
+    /// Use of the `language_name` or `region_name` formatter.
+    FormatDisplayNames,
+    /// Use of `sort_by_locale` or of the `list(sort: true)` formatter.
+    Collation,
 }
 
 pub fn find_used_datakey(keys: &BuildersKeysInner, used_icu_keys: &mut HashSet<Options>) {
@@ -42,12 +54,25 @@ pub fn find_used_datakey(keys: &BuildersKeysInner, used_icu_keys: &mut HashSet<O
                     for formatter in &var_infos.formatters {
                         let dk = match formatter {
                             Formatter::None => continue,
-                            Formatter::Number(_) => Options::FormatNums,
-                            Formatter::Date(_) | Formatter::Time(_) | Formatter::DateTime(_, _) => {
-                                Options::FormatDateTime
+                            Formatter::Number(..) => Options::FormatNums,
+                            Formatter::CompactNumber(_) => Options::FormatCompactNums,
+                            Formatter::Percent(_) => Options::FormatPercent,
+                            Formatter::DateTimeComponents(DateTimeKind::ZonedDateTime, _)
+                            | Formatter::ZonedDateTime(_, _) => Options::FormatZonedDateTime,
+                            Formatter::Date(_)
+                            | Formatter::Time(_)
+                            | Formatter::DateTime(_, _)
+                            | Formatter::DateTimeComponents(_, _) => Options::FormatDateTime,
+                            Formatter::List(_, _, ListSort::Sorted) => {
+                                used_icu_keys.insert(Options::Collation);
+                                Options::FormatList
+                            }
+                            Formatter::List(_, _, ListSort::Unsorted) => Options::FormatList,
+                            Formatter::Currency(..) => Options::FormatCurrency,
+                            Formatter::RelativeTime(_, _) => Options::FormatRelativeTime,
+                            Formatter::LanguageName(_) | Formatter::RegionName(_) => {
+                                Options::FormatDisplayNames
                             }
-                            Formatter::List(_, _) => Options::FormatList,
-                            Formatter::Currency(_, _) => Options::FormatCurrency,
                         };
                         used_icu_keys.insert(dk);
                     }
@@ -106,13 +131,113 @@ impl Options {
                 "datetime/roc/datelengths@1",
                 "datetime/roc/datesymbols@1",
             ]),
+            Options::FormatZonedDateTime => icu_datagen::keys(&[
+                "datetime/timesymbols@1",
+                "datetime/timelengths@1",
+                "datetime/skeletons@1",
+                "plurals/ordinal@1",
+                "datetime/week_data@1",
+                "decimal/symbols@1",
+                "datetime/gregory/datelengths@1",
+                "datetime/gregory/datesymbols@1",
+                "datetime/buddhist/datelengths@1",
+                "datetime/buddhist/datesymbols@1",
+                "calendar/chinesecache@1",
+                "datetime/chinese/datelengths@1",
+                "datetime/chinese/datesymbols@1",
+                "datetime/coptic/datelengths@1",
+                "datetime/coptic/datesymbols@1",
+                "calendar/dangicache@1",
+                "datetime/dangi/datelengths@1",
+                "datetime/dangi/datesymbols@1",
+                "datetime/ethiopic/datelengths@1",
+                "datetime/ethiopic/datesymbols@1",
+                "datetime/hebrew/datelengths@1",
+                "datetime/hebrew/datesymbols@1",
+                "datetime/indian/datelengths@1",
+                "datetime/indian/datesymbols@1",
+                "datetime/islamic/datelengths@1",
+                "datetime/islamic/datesymbols@1",
+                "calendar/islamicobservationalcache@1",
+                "calendar/islamicummalquracache@1",
+                "datetime/japanese/datelengths@1",
+                "datetime/japanese/datesymbols@1",
+                "calendar/japanese@1",
+                "datetime/japanext/datelengths@1",
+                "datetime/japanext/datesymbols@1",
+                "calendar/japanext@1",
+                "datetime/persian/datelengths@1",
+                "datetime/persian/datesymbols@1",
+                "datetime/roc/datelengths@1",
+                "datetime/roc/datesymbols@1",
+                "time_zone/formats@1",
+                "time_zone/exemplar_cities@1",
+                "time_zone/generic_long@1",
+                "time_zone/generic_short@1",
+                "time_zone/specific_long@1",
+                "time_zone/specific_short@1",
+            ]),
             Options::FormatList => icu_datagen::keys(&["list/and@1", "list/or@1", "list/unit@1"]),
             Options::FormatNums => icu_datagen::keys(&["decimal/symbols@1"]),
+            Options::FormatCompactNums => icu_datagen::keys(&[
+                "decimal/symbols@1",
+                "plurals/cardinal@1",
+                "compactdecimal/short@1",
+                "compactdecimal/long@1",
+            ]),
+            Options::FormatPercent => {
+                icu_datagen::keys(&["decimal/symbols@1", "percent/essentials@1"])
+            }
             Options::FormatCurrency => icu_datagen::keys(&[
                 "decimal/digits@1",
                 "decimal/symbols@2",
                 "currency/essentials@1",
             ]),
+            Options::FormatRelativeTime => icu_datagen::keys(&[
+                "plurals/cardinal@1",
+                "decimal/symbols@1",
+                "relativetime/long/second@1",
+                "relativetime/short/second@1",
+                "relativetime/narrow/second@1",
+                "relativetime/long/minute@1",
+                "relativetime/short/minute@1",
+                "relativetime/narrow/minute@1",
+                "relativetime/long/hour@1",
+                "relativetime/short/hour@1",
+                "relativetime/narrow/hour@1",
+                "relativetime/long/day@1",
+                "relativetime/short/day@1",
+                "relativetime/narrow/day@1",
+                "relativetime/long/week@1",
+                "relativetime/short/week@1",
+                "relativetime/narrow/week@1",
+                "relativetime/long/month@1",
+                "relativetime/short/month@1",
+                "relativetime/narrow/month@1",
+                "relativetime/long/quarter@1",
+                "relativetime/short/quarter@1",
+                "relativetime/narrow/quarter@1",
+                "relativetime/long/year@1",
+                "relativetime/short/year@1",
+                "relativetime/narrow/year@1",
+            ]),
+            Options::FormatDisplayNames => icu_datagen::keys(&[
+                "displaynames/languages@1",
+                "displaynames/locales@1",
+                "displaynames/regions@1",
+                "displaynames/scripts@1",
+                "displaynames/variants@1",
+            ]),
+            Options::Collation => icu_datagen::keys(&[
+                "collator/data@1",
+                "collator/dia@1",
+                "collator/jamo@1",
+                "collator/meta@1",
+                "collator/prim@1",
+                "collator/reord@1",
+                "normalizer/nfd@1",
+                "normalizer/nfdex@1",
+            ]),
         }
     }
 }
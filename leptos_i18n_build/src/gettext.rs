@@ -0,0 +1,833 @@
+//! Import and export of the translations as GNU gettext PO catalogs.
+
+use std::fmt::{Display, Write};
+
+use icu_plurals::provider::{Baked, CardinalV1Marker};
+use icu_plurals::rules::reference::ast::{
+    Condition, Operand, Operator, RangeListItem, Relation, Rule,
+};
+use icu_provider::prelude::{DataLocale, DataProvider, DataRequest};
+
+use leptos_i18n_parser::parse_locales::locale::{
+    BuildersKeys, BuildersKeysInner, Locale, LocaleValue,
+};
+use leptos_i18n_parser::parse_locales::parsed_value::ParsedValue;
+use leptos_i18n_parser::parse_locales::plurals::{PluralForm, PluralRuleType};
+use leptos_i18n_parser::utils::KeyPath;
+
+use crate::catalog::{
+    catalog_plurals, find_default_value, form_key_suffix, form_name, is_exportable, locale_index,
+    metadata_notes, plural_categories, plural_form_source, to_source, ImportedTranslations,
+};
+
+/// Errors that can occur while exporting or importing PO catalogs.
+#[derive(Debug)]
+pub enum PoError {
+    /// The given locale is not declared in the configuration.
+    UnknownLocale(String),
+    /// A `msgctxt` of the catalog does not match any key of the default locale.
+    UnknownKey(String),
+    /// The catalog is malformed.
+    Syntax {
+        /// Line of the error, starting at 1.
+        line: usize,
+        /// Description of the error.
+        message: String,
+    },
+    /// Error while computing the plural categories of a locale.
+    PluralRules {
+        /// The locale.
+        locale: String,
+        /// The error message.
+        message: String,
+    },
+    /// The `Plural-Forms` header of the catalog does not match the plural categories of the locale.
+    PluralForms {
+        /// The locale.
+        locale: String,
+        /// The error message.
+        message: String,
+    },
+    /// IO error while reading or writing files.
+    Io(std::io::Error),
+}
+
+impl Display for PoError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PoError::UnknownLocale(locale) => write!(f, "Unknown locale {:?}", locale),
+            PoError::UnknownKey(key) => write!(f, "Unknown key \"{}\"", key),
+            PoError::Syntax { line, message } => {
+                write!(f, "Malformed PO catalog at line {}: {}", line, message)
+            }
+            PoError::PluralRules { locale, message } => write!(
+                f,
+                "Error while computing plurals categories of locale {:?}: {}",
+                locale, message
+            ),
+            PoError::PluralForms { locale, message } => write!(
+                f,
+                "Invalid Plural-Forms header for locale {:?}: {}",
+                locale, message
+            ),
+            PoError::Io(err) => Display::fmt(err, f),
+        }
+    }
+}
+
+impl std::error::Error for PoError {}
+
+impl From<std::io::Error> for PoError {
+    fn from(value: std::io::Error) -> Self {
+        PoError::Io(value)
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+struct PoEntry {
+    comments: Vec<String>,
+    fuzzy: bool,
+    msgctxt: Option<String>,
+    msgid: String,
+    msgid_plural: Option<String>,
+    msgstr: Vec<String>,
+}
+
+fn escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn unescape(s: &str, line: usize) -> Result<String, PoError> {
+    let mut unescaped = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => unescaped.push('\n'),
+            Some('r') => unescaped.push('\r'),
+            Some('t') => unescaped.push('\t'),
+            Some(c @ ('"' | '\\')) => unescaped.push(c),
+            _ => {
+                return Err(PoError::Syntax {
+                    line,
+                    message: "invalid escape sequence".into(),
+                })
+            }
+        }
+    }
+    Ok(unescaped)
+}
+
+fn parse_quoted(s: &str, line: usize) -> Result<String, PoError> {
+    let s = s.trim();
+    match s.strip_prefix('"').and_then(|s| s.strip_suffix('"')) {
+        Some(inner) => unescape(inner, line),
+        None => Err(PoError::Syntax {
+            line,
+            message: "expected a quoted string".into(),
+        }),
+    }
+}
+
+impl Display for PoEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for comment in &self.comments {
+            writeln!(f, "#. {}", comment)?;
+        }
+        if self.fuzzy {
+            writeln!(f, "#, fuzzy")?;
+        }
+        if let Some(msgctxt) = &self.msgctxt {
+            writeln!(f, "msgctxt \"{}\"", escape(msgctxt))?;
+        }
+        writeln!(f, "msgid \"{}\"", escape(&self.msgid))?;
+        match &self.msgid_plural {
+            Some(msgid_plural) => {
+                writeln!(f, "msgid_plural \"{}\"", escape(msgid_plural))?;
+                for (i, msgstr) in self.msgstr.iter().enumerate() {
+                    writeln!(f, "msgstr[{}] \"{}\"", i, escape(msgstr))?;
+                }
+            }
+            None => {
+                let msgstr = self.msgstr.first().map(String::as_str).unwrap_or_default();
+                writeln!(f, "msgstr \"{}\"", escape(msgstr))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn parse_po(content: &str) -> Result<Vec<PoEntry>, PoError> {
+    enum Field {
+        Msgctxt,
+        Msgid,
+        MsgidPlural,
+        Msgstr(usize),
+    }
+
+    fn field_mut<'a>(entry: &'a mut PoEntry, field: &Field) -> &'a mut String {
+        match field {
+            Field::Msgctxt => entry.msgctxt.get_or_insert_with(String::new),
+            Field::Msgid => &mut entry.msgid,
+            Field::MsgidPlural => entry.msgid_plural.get_or_insert_with(String::new),
+            Field::Msgstr(index) => {
+                if entry.msgstr.len() <= *index {
+                    entry.msgstr.resize(*index + 1, String::new());
+                }
+                &mut entry.msgstr[*index]
+            }
+        }
+    }
+
+    let mut entries = vec![];
+    let mut current = PoEntry::default();
+    // the last keyword encountered in the current entry, used for multilines strings.
+    let mut field = None;
+    let mut seen_msgid = false;
+
+    for (i, line) in content.lines().enumerate() {
+        let line_nb = i + 1;
+        let line = line.trim();
+        if line.is_empty() || line.starts_with("#~") {
+            continue;
+        }
+        if let Some(comment) = line.strip_prefix('#') {
+            if field.is_some() {
+                entries.push(std::mem::take(&mut current));
+                field = None;
+                seen_msgid = false;
+            }
+            if let Some(flags) = comment.strip_prefix(',') {
+                current.fuzzy |= flags.split(',').any(|flag| flag.trim() == "fuzzy");
+            } else if let Some(comment) = comment.strip_prefix('.') {
+                current.comments.push(comment.trim().to_string());
+            }
+            continue;
+        }
+        if line.starts_with('"') {
+            let Some(field) = &field else {
+                return Err(PoError::Syntax {
+                    line: line_nb,
+                    message: "unexpected string".into(),
+                });
+            };
+            let s = parse_quoted(line, line_nb)?;
+            field_mut(&mut current, field).push_str(&s);
+            continue;
+        }
+        let (keyword, rest) = line
+            .split_once(char::is_whitespace)
+            .ok_or(PoError::Syntax {
+                line: line_nb,
+                message: "expected a keyword followed by a string".into(),
+            })?;
+        let new_field = match keyword {
+            "msgctxt" => Field::Msgctxt,
+            "msgid" => Field::Msgid,
+            "msgid_plural" => Field::MsgidPlural,
+            "msgstr" => Field::Msgstr(0),
+            keyword => {
+                let index = keyword
+                    .strip_prefix("msgstr[")
+                    .and_then(|s| s.strip_suffix(']'))
+                    .and_then(|s| s.parse().ok());
+                match index {
+                    Some(index) => Field::Msgstr(index),
+                    None => {
+                        return Err(PoError::Syntax {
+                            line: line_nb,
+                            message: format!("unknown keyword {:?}", keyword),
+                        })
+                    }
+                }
+            }
+        };
+        // an entry without comments start with either msgctxt or msgid
+        let starts_entry = match new_field {
+            Field::Msgctxt => field.is_some(),
+            Field::Msgid => seen_msgid,
+            _ => false,
+        };
+        if starts_entry {
+            entries.push(std::mem::take(&mut current));
+            seen_msgid = false;
+        }
+        seen_msgid |= matches!(new_field, Field::Msgid);
+        let s = parse_quoted(rest, line_nb)?;
+        field_mut(&mut current, &new_field).push_str(&s);
+        field = Some(new_field);
+    }
+
+    if field.is_some() {
+        entries.push(current);
+    }
+
+    Ok(entries)
+}
+
+fn po_plural_categories(
+    locale: &str,
+    rule_type: PluralRuleType,
+) -> Result<Vec<PluralForm>, PoError> {
+    plural_categories(locale, rule_type).map_err(|message| PoError::PluralRules {
+        locale: locale.to_string(),
+        message,
+    })
+}
+
+// gettext plural expressions only get the integer `n`, the fraction and exponent operands are 0.
+fn operand_source(operand: Operand) -> Option<&'static str> {
+    match operand {
+        Operand::N | Operand::I => Some("n"),
+        Operand::V | Operand::W | Operand::F | Operand::T | Operand::C | Operand::E => None,
+    }
+}
+
+fn range_contains(item: &RangeListItem, value: u64) -> bool {
+    match item {
+        RangeListItem::Range(range) => range.start().0 <= value && value <= range.end().0,
+        RangeListItem::Value(v) => v.0 == value,
+    }
+}
+
+// C expression of the relation, `Err(result)` if it does not depend on `n`.
+fn relation_source(relation: &Relation) -> Result<String, bool> {
+    let eq = relation.operator == Operator::Eq;
+    let Some(operand) = operand_source(relation.expression.operand) else {
+        let contains = relation
+            .range_list
+            .0
+            .iter()
+            .any(|item| range_contains(item, 0));
+        return Err(contains == eq);
+    };
+    let operand = match &relation.expression.modulus {
+        Some(modulus) => format!("{} % {}", operand, modulus.0),
+        None => operand.to_string(),
+    };
+    let items = relation
+        .range_list
+        .0
+        .iter()
+        .map(|item| match (item, eq) {
+            (RangeListItem::Value(v), true) => format!("{} == {}", operand, v.0),
+            (RangeListItem::Value(v), false) => format!("{} != {}", operand, v.0),
+            (RangeListItem::Range(range), true) => format!(
+                "({} >= {} && {} <= {})",
+                operand,
+                range.start().0,
+                operand,
+                range.end().0
+            ),
+            (RangeListItem::Range(range), false) => format!(
+                "({} < {} || {} > {})",
+                operand,
+                range.start().0,
+                operand,
+                range.end().0
+            ),
+        })
+        .collect::<Vec<_>>();
+    if items.len() == 1 {
+        Ok(items.join(""))
+    } else {
+        Ok(format!(
+            "({})",
+            items.join(if eq { " || " } else { " && " })
+        ))
+    }
+}
+
+// C expression of the condition, `Err(result)` if it does not depend on `n`.
+fn condition_source(condition: &Condition) -> Result<String, bool> {
+    let mut or_sources = vec![];
+    for and_condition in &condition.0 {
+        let mut and_sources = vec![];
+        let mut matches = true;
+        for relation in &and_condition.0 {
+            match relation_source(relation) {
+                Ok(source) => and_sources.push(source),
+                Err(result) => matches &= result,
+            }
+        }
+        match (matches, and_sources.is_empty()) {
+            (false, _) => {}
+            (true, true) => return Err(true),
+            (true, false) => or_sources.push(and_sources.join(" && ")),
+        }
+    }
+    match or_sources.len() {
+        0 => Err(false),
+        1 => Ok(or_sources.join("")),
+        _ => Ok(format!("({})", or_sources.join(" || "))),
+    }
+}
+
+/// Value of the `Plural-Forms` header for the cardinal rules of the locale,
+/// the indexes of the expression follow the order of the CLDR categories.
+fn plural_forms_header(locale: &str) -> Result<String, PoError> {
+    let plural_rules_error = |message: String| PoError::PluralRules {
+        locale: locale.to_string(),
+        message,
+    };
+    let icu_locale = locale
+        .parse::<icu_locid::Locale>()
+        .map_err(|err| plural_rules_error(err.to_string()))?;
+    let response: icu_provider::DataResponse<CardinalV1Marker> = Baked
+        .load(DataRequest {
+            locale: &DataLocale::from(&icu_locale),
+            metadata: Default::default(),
+        })
+        .map_err(|err| plural_rules_error(err.to_string()))?;
+    let payload = response
+        .take_payload()
+        .map_err(|err| plural_rules_error(err.to_string()))?;
+    let rules = payload.get();
+
+    let mut conditions = vec![];
+    for rule in [&rules.zero, &rules.one, &rules.two, &rules.few, &rules.many]
+        .into_iter()
+        .flatten()
+    {
+        let condition = condition_source(&Rule::from(rule).condition);
+        conditions.push(condition);
+    }
+    let nplurals = conditions.len() + 1;
+    let mut plural = conditions.len().to_string();
+    for (index, condition) in conditions.into_iter().enumerate().rev() {
+        plural = match condition {
+            Ok(condition) => format!("{} ? {} : {}", condition, index, plural),
+            Err(true) => index.to_string(),
+            Err(false) => plural,
+        };
+    }
+    Ok(format!("nplurals={}; plural={};", nplurals, plural))
+}
+
+// Parse the number of plurals of a `Plural-Forms` header.
+fn parse_nplurals(header: &str) -> Option<usize> {
+    header
+        .split(';')
+        .find_map(|part| part.trim().strip_prefix("nplurals="))
+        .and_then(|nplurals| nplurals.trim().parse().ok())
+}
+
+struct Exporter<'a> {
+    // `None` when exporting the template.
+    locale: Option<(&'a str, usize)>,
+    entries: Vec<PoEntry>,
+}
+
+impl Exporter<'_> {
+    fn export_value(
+        &mut self,
+        key_path: &KeyPath,
+        default_value: &ParsedValue,
+        value: Option<&ParsedValue>,
+        mut comments: Vec<String>,
+    ) -> Result<(), PoError> {
+        if !is_exportable(default_value) {
+            return Ok(());
+        }
+        let msgctxt = Some(key_path.to_string());
+        let entry = if let Some(plurals) = catalog_plurals(default_value) {
+            let Some(msgid_plural) = to_source(&plurals.other) else {
+                return Ok(());
+            };
+            let msgid = plural_form_source(plurals, PluralForm::One).unwrap_or_default();
+            let msgstr = match self.locale {
+                None => vec![String::new(); 2],
+                Some((locale, _)) => {
+                    let forms = po_plural_categories(locale, plurals.rule_type)?;
+                    let names = forms.iter().copied().map(form_name).collect::<Vec<_>>();
+                    comments.push(format!(
+                        "{} plural: {}",
+                        plurals.rule_type,
+                        names.join(", ")
+                    ));
+                    let msgstr = forms
+                        .iter()
+                        .map(|form| match value {
+                            Some(ParsedValue::Plurals(plurals)) => {
+                                plural_form_source(plurals, *form).unwrap_or_default()
+                            }
+                            _ => String::new(),
+                        })
+                        .collect();
+                    msgstr
+                }
+            };
+            PoEntry {
+                comments,
+                fuzzy: false,
+                msgctxt,
+                msgid,
+                msgid_plural: Some(msgid_plural),
+                msgstr,
+            }
+        } else {
+            let Some(msgid) = to_source(default_value) else {
+                return Ok(());
+            };
+            let msgstr = value.and_then(to_source).unwrap_or_default();
+            PoEntry {
+                comments,
+                msgctxt,
+                msgid,
+                msgstr: vec![msgstr],
+                ..Default::default()
+            }
+        };
+        self.entries.push(entry);
+        Ok(())
+    }
+
+    fn export_keys(
+        &mut self,
+        keys: &BuildersKeysInner,
+        locales: &[Locale],
+        key_path: &mut KeyPath,
+    ) -> Result<(), PoError> {
+        let Some(default_locale) = locales.first() else {
+            return Ok(());
+        };
+        let locale = self.locale.and_then(|(_, index)| locales.get(index));
+        for (key, locale_value) in &keys.0 {
+            key_path.push_key(key.clone());
+            match locale_value {
+                LocaleValue::Subkeys { locales, keys } => {
+                    self.export_keys(keys, locales, key_path)?;
+                }
+                LocaleValue::Value { .. } => {
+                    if let Some(default_value) = default_locale.keys.get(key) {
+                        let value = locale.and_then(|locale| locale.keys.get(key));
+                        let comments = metadata_notes(default_locale.metadata.get(key));
+                        self.export_value(key_path, default_value, value, comments)?;
+                    }
+                }
+            }
+            key_path.pop_key();
+        }
+        Ok(())
+    }
+
+    fn export(mut self, builder_keys: &BuildersKeys) -> Result<String, PoError> {
+        match builder_keys {
+            BuildersKeys::NameSpaces { namespaces, keys } => {
+                for namespace in namespaces {
+                    let mut key_path = KeyPath::new(Some(namespace.key.clone()));
+                    if let Some(keys) = keys.get(&namespace.key) {
+                        self.export_keys(keys, &namespace.locales, &mut key_path)?;
+                    }
+                }
+            }
+            BuildersKeys::Locales { locales, keys } => {
+                self.export_keys(keys, locales, &mut KeyPath::new(None))?;
+            }
+        }
+
+        let mut header = String::from("Content-Type: text/plain; charset=UTF-8\n");
+        if let Some((locale, _)) = self.locale {
+            writeln!(header, "Language: {}", locale).unwrap();
+            writeln!(header, "Plural-Forms: {}", plural_forms_header(locale)?).unwrap();
+        } else {
+            header.push_str("Plural-Forms: nplurals=INTEGER; plural=EXPRESSION;\n");
+        }
+        header.push_str("X-Generator: leptos_i18n_build\n");
+
+        let mut output = PoEntry {
+            msgstr: vec![header],
+            ..Default::default()
+        }
+        .to_string();
+        for entry in self.entries {
+            output.push('\n');
+            write!(output, "{}", entry).unwrap();
+        }
+        Ok(output)
+    }
+}
+
+pub(crate) fn export_pot(builder_keys: &BuildersKeys) -> String {
+    let exporter = Exporter {
+        locale: None,
+        entries: vec![],
+    };
+    // The template does not compute plural categories, so it can't fail.
+    exporter
+        .export(builder_keys)
+        .expect("exporting a template can't fail")
+}
+
+pub(crate) fn export_po(builder_keys: &BuildersKeys, locale: &str) -> Result<String, PoError> {
+    let index = locale_index(builder_keys, locale)
+        .ok_or_else(|| PoError::UnknownLocale(locale.to_string()))?;
+    let exporter = Exporter {
+        locale: Some((locale, index)),
+        entries: vec![],
+    };
+    exporter.export(builder_keys)
+}
+
+pub(crate) fn import_po(
+    builder_keys: &BuildersKeys,
+    locale: &str,
+    content: &str,
+) -> Result<ImportedTranslations, PoError> {
+    if locale_index(builder_keys, locale).is_none() {
+        return Err(PoError::UnknownLocale(locale.to_string()));
+    }
+
+    let mut imported = ImportedTranslations::new(locale);
+    let entries = parse_po(content)?;
+
+    let plural_forms = entries
+        .iter()
+        .find(|entry| entry.msgctxt.is_none() && entry.msgid.is_empty())
+        .and_then(|header| header.msgstr.first())
+        .and_then(|header| {
+            header
+                .lines()
+                .find_map(|line| line.strip_prefix("Plural-Forms:"))
+        });
+    if let Some(plural_forms) = plural_forms {
+        let plural_forms_error = |message: String| PoError::PluralForms {
+            locale: locale.to_string(),
+            message,
+        };
+        let nplurals = parse_nplurals(plural_forms)
+            .ok_or_else(|| plural_forms_error(format!("invalid header {:?}", plural_forms)))?;
+        let expected = po_plural_categories(locale, PluralRuleType::Cardinal)?.len();
+        if nplurals != expected {
+            return Err(plural_forms_error(format!(
+                "expected {} plural forms, found {}",
+                expected, nplurals
+            )));
+        }
+    }
+
+    for entry in entries {
+        let Some(msgctxt) = entry.msgctxt.as_deref() else {
+            // header or entries not generated by us.
+            continue;
+        };
+        if entry.fuzzy {
+            continue;
+        }
+        let (namespace, path) = match msgctxt.split_once("::") {
+            Some((namespace, path)) => (Some(namespace), path),
+            None => (None, msgctxt),
+        };
+        let path = path.split('.').collect::<Vec<_>>();
+        let unknown_key = || PoError::UnknownKey(msgctxt.to_string());
+        let (default_value, _) =
+            find_default_value(builder_keys, namespace, &path).ok_or_else(unknown_key)?;
+        let (key, parents) = path.split_last().ok_or_else(unknown_key)?;
+
+        match catalog_plurals(default_value) {
+            Some(plurals) => {
+                let forms = po_plural_categories(locale, plurals.rule_type)?;
+                for (form, msgstr) in forms.into_iter().zip(entry.msgstr) {
+                    if msgstr.is_empty() {
+                        continue;
+                    }
+                    let key = format!("{}{}", key, form_key_suffix(plurals.rule_type, form));
+                    imported.insert(namespace, parents, key, msgstr);
+                }
+            }
+            None => {
+                let Some(msgstr) = entry.msgstr.into_iter().next() else {
+                    continue;
+                };
+                if !msgstr.is_empty() {
+                    imported.insert(namespace, parents, key.to_string(), msgstr);
+                }
+            }
+        }
+    }
+
+    Ok(imported)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::catalog::test_utils::TempDir;
+
+    #[test]
+    fn escape_roundtrip() {
+        let s = "a \"quoted\"\n\\ string\t";
+        assert_eq!(unescape(&escape(s), 0).unwrap(), s);
+    }
+
+    #[test]
+    fn parse_entries() {
+        let content = r#"
+msgid ""
+msgstr ""
+"Language: fr\n"
+
+#. cardinal plural: one, many, other
+msgctxt "items"
+msgid "one item"
+msgid_plural "{{ count }} items"
+msgstr[0] "un objet"
+msgstr[1] "{{ count }} d'objets"
+msgstr[2] "{{ count }} objets"
+
+#, fuzzy
+msgctxt "ns::subkeys.title"
+msgid "Title"
+msgstr ""
+"Ti"
+"tre"
+"#;
+        let entries = parse_po(content).unwrap();
+        assert_eq!(entries.len(), 3);
+        assert_eq!(entries[0].msgstr, vec!["Language: fr\n".to_string()]);
+        assert_eq!(
+            entries[1],
+            PoEntry {
+                comments: vec!["cardinal plural: one, many, other".into()],
+                fuzzy: false,
+                msgctxt: Some("items".into()),
+                msgid: "one item".into(),
+                msgid_plural: Some("{{ count }} items".into()),
+                msgstr: vec![
+                    "un objet".into(),
+                    "{{ count }} d'objets".into(),
+                    "{{ count }} objets".into()
+                ],
+            }
+        );
+        assert!(entries[2].fuzzy);
+        assert_eq!(entries[2].msgstr, vec!["Titre".to_string()]);
+    }
+
+    #[test]
+    fn plural_forms() {
+        assert_eq!(
+            plural_forms_header("en").unwrap(),
+            "nplurals=2; plural=n == 1 ? 0 : 1;"
+        );
+        assert_eq!(
+            plural_forms_header("fr").unwrap(),
+            "nplurals=3; plural=(n == 0 || n == 1) ? 0 : n != 0 && n % 1000000 == 0 ? 1 : 2;"
+        );
+        assert_eq!(plural_forms_header("ja").unwrap(), "nplurals=1; plural=0;");
+        assert_eq!(
+            plural_forms_header("ar").unwrap(),
+            "nplurals=6; plural=n == 0 ? 0 : n == 1 ? 1 : n == 2 ? 2 : (n % 100 >= 3 && n % 100 <= 10) ? 3 : (n % 100 >= 11 && n % 100 <= 99) ? 4 : 5;"
+        );
+        assert_eq!(parse_nplurals(" nplurals=3; plural=n > 1;"), Some(3));
+    }
+
+    #[test]
+    fn entry_roundtrip() {
+        let entry = PoEntry {
+            comments: vec![],
+            fuzzy: false,
+            msgctxt: Some("key".into()),
+            msgid: "<b>{{ name }}</b>".into(),
+            msgid_plural: None,
+            msgstr: vec!["<b>{{ name }}</b> !".into()],
+        };
+        let entries = parse_po(&entry.to_string()).unwrap();
+        assert_eq!(entries, vec![entry]);
+    }
+
+    #[test]
+    fn import_keeps_keys_not_exported() {
+        let dir = TempDir::new("po_roundtrip");
+        let locales_dir = dir.locales_dir();
+        std::fs::write(
+            locales_dir.join("en.json"),
+            r#"{ "hello": "Hello", "path": "C:\\\\<b>dir</b>", "click_count": [["No clicks", 0], ["{{ count }} clicks", "_"]] }"#,
+        )
+        .unwrap();
+        std::fs::write(
+            locales_dir.join("fr.json"),
+            r#"{ "hello": "Bonjour", "path": "C:\\\\<b>dossier</b>", "click_count": [["Aucun clic", 0], ["{{ count }} clics", "_"]] }"#,
+        )
+        .unwrap();
+
+        let infos = crate::TranslationsInfos::parse_at_dir(dir.path()).unwrap();
+        let po = infos
+            .export_po("fr")
+            .unwrap()
+            .replace("msgstr \"Bonjour\"", "msgstr \"Salut\"");
+        assert!(po.contains(r#"msgid "C:\\\\<b>dir</b>""#));
+        infos
+            .import_po("fr", &po)
+            .unwrap()
+            .write_to_dir(&locales_dir)
+            .unwrap();
+
+        let content = std::fs::read_to_string(locales_dir.join("fr.json")).unwrap();
+        let fr: serde_json::Value = serde_json::from_str(&content).unwrap();
+        assert_eq!(fr["hello"], "Salut");
+        assert_eq!(fr["path"], r"C:\\<b>dossier</b>");
+        assert_eq!(
+            fr["click_count"],
+            serde_json::json!([["Aucun clic", 0], ["{{ count }} clics", "_"]])
+        );
+        assert!(!locales_dir.join("fr.yaml").exists());
+
+        let po = infos.export_po("fr").unwrap();
+        assert!(po.contains("Plural-Forms: nplurals=3;"));
+        let po = po.replace("nplurals=3;", "nplurals=2;");
+        assert!(matches!(
+            infos.import_po("fr", &po),
+            Err(PoError::PluralForms { .. })
+        ));
+    }
+
+    #[test]
+    fn inline_plural_roundtrip() {
+        let dir = TempDir::new("po_inline_plural");
+        let locales_dir = dir.locales_dir();
+        std::fs::write(
+            locales_dir.join("en.json"),
+            r#"{ "apples": "You have {{ n, plural(=0: 'no apple'; one: '{{ n }} apple'; other: \"{{ n }} apples\") }}." }"#,
+        )
+        .unwrap();
+        std::fs::write(
+            locales_dir.join("fr.json"),
+            r#"{ "apples": "Vous avez {{ n, plural(one: '{{ n }} pomme'; other: '{{ n }} pommes') }}." }"#,
+        )
+        .unwrap();
+
+        let infos = crate::TranslationsInfos::parse_at_dir(dir.path()).unwrap();
+        let po = infos.export_po("fr").unwrap();
+        assert!(po.contains(
+            r#"msgid "You have {{ n, plural(=0: 'no apple'; one: '{{ n }} apple'; other: '{{ n }} apples') }}.""#
+        ));
+        let po = po.replace("{{ n }} pommes", "{{ n }} pommes rouges");
+        infos
+            .import_po("fr", &po)
+            .unwrap()
+            .write_to_dir(&locales_dir)
+            .unwrap();
+
+        let content = std::fs::read_to_string(locales_dir.join("fr.json")).unwrap();
+        let fr: serde_json::Value = serde_json::from_str(&content).unwrap();
+        assert_eq!(
+            fr["apples"],
+            "Vous avez {{ n, plural(one: '{{ n }} pomme'; other: '{{ n }} pommes rouges') }}."
+        );
+    }
+}
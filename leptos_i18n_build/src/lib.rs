@@ -3,14 +3,16 @@
 #![deny(warnings)]
 //! This crate provide `build.rs` utilities for the `leptos_i18n` crate.
 
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 use std::fmt::{Display, Write};
 use std::fs::{create_dir_all, File};
 use std::io::BufWriter;
 use std::path::PathBuf;
 use std::rc::Rc;
 
+pub use catalog::ImportedTranslations;
 pub use datakey::Options;
+pub use gettext::PoError;
 use icu_datagen::baked_exporter::BakedExporter;
 use icu_datagen::prelude::DataKey;
 use icu_datagen::{DatagenDriver, DatagenProvider};
@@ -19,8 +21,15 @@ use icu_provider::DataError;
 use leptos_i18n_parser::parse_locales;
 use leptos_i18n_parser::parse_locales::error::Result;
 use leptos_i18n_parser::parse_locales::locale::{BuildersKeys, Locale};
+use leptos_i18n_parser::parse_locales::metadata::collect_metadata;
+pub use leptos_i18n_parser::parse_locales::metadata::KeyMetadata;
+use leptos_i18n_parser::utils::KeyPath;
+pub use xliff::XliffError;
 
+mod catalog;
 mod datakey;
+mod gettext;
+mod xliff;
 
 #[derive(Clone)]
 enum EitherIter<A, B> {
@@ -63,12 +72,12 @@ impl TranslationsInfos {
         Self::parse_inner(Some(dir_path.into()))
     }
 
-    /// Paths to all files containing translations.
+    /// Paths to all files containing translations, and the configuration files outside of the `Cargo.toml`.
     pub fn files_paths(&self) -> &[String] {
         &self.paths
     }
 
-    /// Output "cargo:rerun-if-changed" for all locales files.
+    /// Output "cargo:rerun-if-changed" for all locales files, and the configuration files outside of the `Cargo.toml`.
     pub fn rerun_if_locales_changed(&self) {
         for path in &self.paths {
             println!("cargo:rerun-if-changed={}", path);
@@ -132,10 +141,38 @@ impl TranslationsInfos {
         }
     }
 
+    /// Return the translators metadata declared in the default locale, indexed by key path.
+    ///
+    /// Key paths are formatted as `"key.subkey"`, prefixed with `"namespace::"` when using namespaces.
+    pub fn get_keys_metadata(&self) -> BTreeMap<String, &KeyMetadata> {
+        let mut metadata = BTreeMap::new();
+        match &self.locales {
+            BuildersKeys::NameSpaces { namespaces, .. } => {
+                for namespace in namespaces {
+                    if let Some(locale) = namespace.locales.first() {
+                        let mut key_path = KeyPath::new(Some(namespace.key.clone()));
+                        collect_metadata(locale, &mut key_path, &mut metadata);
+                    }
+                }
+            }
+            BuildersKeys::Locales { locales, .. } => {
+                if let Some(locale) = locales.first() {
+                    collect_metadata(locale, &mut KeyPath::new(None), &mut metadata);
+                }
+            }
+        }
+        metadata
+            .into_iter()
+            .map(|(key_path, metadata)| (key_path.to_string(), metadata))
+            .collect()
+    }
+
     /// Return an iterator containing each locales in the form of `LanguageIdentifier`.
+    ///
+    /// Unicode extensions are dropped, "th-TH-u-ca-buddhist" gives "th-TH".
     pub fn get_locales_langids(&self) -> impl Iterator<Item = LanguageIdentifier> + '_ {
         self.get_locales()
-            .map(|locale| locale.parse::<LanguageIdentifier>().unwrap())
+            .map(|locale| locale.parse::<icu_locid::Locale>().unwrap().id)
     }
 
     fn get_icu_keys_inner(&self, used_icu_keys: &mut HashSet<Options>) {
@@ -217,6 +254,79 @@ impl TranslationsInfos {
     pub fn generate_data(&self, mod_directory: PathBuf) -> Result<(), DataError> {
         self.generate_data_with_options(mod_directory, std::iter::empty())
     }
+
+    /// Export the keys of the default locale as a gettext `.pot` template.
+    ///
+    /// Each key path is an entry with its `msgctxt` set to the namespace and key (`namespace::key.subkey`),
+    /// plurals use `msgid_plural`. Ranges and non-string values are not exported.
+    pub fn export_pot(&self) -> String {
+        gettext::export_pot(&self.locales)
+    }
+
+    /// Export the translations of the given locale as a gettext `.po` catalog.
+    ///
+    /// Plurals `msgstr[n]` follow the CLDR plural categories of the locale,
+    /// they are listed in a comment above each entry.
+    pub fn export_po(&self, locale: &str) -> Result<String, PoError> {
+        gettext::export_po(&self.locales, locale)
+    }
+
+    /// Write the `.pot` template as `messages.pot` and a `.po` catalog for each locale in the given directory.
+    pub fn write_po_files<P: Into<PathBuf>>(&self, dir: P) -> Result<(), PoError> {
+        let mut path: PathBuf = dir.into();
+        create_dir_all(&path)?;
+        path.push("messages.pot");
+        std::fs::write(&path, self.export_pot())?;
+        path.pop();
+        for locale in self.get_locales() {
+            path.push(&*locale);
+            path.set_extension("po");
+            std::fs::write(&path, self.export_po(&locale)?)?;
+            path.pop();
+        }
+        Ok(())
+    }
+
+    /// Import a translated `.po` catalog for the given locale.
+    ///
+    /// Entries are matched with the keys of the default locale using their `msgctxt`,
+    /// fuzzy and untranslated entries are skipped.
+    /// The result can then be written in the locales directory with `ImportedTranslations::write_to_dir`.
+    pub fn import_po(&self, locale: &str, content: &str) -> Result<ImportedTranslations, PoError> {
+        gettext::import_po(&self.locales, locale, content)
+    }
+
+    /// Export the translations of the given locale as a XLIFF 2.0 document,
+    /// with the default locale as source and the given locale as target.
+    ///
+    /// Variables and components are exported as `<ph>` and `<pc>` inline elements,
+    /// each plural category of the target locale is its own unit.
+    /// Ranges and non-string values are not exported.
+    pub fn export_xliff(&self, locale: &str) -> Result<String, XliffError> {
+        xliff::export_xliff(&self.locales, locale)
+    }
+
+    /// Write a `{locale}.xlf` XLIFF document for each locale other than the default one in the given directory.
+    pub fn write_xliff_files<P: Into<PathBuf>>(&self, dir: P) -> Result<(), XliffError> {
+        let mut path: PathBuf = dir.into();
+        create_dir_all(&path)?;
+        for locale in self.get_locales().skip(1) {
+            path.push(&*locale);
+            path.set_extension("xlf");
+            std::fs::write(&path, self.export_xliff(&locale)?)?;
+            path.pop();
+        }
+        Ok(())
+    }
+
+    /// Import a translated XLIFF 2.0 document, the locale is read from its `trgLang` attribute.
+    ///
+    /// Targets are validated against the interpolation keys of the default locale:
+    /// an unknown variable or component is an error. Units without target are skipped.
+    /// The result can then be written in the locales directory with `ImportedTranslations::write_to_dir`.
+    pub fn import_xliff(&self, content: &str) -> Result<ImportedTranslations, XliffError> {
+        xliff::import_xliff(&self.locales, content)
+    }
 }
 
 /// Describe if the translations have been declared in namespaces or as is.
@@ -0,0 +1,1256 @@
+//! Import and export of the translations as XLIFF 2.0 documents.
+
+use std::collections::BTreeMap;
+use std::fmt::{Display, Write};
+
+use leptos_i18n_parser::parse_locales::locale::{
+    BuildersKeys, BuildersKeysInner, ComponentKind, InterpolOrLit, Locale, LocaleValue,
+};
+use leptos_i18n_parser::parse_locales::parsed_value::{escape_literal, Literal, ParsedValue};
+use leptos_i18n_parser::parse_locales::plurals::{PluralForm, PluralRuleType, Plurals};
+use leptos_i18n_parser::utils::{Key, KeyPath};
+
+use crate::catalog::{
+    catalog_plurals, find_default_value, form_key_suffix, form_name, is_exportable, locale_index,
+    metadata_notes, opening_tag, plural_categories, strip_key_prefix, to_source, top_locales,
+    ImportedTranslations,
+};
+
+const XLIFF_NAMESPACE: &str = "urn:oasis:names:tc:xliff:document:2.0";
+/// `id` of the `<file>` element when translations are not declared in namespaces.
+const DEFAULT_FILE_ID: &str = "translations";
+
+/// Errors that can occur while exporting or importing XLIFF documents.
+#[derive(Debug)]
+pub enum XliffError {
+    /// The given locale is not declared in the configuration.
+    UnknownLocale(String),
+    /// The document does not declare a target language.
+    MissingTargetLocale,
+    /// A unit of the document does not match any key of the default locale.
+    UnknownKey(String),
+    /// The document is malformed.
+    Syntax {
+        /// Line of the error, starting at 1.
+        line: usize,
+        /// Description of the error.
+        message: String,
+    },
+    /// An inline element of a target is invalid.
+    InvalidInline {
+        /// Key path of the unit.
+        key: String,
+        /// Description of the error.
+        message: String,
+    },
+    /// A target uses a variable that the key does not declare.
+    UnknownVariable {
+        /// Key path of the unit.
+        key: String,
+        /// Name of the variable.
+        variable: String,
+    },
+    /// A target uses a component that the key does not declare.
+    UnknownComponent {
+        /// Key path of the unit.
+        key: String,
+        /// Name of the component.
+        component: String,
+    },
+    /// Error while computing the plural categories of a locale.
+    PluralRules {
+        /// The locale.
+        locale: String,
+        /// The error message.
+        message: String,
+    },
+    /// IO error while reading or writing files.
+    Io(std::io::Error),
+}
+
+impl Display for XliffError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            XliffError::UnknownLocale(locale) => write!(f, "Unknown locale {:?}", locale),
+            XliffError::MissingTargetLocale => {
+                write!(
+                    f,
+                    "The XLIFF document is missing the \"trgLang\" attribute."
+                )
+            }
+            XliffError::UnknownKey(key) => write!(f, "Unknown key \"{}\"", key),
+            XliffError::Syntax { line, message } => {
+                write!(f, "Malformed XLIFF document at line {}: {}", line, message)
+            }
+            XliffError::InvalidInline { key, message } => {
+                write!(f, "Invalid inline element in key \"{}\": {}", key, message)
+            }
+            XliffError::UnknownVariable { key, variable } => write!(
+                f,
+                "Key \"{}\" uses variable {:?} which is not declared in the default locale.",
+                key, variable
+            ),
+            XliffError::UnknownComponent { key, component } => write!(
+                f,
+                "Key \"{}\" uses component {:?} which is not declared in the default locale.",
+                key, component
+            ),
+            XliffError::PluralRules { locale, message } => write!(
+                f,
+                "Error while computing plurals categories of locale {:?}: {}",
+                locale, message
+            ),
+            XliffError::Io(err) => Display::fmt(err, f),
+        }
+    }
+}
+
+impl std::error::Error for XliffError {}
+
+impl From<std::io::Error> for XliffError {
+    fn from(value: std::io::Error) -> Self {
+        XliffError::Io(value)
+    }
+}
+
+fn escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum XmlNode {
+    Element(XmlElement),
+    Text(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+struct XmlElement {
+    name: String,
+    attributes: Vec<(String, String)>,
+    children: Vec<XmlNode>,
+}
+
+impl XmlElement {
+    fn attr(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(attr, _)| attr == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    fn elements<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a XmlElement> + 'a {
+        self.children.iter().filter_map(move |child| match child {
+            XmlNode::Element(el) if el.name == name => Some(el),
+            _ => None,
+        })
+    }
+
+    fn element(&self, name: &str) -> Option<&XmlElement> {
+        self.children.iter().find_map(|child| match child {
+            XmlNode::Element(el) if el.name == name => Some(el),
+            _ => None,
+        })
+    }
+}
+
+// Only support what is needed to read XLIFF documents:
+// elements, attributes, text, CDATA sections and the predefined and numeric entities.
+// Comments, processing instructions and doctypes are skipped.
+struct XmlReader<'a> {
+    source: &'a str,
+    pos: usize,
+}
+
+impl<'a> XmlReader<'a> {
+    fn error(&self, message: impl Into<String>) -> XliffError {
+        XliffError::Syntax {
+            line: self.source[..self.pos].matches('\n').count() + 1,
+            message: message.into(),
+        }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.source[self.pos..]
+    }
+
+    fn skip_until(&mut self, pattern: &str) -> Result<&'a str, XliffError> {
+        let rest = self.rest();
+        let index = rest
+            .find(pattern)
+            .ok_or_else(|| self.error(format!("expected {:?}", pattern)))?;
+        self.pos += index + pattern.len();
+        Ok(&rest[..index])
+    }
+
+    fn skip_whitespaces(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn read_name(&mut self) -> Result<&'a str, XliffError> {
+        let rest = self.rest();
+        let len = rest
+            .find(|c: char| c.is_whitespace() || matches!(c, '/' | '>' | '='))
+            .unwrap_or(rest.len());
+        if len == 0 {
+            return Err(self.error("expected a name"));
+        }
+        self.pos += len;
+        Ok(&rest[..len])
+    }
+
+    fn decode(&self, s: &str) -> Result<String, XliffError> {
+        let mut decoded = String::with_capacity(s.len());
+        let mut rest = s;
+        while let Some(index) = rest.find('&') {
+            decoded.push_str(&rest[..index]);
+            rest = &rest[index + 1..];
+            let end = rest
+                .find(';')
+                .ok_or_else(|| self.error("unterminated entity"))?;
+            let entity = &rest[..end];
+            let c = match entity {
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "amp" => Some('&'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                _ => match entity.strip_prefix('#') {
+                    Some(code) => match code.strip_prefix('x') {
+                        Some(hex) => u32::from_str_radix(hex, 16).ok(),
+                        None => code.parse().ok(),
+                    }
+                    .and_then(char::from_u32),
+                    None => None,
+                },
+            };
+            let c = c.ok_or_else(|| self.error(format!("unknown entity \"&{};\"", entity)))?;
+            decoded.push(c);
+            rest = &rest[end + 1..];
+        }
+        decoded.push_str(rest);
+        Ok(decoded)
+    }
+
+    // Skip comments, processing instructions and doctypes, return `false` if none where found.
+    fn skip_misc(&mut self) -> Result<bool, XliffError> {
+        let rest = self.rest();
+        if rest.starts_with("<!--") {
+            self.skip_until("-->")?;
+        } else if rest.starts_with("<?") {
+            self.skip_until("?>")?;
+        } else if rest.starts_with("<!") && !rest.starts_with("<![CDATA[") {
+            self.skip_until(">")?;
+        } else {
+            return Ok(false);
+        }
+        Ok(true)
+    }
+
+    fn read_element(&mut self) -> Result<XmlElement, XliffError> {
+        // skip '<'
+        self.pos += 1;
+        let mut element = XmlElement {
+            name: self.read_name()?.to_string(),
+            ..Default::default()
+        };
+        loop {
+            self.skip_whitespaces();
+            let rest = self.rest();
+            if rest.starts_with("/>") {
+                self.pos += 2;
+                return Ok(element);
+            } else if rest.starts_with('>') {
+                self.pos += 1;
+                break;
+            } else if rest.is_empty() {
+                return Err(self.error("unexpected end of document"));
+            }
+            let name = self.read_name()?.to_string();
+            self.skip_whitespaces();
+            if !self.rest().starts_with('=') {
+                return Err(self.error(format!("expected a value for attribute {:?}", name)));
+            }
+            self.pos += 1;
+            self.skip_whitespaces();
+            let quote = match self.rest().chars().next() {
+                Some(quote @ ('"' | '\'')) => quote,
+                _ => return Err(self.error("expected a quoted attribute value")),
+            };
+            self.pos += 1;
+            let value = self.skip_until(if quote == '"' { "\"" } else { "'" })?;
+            let value = self.decode(value)?;
+            element.attributes.push((name, value));
+        }
+
+        loop {
+            if self.skip_misc()? {
+                continue;
+            }
+            let rest = self.rest();
+            if let Some(closing) = rest.strip_prefix("</") {
+                let end = closing
+                    .find('>')
+                    .ok_or_else(|| self.error("unterminated closing tag"))?;
+                if closing[..end].trim() != element.name {
+                    return Err(self.error(format!(
+                        "expected closing tag for {:?}, found {:?}",
+                        element.name,
+                        closing[..end].trim()
+                    )));
+                }
+                self.pos += 2 + end + 1;
+                return Ok(element);
+            } else if rest.starts_with("<![CDATA[") {
+                self.pos += "<![CDATA[".len();
+                let text = self.skip_until("]]>")?;
+                element.children.push(XmlNode::Text(text.to_string()));
+            } else if rest.starts_with('<') {
+                let child = self.read_element()?;
+                element.children.push(XmlNode::Element(child));
+            } else if rest.is_empty() {
+                return Err(self.error(format!("unclosed element {:?}", element.name)));
+            } else {
+                let len = rest.find('<').unwrap_or(rest.len());
+                let text = self.decode(&rest[..len])?;
+                self.pos += len;
+                element.children.push(XmlNode::Text(text));
+            }
+        }
+    }
+}
+
+fn parse_xml(source: &str) -> Result<XmlElement, XliffError> {
+    let mut reader = XmlReader { source, pos: 0 };
+    loop {
+        reader.skip_whitespaces();
+        if !reader.skip_misc()? {
+            break;
+        }
+    }
+    if !reader.rest().starts_with('<') {
+        return Err(reader.error("expected the root element"));
+    }
+    reader.read_element()
+}
+
+// Write the inline content of a `<source>` or `<target>`,
+// the syntax of variables and components is stored in the unit `<originalData>`.
+struct InlineWriter<'a> {
+    original_data: &'a mut Vec<String>,
+    // ids of the inline elements of the source, by data index, so the target can reuse them.
+    source_ids: &'a mut BTreeMap<usize, Vec<usize>>,
+    next_id: &'a mut usize,
+    is_target: bool,
+    plural_forms: &'a PluralForms,
+    occurrences: BTreeMap<usize, usize>,
+    buff: String,
+}
+
+impl InlineWriter<'_> {
+    fn data_ref(&mut self, content: String) -> usize {
+        match self.original_data.iter().position(|data| *data == content) {
+            Some(index) => index,
+            None => {
+                self.original_data.push(content);
+                self.original_data.len() - 1
+            }
+        }
+    }
+
+    fn inline_id(&mut self, data_index: usize) -> usize {
+        let occurrence = self.occurrences.entry(data_index).or_default();
+        let ids = self.source_ids.entry(data_index).or_default();
+        let id = match ids.get(*occurrence) {
+            Some(id) if self.is_target => *id,
+            _ => {
+                *self.next_id += 1;
+                if !self.is_target {
+                    ids.push(*self.next_id);
+                }
+                *self.next_id
+            }
+        };
+        *occurrence += 1;
+        id
+    }
+
+    fn write(&mut self, value: &ParsedValue) -> Option<()> {
+        match value {
+            ParsedValue::Literal(Literal::String(s, _)) => self.buff.push_str(&escape(s)),
+            ParsedValue::Literal(lit) => write!(self.buff, "{}", lit).ok()?,
+            ParsedValue::Variable { .. } => {
+                let data_index = self.data_ref(to_source(value)?);
+                let id = self.inline_id(data_index);
+                write!(
+                    self.buff,
+                    "<ph id=\"{}\" dataRef=\"d{}\"/>",
+                    id,
+                    data_index + 1
+                )
+                .ok()?;
+            }
+            ParsedValue::Component {
+                key,
+                inner: None,
+                attrs,
+            } => {
+                let name = strip_key_prefix(key, "comp_");
+                let data_index = self.data_ref(opening_tag(name, attrs, true));
+                let id = self.inline_id(data_index);
+                write!(
+                    self.buff,
+                    "<ph id=\"{}\" dataRef=\"d{}\"/>",
+                    id,
+                    data_index + 1
+                )
+                .ok()?;
+            }
+            ParsedValue::Component {
+                key,
+                inner: Some(inner),
+                attrs,
+            } => {
+                let name = strip_key_prefix(key, "comp_");
+                let start = self.data_ref(opening_tag(name, attrs, false));
+                let end = self.data_ref(format!("</{}>", name));
+                let id = self.inline_id(start);
+                self.write_pc_start(id, start, end)?;
+                self.write(inner)?;
+                self.buff.push_str("</pc>");
+            }
+            ParsedValue::Plurals(plurals) => self.write_plurals(plurals)?,
+            ParsedValue::Bloc(values) => {
+                for value in values {
+                    self.write(value)?;
+                }
+            }
+            ParsedValue::ForeignKey(foreign_key) => {
+                self.write(foreign_key.borrow().as_inner("xliff inline writer"))?
+            }
+            ParsedValue::Default
+            | ParsedValue::Ranges(_)
+            | ParsedValue::Subkeys(_)
+            | ParsedValue::Select(_) => return None,
+        }
+        Some(())
+    }
+
+    // Inline plurals are a `<pc>` with a nested `<pc>` for the text of each category,
+    // the categories are the exact numbers of the value followed by the ones of the target locale.
+    fn write_plurals(&mut self, plurals: &Plurals) -> Option<()> {
+        let name = strip_key_prefix(&plurals.count_key, "var_");
+        let start = match plurals.rule_type {
+            PluralRuleType::Cardinal => format!("{{{{ {}, plural(", name),
+            PluralRuleType::Ordinal => format!("{{{{ {}, plural(type: ordinal; ", name),
+        };
+        let start = self.data_ref(start);
+        let end = self.data_ref(") }}".to_string());
+        let id = self.inline_id(start);
+        self.write_pc_start(id, start, end)?;
+        let exact = plurals
+            .exact
+            .iter()
+            .map(|(number, value)| (format!("={}", number), value));
+        let plural_forms = self.plural_forms;
+        let forms = plural_forms.get(plurals.rule_type).iter().map(|form| {
+            let value = match form {
+                PluralForm::Other => &*plurals.other,
+                form => plurals.forms.get(form).unwrap_or(&plurals.other),
+            };
+            (form_name(*form), value)
+        });
+        for (category, value) in exact.chain(forms) {
+            let start = self.data_ref(format!("{}: '", category));
+            let end = self.data_ref("'".to_string());
+            let id = self.inline_id(start);
+            self.write_pc_start(id, start, end)?;
+            self.write(value)?;
+            self.buff.push_str("</pc>");
+        }
+        self.buff.push_str("</pc>");
+        Some(())
+    }
+
+    fn write_pc_start(&mut self, id: usize, start: usize, end: usize) -> Option<()> {
+        write!(
+            self.buff,
+            "<pc id=\"{}\" dataRefStart=\"d{}\" dataRefEnd=\"d{}\">",
+            id,
+            start + 1,
+            end + 1
+        )
+        .ok()
+    }
+}
+
+// Plural categories of the target locale.
+struct PluralForms {
+    cardinal: Vec<PluralForm>,
+    ordinal: Vec<PluralForm>,
+}
+
+impl PluralForms {
+    fn new(locale: &str) -> Result<Self, XliffError> {
+        Ok(PluralForms {
+            cardinal: xliff_plural_categories(locale, PluralRuleType::Cardinal)?,
+            ordinal: xliff_plural_categories(locale, PluralRuleType::Ordinal)?,
+        })
+    }
+
+    fn get(&self, rule_type: PluralRuleType) -> &[PluralForm] {
+        match rule_type {
+            PluralRuleType::Cardinal => &self.cardinal,
+            PluralRuleType::Ordinal => &self.ordinal,
+        }
+    }
+}
+
+struct Unit {
+    id: String,
+    notes: Vec<String>,
+    original_data: Vec<String>,
+    source: String,
+    target: Option<String>,
+}
+
+impl Unit {
+    fn new(
+        id: String,
+        notes: Vec<String>,
+        source: &ParsedValue,
+        target: Option<&ParsedValue>,
+        plural_forms: &PluralForms,
+    ) -> Option<Self> {
+        let mut original_data = vec![];
+        let mut source_ids = BTreeMap::new();
+        let mut next_id = 0;
+        let mut writer = |value: &ParsedValue, is_target: bool| {
+            let mut writer = InlineWriter {
+                original_data: &mut original_data,
+                source_ids: &mut source_ids,
+                next_id: &mut next_id,
+                is_target,
+                plural_forms,
+                occurrences: BTreeMap::new(),
+                buff: String::new(),
+            };
+            writer.write(value).map(|_| writer.buff)
+        };
+        let source = writer(source, false)?;
+        let target = target.and_then(|target| writer(target, true));
+        Some(Unit {
+            id,
+            notes,
+            original_data,
+            source,
+            target,
+        })
+    }
+}
+
+impl Display for Unit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "    <unit id=\"{}\">", escape(&self.id))?;
+        if !self.notes.is_empty() {
+            writeln!(f, "      <notes>")?;
+            for note in &self.notes {
+                writeln!(f, "        <note>{}</note>", escape(note))?;
+            }
+            writeln!(f, "      </notes>")?;
+        }
+        if !self.original_data.is_empty() {
+            writeln!(f, "      <originalData>")?;
+            for (index, data) in self.original_data.iter().enumerate() {
+                writeln!(
+                    f,
+                    "        <data id=\"d{}\">{}</data>",
+                    index + 1,
+                    escape(data)
+                )?;
+            }
+            writeln!(f, "      </originalData>")?;
+        }
+        writeln!(f, "      <segment>")?;
+        writeln!(f, "        <source>{}</source>", self.source)?;
+        if let Some(target) = &self.target {
+            writeln!(f, "        <target>{}</target>", target)?;
+        }
+        writeln!(f, "      </segment>")?;
+        writeln!(f, "    </unit>")
+    }
+}
+
+struct Exporter {
+    plural_forms: PluralForms,
+    locale_index: usize,
+    units: Vec<Unit>,
+}
+
+impl Exporter {
+    fn export_value(
+        &mut self,
+        key_path: &KeyPath,
+        default_value: &ParsedValue,
+        value: Option<&ParsedValue>,
+        mut notes: Vec<String>,
+    ) -> Result<(), XliffError> {
+        if !is_exportable(default_value) {
+            return Ok(());
+        }
+        let id = key_path_id(key_path);
+        let Some(plurals) = catalog_plurals(default_value) else {
+            self.units.extend(Unit::new(
+                id,
+                notes,
+                default_value,
+                value,
+                &self.plural_forms,
+            ));
+            return Ok(());
+        };
+        // XLIFF has no notion of plurals, each category of the target locale is its own unit.
+        let forms = self.plural_forms.get(plurals.rule_type).to_vec();
+        let names = forms.iter().copied().map(form_name).collect::<Vec<_>>();
+        notes.push(format!(
+            "{} plural: {}",
+            plurals.rule_type,
+            names.join(", ")
+        ));
+        for form in forms {
+            let source = plurals.forms.get(&form).unwrap_or(&plurals.other);
+            let target = match value {
+                Some(ParsedValue::Plurals(plurals)) => match form {
+                    PluralForm::Other => Some(&*plurals.other),
+                    form => Some(plurals.forms.get(&form).unwrap_or(&plurals.other)),
+                },
+                _ => None,
+            };
+            let id = format!("{}{}", id, form_key_suffix(plurals.rule_type, form));
+            self.units.extend(Unit::new(
+                id,
+                notes.clone(),
+                source,
+                target,
+                &self.plural_forms,
+            ));
+        }
+        Ok(())
+    }
+
+    fn export_keys(
+        &mut self,
+        keys: &BuildersKeysInner,
+        locales: &[Locale],
+        key_path: &mut KeyPath,
+    ) -> Result<(), XliffError> {
+        let Some(default_locale) = locales.first() else {
+            return Ok(());
+        };
+        let locale = locales.get(self.locale_index);
+        for (key, locale_value) in &keys.0 {
+            key_path.push_key(key.clone());
+            match locale_value {
+                LocaleValue::Subkeys { locales, keys } => {
+                    self.export_keys(keys, locales, key_path)?;
+                }
+                LocaleValue::Value { .. } => {
+                    if let Some(default_value) = default_locale.keys.get(key) {
+                        let value = locale.and_then(|locale| locale.keys.get(key));
+                        let notes = metadata_notes(default_locale.metadata.get(key));
+                        self.export_value(key_path, default_value, value, notes)?;
+                    }
+                }
+            }
+            key_path.pop_key();
+        }
+        Ok(())
+    }
+
+    fn write_file(&mut self, output: &mut String, id: &str) {
+        writeln!(output, "  <file id=\"{}\">", escape(id)).unwrap();
+        for unit in std::mem::take(&mut self.units) {
+            write!(output, "{}", unit).unwrap();
+        }
+        output.push_str("  </file>\n");
+    }
+}
+
+fn key_path_id(key_path: &KeyPath) -> String {
+    // The namespace is the `<file>` id.
+    let key_path = key_path.to_string();
+    match key_path.split_once("::") {
+        Some((_, path)) => path.to_string(),
+        None => key_path,
+    }
+}
+
+fn xliff_plural_categories(
+    locale: &str,
+    rule_type: PluralRuleType,
+) -> Result<Vec<PluralForm>, XliffError> {
+    plural_categories(locale, rule_type).map_err(|message| XliffError::PluralRules {
+        locale: locale.to_string(),
+        message,
+    })
+}
+
+pub(crate) fn export_xliff(
+    builder_keys: &BuildersKeys,
+    locale: &str,
+) -> Result<String, XliffError> {
+    let locale_index = locale_index(builder_keys, locale)
+        .ok_or_else(|| XliffError::UnknownLocale(locale.to_string()))?;
+    let default_locale = top_locales(builder_keys)
+        .first()
+        .map(|locale| locale.name.name.clone())
+        .unwrap_or_default();
+    let mut exporter = Exporter {
+        plural_forms: PluralForms::new(locale)?,
+        locale_index,
+        units: vec![],
+    };
+
+    let mut output = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    writeln!(
+        output,
+        "<xliff xmlns=\"{}\" version=\"2.0\" srcLang=\"{}\" trgLang=\"{}\">",
+        XLIFF_NAMESPACE,
+        escape(&default_locale),
+        escape(locale)
+    )
+    .unwrap();
+
+    match builder_keys {
+        BuildersKeys::NameSpaces { namespaces, keys } => {
+            for namespace in namespaces {
+                let mut key_path = KeyPath::new(Some(namespace.key.clone()));
+                if let Some(keys) = keys.get(&namespace.key) {
+                    exporter.export_keys(keys, &namespace.locales, &mut key_path)?;
+                }
+                exporter.write_file(&mut output, &namespace.key.name);
+            }
+        }
+        BuildersKeys::Locales { locales, keys } => {
+            exporter.export_keys(keys, locales, &mut KeyPath::new(None))?;
+            exporter.write_file(&mut output, DEFAULT_FILE_ID);
+        }
+    }
+
+    output.push_str("</xliff>\n");
+    Ok(output)
+}
+
+struct InlineReader<'a> {
+    key: &'a str,
+    original_data: BTreeMap<&'a str, &'a str>,
+    interpolation: &'a InterpolOrLit,
+}
+
+impl InlineReader<'_> {
+    fn invalid(&self, message: impl Into<String>) -> XliffError {
+        XliffError::InvalidInline {
+            key: self.key.to_string(),
+            message: message.into(),
+        }
+    }
+
+    fn data(&self, element: &XmlElement, attr: &str) -> Result<&str, XliffError> {
+        let data_ref = element.attr(attr).ok_or_else(|| {
+            self.invalid(format!(
+                "<{}> is missing the {:?} attribute",
+                element.name, attr
+            ))
+        })?;
+        self.original_data
+            .get(data_ref)
+            .copied()
+            .ok_or_else(|| self.invalid(format!("unknown data reference {:?}", data_ref)))
+    }
+
+    fn check_variable(&self, data: &str) -> Result<(), XliffError> {
+        let name = data
+            .strip_prefix("{{")
+            .and_then(|s| s.strip_suffix("}}"))
+            .map(|s| s.split(',').next().unwrap_or_default().trim())
+            .ok_or_else(|| self.invalid(format!("{:?} is not a variable", data)))?;
+        self.check_variable_name(name)
+    }
+
+    fn check_variable_name(&self, name: &str) -> Result<(), XliffError> {
+        let declared = Key::new(&format!("var_{}", name)).is_some_and(|key| {
+            self.interpolation
+                .is_interpol()
+                .is_some_and(|keys| keys.iter_vars().any(|(var, _)| var == key))
+        });
+        if declared {
+            Ok(())
+        } else {
+            Err(XliffError::UnknownVariable {
+                key: self.key.to_string(),
+                variable: name.to_string(),
+            })
+        }
+    }
+
+    fn check_component(&self, data: &str, kind: ComponentKind) -> Result<String, XliffError> {
+        let tag = data
+            .strip_prefix('<')
+            .and_then(|s| s.strip_suffix('>'))
+            .filter(|s| !s.starts_with('/'))
+            .filter(|s| s.ends_with('/') == (kind == ComponentKind::SelfClosing))
+            .ok_or_else(|| self.invalid(format!("{:?} is not a component", data)))?;
+        let name = tag
+            .trim_end_matches('/')
+            .split_whitespace()
+            .next()
+            .unwrap_or_default();
+        let declared = Key::new(&format!("comp_{}", name)).is_some_and(|key| {
+            self.interpolation.is_interpol().is_some_and(|keys| {
+                keys.iter_comps()
+                    .any(|(comp, comp_kind)| comp == key && comp_kind == kind)
+            })
+        });
+        if declared {
+            Ok(name.to_string())
+        } else {
+            Err(XliffError::UnknownComponent {
+                key: self.key.to_string(),
+                component: name.to_string(),
+            })
+        }
+    }
+
+    fn read(&self, nodes: &[XmlNode], buff: &mut SourceBuffer) -> Result<(), XliffError> {
+        for node in nodes {
+            let element = match node {
+                XmlNode::Text(text) => {
+                    buff.text.push_str(text);
+                    continue;
+                }
+                XmlNode::Element(element) => element,
+            };
+            match element.name.as_str() {
+                "ph" => {
+                    let data = self.data(element, "dataRef")?;
+                    // placeholders are variables or self-closing components.
+                    if data.starts_with('<') {
+                        self.check_component(data, ComponentKind::SelfClosing)?;
+                    } else {
+                        self.check_variable(data)?;
+                    }
+                    buff.push_syntax(data);
+                }
+                "pc" => {
+                    let data = self.data(element, "dataRefStart")?;
+                    if data.starts_with("{{") {
+                        self.read_plurals(element, data, buff)?;
+                        continue;
+                    }
+                    let name = self.check_component(data, ComponentKind::Paired)?;
+                    buff.push_syntax(data);
+                    self.read(&element.children, buff)?;
+                    buff.push_syntax(&format!("</{}>", name));
+                }
+                "cp" => {
+                    let c = element
+                        .attr("hex")
+                        .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                        .and_then(char::from_u32)
+                        .ok_or_else(|| self.invalid("invalid <cp> element"))?;
+                    buff.text.push(c);
+                }
+                "sc" | "ec" | "sm" | "em" => {
+                    return Err(self.invalid(format!(
+                        "<{}> elements are not supported, use <pc> or <mrk> instead",
+                        element.name
+                    )))
+                }
+                // `<mrk>` and unknown extensions annotate their content.
+                _ => self.read(&element.children, buff)?,
+            }
+        }
+        Ok(())
+    }
+
+    // Rebuild an inline plural from the text of its categories, `{{ n, plural(one: '..'; other: '..') }}`.
+    fn read_plurals(
+        &self,
+        element: &XmlElement,
+        start: &str,
+        buff: &mut SourceBuffer,
+    ) -> Result<(), XliffError> {
+        let (name, ordinal) = start
+            .strip_prefix("{{")
+            .and_then(|s| s.split_once(','))
+            .filter(|(_, rest)| rest.trim_start().starts_with("plural("))
+            .map(|(name, rest)| (name.trim(), rest.contains("type: ordinal")))
+            .ok_or_else(|| self.invalid(format!("{:?} is not a plural", start)))?;
+        self.check_variable_name(name)?;
+        let mut args = vec![];
+        if ordinal {
+            args.push(format!("type: {}", PluralRuleType::Ordinal));
+        }
+        for node in &element.children {
+            let category = match node {
+                XmlNode::Text(text) if text.trim().is_empty() => continue,
+                XmlNode::Text(text) => {
+                    return Err(
+                        self.invalid(format!("text {:?} is outside of a plural category", text))
+                    )
+                }
+                XmlNode::Element(category) => category,
+            };
+            let data = self.data(category, "dataRefStart")?;
+            let category_name = data
+                .strip_suffix('\'')
+                .and_then(|s| s.trim_end().strip_suffix(':'))
+                .map(str::trim)
+                .filter(|name| {
+                    PluralForm::try_from_str(name).is_some()
+                        || name
+                            .strip_prefix('=')
+                            .is_some_and(|number| number.parse::<u64>().is_ok())
+                })
+                .ok_or_else(|| self.invalid(format!("{:?} is not a plural category", data)))?;
+            let mut value = SourceBuffer::default();
+            self.read(&category.children, &mut value)?;
+            let value = value.finish();
+            // values are quoted without escapes, so they can't contain both quotes.
+            let quote = match (value.contains('\''), value.contains('"')) {
+                (false, _) => '\'',
+                (true, false) => '"',
+                (true, true) => {
+                    return Err(self.invalid(format!(
+                        "the plural category {} can't contain both quotes",
+                        category_name
+                    )))
+                }
+            };
+            args.push(format!("{}: {}{}{}", category_name, quote, value, quote));
+        }
+        buff.push_syntax(&format!("{{{{ {}, plural({}) }}}}", name, args.join("; ")));
+        Ok(())
+    }
+}
+
+// Source of an imported translation, the text is escaped so it is not parsed as interpolations.
+#[derive(Default)]
+struct SourceBuffer {
+    source: String,
+    text: String,
+}
+
+impl SourceBuffer {
+    fn flush_text(&mut self, before_token: bool) {
+        if !self.text.is_empty() {
+            self.source
+                .push_str(&escape_literal(&self.text, before_token));
+            self.text.clear();
+        }
+    }
+
+    fn push_syntax(&mut self, syntax: &str) {
+        self.flush_text(true);
+        self.source.push_str(syntax);
+    }
+
+    fn finish(mut self) -> String {
+        self.flush_text(false);
+        self.source
+    }
+}
+
+// Find the key path of an unit id, along with the plural suffix if the unit is a plural form.
+fn resolve_unit_id<'a, 'b>(
+    builder_keys: &'a BuildersKeys,
+    namespace: Option<&str>,
+    id: &'b str,
+) -> Option<(Vec<&'b str>, String, &'a InterpolOrLit)> {
+    let mut path = id.split('.').collect::<Vec<_>>();
+    if let Some((_, interpolation)) = find_default_value(builder_keys, namespace, &path) {
+        return Some((path, String::new(), interpolation));
+    }
+    let last = path.pop()?;
+    let (base, form) = last.rsplit_once('_')?;
+    let form = PluralForm::try_from_str(form)?;
+    let (key, rule_type) = match base.strip_suffix("_ordinal") {
+        Some(key) => (key, PluralRuleType::Ordinal),
+        None => (base, PluralRuleType::Cardinal),
+    };
+    path.push(key);
+    match find_default_value(builder_keys, namespace, &path)? {
+        (default_value, interpolation)
+            if catalog_plurals(default_value)
+                .is_some_and(|plurals| plurals.rule_type == rule_type) =>
+        {
+            Some((path, form_key_suffix(rule_type, form), interpolation))
+        }
+        _ => None,
+    }
+}
+
+fn text_content(nodes: &[XmlNode]) -> String {
+    let mut buff = String::new();
+    for node in nodes {
+        match node {
+            XmlNode::Text(text) => buff.push_str(text),
+            XmlNode::Element(element) => buff.push_str(&text_content(&element.children)),
+        }
+    }
+    buff
+}
+
+pub(crate) fn import_xliff(
+    builder_keys: &BuildersKeys,
+    content: &str,
+) -> Result<ImportedTranslations, XliffError> {
+    let root = parse_xml(content)?;
+    if root.name != "xliff" {
+        return Err(XliffError::Syntax {
+            line: 1,
+            message: format!("expected a <xliff> root element, found <{}>", root.name),
+        });
+    }
+    let locale = root
+        .attr("trgLang")
+        .ok_or(XliffError::MissingTargetLocale)?;
+    if locale_index(builder_keys, locale).is_none() {
+        return Err(XliffError::UnknownLocale(locale.to_string()));
+    }
+
+    let mut imported = ImportedTranslations::new(locale);
+
+    for file in root.elements("file") {
+        let namespace = match builder_keys {
+            BuildersKeys::NameSpaces { .. } => file.attr("id"),
+            BuildersKeys::Locales { .. } => None,
+        };
+        let mut units = vec![];
+        collect_units(file, &mut units);
+        for unit in units {
+            let id = unit.attr("id").unwrap_or_default();
+            let key_path = match namespace {
+                Some(namespace) => format!("{}::{}", namespace, id),
+                None => id.to_string(),
+            };
+            let (path, suffix, interpolation) = resolve_unit_id(builder_keys, namespace, id)
+                .ok_or_else(|| XliffError::UnknownKey(key_path.clone()))?;
+            let original_data = unit
+                .element("originalData")
+                .into_iter()
+                .flat_map(|original_data| original_data.elements("data"))
+                .filter_map(|data| Some((data.attr("id")?, data)))
+                .collect::<Vec<_>>();
+            let data_contents = original_data
+                .iter()
+                .map(|(id, data)| (*id, text_content(&data.children)))
+                .collect::<Vec<_>>();
+            let reader = InlineReader {
+                key: &key_path,
+                original_data: data_contents
+                    .iter()
+                    .map(|(id, data)| (*id, data.as_str()))
+                    .collect(),
+                interpolation,
+            };
+
+            let mut value = SourceBuffer::default();
+            let mut has_target = false;
+            for child in &unit.children {
+                let XmlNode::Element(child) = child else {
+                    continue;
+                };
+                if child.name != "segment" && child.name != "ignorable" {
+                    continue;
+                }
+                match child.element("target") {
+                    Some(target) => {
+                        has_target |= child.name == "segment";
+                        reader.read(&target.children, &mut value)?;
+                    }
+                    // whitespaces between segments can be left untranslated.
+                    None if child.name == "ignorable" => {
+                        if let Some(source) = child.element("source") {
+                            reader.read(&source.children, &mut value)?;
+                        }
+                    }
+                    None => {}
+                }
+            }
+            let value = value.finish();
+            if !has_target || value.is_empty() {
+                continue;
+            }
+
+            let (key, parents) = path.split_last().expect("path can't be empty");
+            imported.insert(namespace, parents, format!("{}{}", key, suffix), value);
+        }
+    }
+
+    Ok(imported)
+}
+
+fn collect_units<'a>(element: &'a XmlElement, units: &mut Vec<&'a XmlElement>) {
+    for child in &element.children {
+        if let XmlNode::Element(child) = child {
+            match child.name.as_str() {
+                "unit" => units.push(child),
+                "group" => collect_units(child, units),
+                _ => {}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_document() {
+        let content = r#"<?xml version="1.0" encoding="UTF-8"?>
+<!-- comment -->
+<xliff xmlns="urn:oasis:names:tc:xliff:document:2.0" version="2.0" srcLang="en" trgLang='fr'>
+  <file id="translations">
+    <unit id="key">
+      <segment>
+        <source>a &lt; b <![CDATA[<raw>]]><ph id="1" dataRef="d1"/></source>
+      </segment>
+    </unit>
+  </file>
+</xliff>"#;
+        let root = parse_xml(content).unwrap();
+        assert_eq!(root.attr("trgLang"), Some("fr"));
+        let source = root
+            .element("file")
+            .and_then(|file| file.element("unit"))
+            .and_then(|unit| unit.element("segment"))
+            .and_then(|segment| segment.element("source"))
+            .unwrap();
+        assert_eq!(source.children.len(), 3);
+        assert_eq!(source.children[0], XmlNode::Text("a < b ".into()));
+        assert_eq!(source.children[1], XmlNode::Text("<raw>".into()));
+        assert_eq!(text_content(&source.children), "a < b <raw>");
+    }
+
+    #[test]
+    fn syntax_error_line() {
+        let content = "<xliff>\n<file>\n</unit>\n</xliff>";
+        let Err(XliffError::Syntax { line, .. }) = parse_xml(content) else {
+            panic!("expected a syntax error");
+        };
+        assert_eq!(line, 3);
+    }
+
+    #[test]
+    fn escape_roundtrip() {
+        let s = "<b>\"a\" & 'b'</b>";
+        let reader = XmlReader { source: "", pos: 0 };
+        assert_eq!(reader.decode(&escape(s)).unwrap(), s);
+        assert_eq!(reader.decode("&#233;&#x263A;").unwrap(), "é☺");
+    }
+
+    #[test]
+    fn validate_inline() {
+        use leptos_i18n_parser::parse_locales::locale::InterpolationKeys;
+        use leptos_i18n_parser::utils::formatter::Formatter;
+
+        let mut keys = InterpolationKeys::default();
+        keys.push_var(Key::new("var_name").unwrap(), Formatter::None);
+        keys.push_comp(
+            &mut KeyPath::new(None),
+            Key::new("comp_b").unwrap(),
+            ComponentKind::Paired,
+        )
+        .unwrap();
+        keys.push_comp(
+            &mut KeyPath::new(None),
+            Key::new("comp_br").unwrap(),
+            ComponentKind::SelfClosing,
+        )
+        .unwrap();
+        let interpolation = InterpolOrLit::Interpol(keys);
+        let reader = InlineReader {
+            key: "key",
+            original_data: [
+                ("d1", "{{ name }}"),
+                ("d2", "<b class=\"x\">"),
+                ("d3", "{{ other }}"),
+                ("d4", "<br/>"),
+            ]
+            .into(),
+            interpolation: &interpolation,
+        };
+        let target = parse_xml(
+            r#"<target>Hi <pc id="2" dataRefStart="d2"><ph id="1" dataRef="d1"/></pc><ph id="3" dataRef="d4"/>!</target>"#,
+        )
+        .unwrap();
+        let mut buff = SourceBuffer::default();
+        reader.read(&target.children, &mut buff).unwrap();
+        assert_eq!(buff.finish(), r#"Hi <b class="x">{{ name }}</b><br/>!"#);
+
+        let target = parse_xml(r"<target>a &lt; b\</target>").unwrap();
+        let mut buff = SourceBuffer::default();
+        reader.read(&target.children, &mut buff).unwrap();
+        assert_eq!(buff.finish(), r"a \< b\");
+
+        let target = parse_xml(r#"<target>C:\<ph id="1" dataRef="d1"/></target>"#).unwrap();
+        let mut buff = SourceBuffer::default();
+        reader.read(&target.children, &mut buff).unwrap();
+        assert_eq!(buff.finish(), r"C:\\{{ name }}");
+
+        let target = parse_xml(r#"<target><ph id="1" dataRef="d3"/></target>"#).unwrap();
+        let err = reader.read(&target.children, &mut SourceBuffer::default());
+        assert!(
+            matches!(err, Err(XliffError::UnknownVariable { variable, .. }) if variable == "other")
+        );
+    }
+
+    #[test]
+    fn inline_plural_roundtrip() {
+        let dir = crate::catalog::test_utils::TempDir::new("xliff_inline_plural");
+        let locales_dir = dir.locales_dir();
+        std::fs::write(
+            locales_dir.join("en.json"),
+            r#"{ "apples": "You have {{ n, plural(=0: 'no apple'; one: '{{ n }} apple'; other: '{{ n }} apples') }}." }"#,
+        )
+        .unwrap();
+        std::fs::write(
+            locales_dir.join("fr.json"),
+            r#"{ "apples": "Vous avez {{ n, plural(one: '{{ n }} pomme'; other: '{{ n }} pommes') }}." }"#,
+        )
+        .unwrap();
+
+        let infos = crate::TranslationsInfos::parse_at_dir(dir.path()).unwrap();
+        let xliff = infos.export_xliff("fr").unwrap();
+        assert!(xliff.contains(
+            r#"<source>You have <pc id="1" dataRefStart="d1" dataRefEnd="d2"><pc id="2" dataRefStart="d3" dataRefEnd="d4">no apple</pc><pc id="3" dataRefStart="d5" dataRefEnd="d4"><ph id="4" dataRef="d6"/> apple</pc><pc id="5" dataRefStart="d7" dataRefEnd="d4"><ph id="6" dataRef="d6"/> apples</pc><pc id="7" dataRefStart="d8" dataRefEnd="d4"><ph id="8" dataRef="d6"/> apples</pc></pc>.</source>"#
+        ));
+        // french has a "many" category, it is exported with the text of "other".
+        let xliff = xliff.replace("pommes</pc></pc>", "pommes d'api</pc></pc>");
+        infos
+            .import_xliff(&xliff)
+            .unwrap()
+            .write_to_dir(&locales_dir)
+            .unwrap();
+
+        let content = std::fs::read_to_string(locales_dir.join("fr.json")).unwrap();
+        let fr: serde_json::Value = serde_json::from_str(&content).unwrap();
+        assert_eq!(
+            fr["apples"],
+            "Vous avez {{ n, plural(one: '{{ n }} pomme'; many: '{{ n }} pommes'; other: \"{{ n }} pommes d'api\") }}."
+        );
+    }
+}
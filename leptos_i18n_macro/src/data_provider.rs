@@ -1,4 +1,4 @@
-use quote::quote;
+use quote::{format_ident, quote};
 use syn::{parse_macro_input, DeriveInput};
 
 pub fn derive_icu_data_provider(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
@@ -29,6 +29,41 @@ pub fn derive_icu_data_provider(input: proc_macro::TokenStream) -> proc_macro::T
         quote!()
     };
 
+    let new_compact_num_formatter = if cfg!(feature = "format_compact_nums") {
+        quote! {
+            fn try_new_short_compact_num_formatter(
+                &self,
+                locale: &leptos_i18n::reexports::icu::provider::DataLocale,
+                options: leptos_i18n::reexports::icu::decimal::options::FixedDecimalFormatterOptions
+            ) -> Result<leptos_i18n::reexports::icu::compactdecimal::CompactDecimalFormatter, leptos_i18n::reexports::icu::compactdecimal::CompactDecimalError> {
+                leptos_i18n::reexports::icu::compactdecimal::CompactDecimalFormatter::try_new_short_unstable(self, locale, options.into())
+            }
+
+            fn try_new_long_compact_num_formatter(
+                &self,
+                locale: &leptos_i18n::reexports::icu::provider::DataLocale,
+                options: leptos_i18n::reexports::icu::decimal::options::FixedDecimalFormatterOptions
+            ) -> Result<leptos_i18n::reexports::icu::compactdecimal::CompactDecimalFormatter, leptos_i18n::reexports::icu::compactdecimal::CompactDecimalError> {
+                leptos_i18n::reexports::icu::compactdecimal::CompactDecimalFormatter::try_new_long_unstable(self, locale, options.into())
+            }
+        }
+    } else {
+        quote!()
+    };
+
+    let new_percent_formatter = if cfg!(feature = "format_percent") {
+        quote! {
+            fn try_new_percent_formatter(
+                &self,
+                locale: &leptos_i18n::reexports::icu::provider::DataLocale,
+            ) -> Result<leptos_i18n::formatting::PercentFormatter, leptos_i18n::reexports::icu::provider::DataError> {
+                leptos_i18n::formatting::PercentFormatter::try_new_unstable(self, locale)
+            }
+        }
+    } else {
+        quote!()
+    };
+
     let new_datetime_formatter = if cfg!(feature = "format_datetime") {
         quote! {
             fn try_new_date_formatter(
@@ -59,6 +94,34 @@ pub fn derive_icu_data_provider(input: proc_macro::TokenStream) -> proc_macro::T
         quote!()
     };
 
+    let new_components_formatter = if cfg!(feature = "format_datetime_components") {
+        quote! {
+            fn try_new_components_formatter(
+                &self,
+                locale: &leptos_i18n::reexports::icu::provider::DataLocale,
+                options: leptos_i18n::reexports::icu::datetime::options::components::Bag
+            ) -> Result<leptos_i18n::reexports::icu::datetime::DateTimeFormatter, leptos_i18n::reexports::icu::datetime::DateTimeError> {
+                leptos_i18n::reexports::icu::datetime::DateTimeFormatter::try_new_experimental_unstable(self, locale, options.into())
+            }
+        }
+    } else {
+        quote!()
+    };
+
+    let new_zoned_datetime_formatter = if cfg!(feature = "format_zoned_datetime") {
+        quote! {
+            fn try_new_zoned_datetime_formatter(
+                &self,
+                locale: &leptos_i18n::reexports::icu::provider::DataLocale,
+                options: leptos_i18n::reexports::icu::datetime::options::DateTimeFormatterOptions
+            ) -> Result<leptos_i18n::reexports::icu::datetime::ZonedDateTimeFormatter, leptos_i18n::reexports::icu::datetime::DateTimeError> {
+                leptos_i18n::reexports::icu::datetime::ZonedDateTimeFormatter::try_new_experimental_unstable(self, locale, options, Default::default())
+            }
+        }
+    } else {
+        quote!()
+    };
+
     let new_list_formatter = if cfg!(feature = "format_list") {
         quote! {
             fn try_new_and_list_formatter(
@@ -117,18 +180,108 @@ pub fn derive_icu_data_provider(input: proc_macro::TokenStream) -> proc_macro::T
         quote!()
     };
 
+    let new_relative_time_formatter = if cfg!(feature = "format_relative_time") {
+        // ICU4X has one constructor per style and unit.
+        let arms = ["Long", "Short", "Narrow"].into_iter().flat_map(|style| {
+            [
+                "Second", "Minute", "Hour", "Day", "Week", "Month", "Quarter", "Year",
+            ]
+            .into_iter()
+            .map(move |unit| {
+                let constructor = format_ident!(
+                    "try_new_{}_{}_unstable",
+                    style.to_lowercase(),
+                    unit.to_lowercase()
+                );
+                let style = format_ident!("{}", style);
+                let unit = format_ident!("{}", unit);
+                quote! {
+                    (
+                        leptos_i18n::formatting::RelativeTimeStyle::#style,
+                        leptos_i18n::formatting::RelativeTimeUnit::#unit,
+                    ) => leptos_i18n::reexports::icu::relativetime::RelativeTimeFormatter::#constructor(self, locale, options)
+                }
+            })
+        });
+        quote! {
+            fn try_new_relative_time_formatter(
+                &self,
+                locale: &leptos_i18n::reexports::icu::provider::DataLocale,
+                style: leptos_i18n::formatting::RelativeTimeStyle,
+                unit: leptos_i18n::formatting::RelativeTimeUnit,
+                options: leptos_i18n::reexports::icu::relativetime::options::RelativeTimeFormatterOptions
+            ) -> Result<leptos_i18n::reexports::icu::relativetime::RelativeTimeFormatter, leptos_i18n::reexports::icu::relativetime::RelativeTimeError> {
+                match (style, unit) {
+                    #(#arms,)*
+                }
+            }
+        }
+    } else {
+        quote!()
+    };
+
+    let new_display_names_formatters = if cfg!(feature = "format_display_names") {
+        quote! {
+            fn try_new_language_names_formatter(
+                &self,
+                locale: &leptos_i18n::reexports::icu::provider::DataLocale,
+                options: leptos_i18n::reexports::icu::displaynames::DisplayNamesOptions
+            ) -> Result<leptos_i18n::reexports::icu::displaynames::LocaleDisplayNamesFormatter, leptos_i18n::reexports::icu::provider::DataError> {
+                leptos_i18n::reexports::icu::displaynames::LocaleDisplayNamesFormatter::try_new_unstable(self, locale, options)
+            }
+
+            fn try_new_region_names_formatter(
+                &self,
+                locale: &leptos_i18n::reexports::icu::provider::DataLocale,
+                options: leptos_i18n::reexports::icu::displaynames::DisplayNamesOptions
+            ) -> Result<leptos_i18n::reexports::icu::displaynames::RegionDisplayNames, leptos_i18n::reexports::icu::provider::DataError> {
+                leptos_i18n::reexports::icu::displaynames::RegionDisplayNames::try_new_unstable(self, locale, options)
+            }
+        }
+    } else {
+        quote!()
+    };
+
+    let new_collator = if cfg!(feature = "collation") {
+        quote! {
+            fn try_new_collator(
+                &self,
+                locale: &leptos_i18n::reexports::icu::provider::DataLocale,
+                options: leptos_i18n::reexports::icu::collator::CollatorOptions
+            ) -> Result<leptos_i18n::reexports::icu::collator::Collator, leptos_i18n::reexports::icu::collator::CollatorError> {
+                leptos_i18n::reexports::icu::collator::Collator::try_new_unstable(self, locale, options)
+            }
+        }
+    } else {
+        quote!()
+    };
+
     let expanded = quote! {
         impl #impl_generics leptos_i18n::custom_provider::IcuDataProvider for #name #ty_generics #where_clause {
 
             #new_num_formatter
 
+            #new_compact_num_formatter
+
+            #new_percent_formatter
+
             #new_datetime_formatter
 
+            #new_components_formatter
+
+            #new_zoned_datetime_formatter
+
             #new_list_formatter
 
             #new_plural_rules
 
             #new_currency_formatter
+
+            #new_relative_time_formatter
+
+            #new_display_names_formatters
+
+            #new_collator
         }
     };
 
@@ -115,6 +115,7 @@ fn parse_map_values(
         top_locale_name: locale.clone(),
         name: name.clone(),
         keys,
+        metadata: BTreeMap::new(),
         strings: vec![],
         top_locale_string_count: 0,
     }))))
@@ -241,6 +242,9 @@ fn parse_ranges(
             inner: UntypedRangesInner::I32(vec![range]),
             count_key: Key::count(),
         },
+        TypeOrRange::Select(_) => {
+            return Err(content.error("selects are not supported in `declare_locales!`."))
+        }
     };
 
     ranges.deserialize_inner(RangeParseBuffer(content), seed)?;
@@ -334,6 +338,7 @@ fn parse_locale(
         top_locale_name: locale_key.clone(),
         name: locale_key,
         keys,
+        metadata: BTreeMap::new(),
         strings: vec![],
         top_locale_string_count: 0,
     })
@@ -356,15 +361,24 @@ impl syn::parse::Parse for ParsedInput {
             input.parse()?
         };
 
-        let interpolate_display = ident == "interpolate_display";
+        // optional flags: interpolate_display, bidi_isolation,
+        let mut interpolate_display = false;
+        let mut bidi_isolation = false;
+        let mut ident = ident;
+        loop {
+            if ident == "interpolate_display" {
+                interpolate_display = true;
+            } else if ident == "bidi_isolation" {
+                bidi_isolation = true;
+            } else {
+                break;
+            }
+            input.parse::<Token![,]>()?;
+            ident = input.parse()?;
+        }
 
         // default: "defaultloc",
-        let def_ident: Ident = if interpolate_display {
-            input.parse::<Token![,]>()?;
-            input.parse()?
-        } else {
-            ident
-        };
+        let def_ident = ident;
         if def_ident != "default" {
             return emit_err(def_ident, "not default");
         }
@@ -420,6 +434,10 @@ impl syn::parse::Parse for ParsedInput {
                 locales_dir: "".into(),
                 translations_uri: None,
                 extensions: Default::default(),
+                message_format: Default::default(),
+                message_format_files: Default::default(),
+                key_separator: None,
+                bidi_isolation,
             },
             locales: LocalesOrNamespaces::Locales(locales),
             crate_path,
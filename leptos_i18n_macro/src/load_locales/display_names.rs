@@ -0,0 +1,70 @@
+use icu_locid::LanguageIdentifier;
+use proc_macro2::{Ident, TokenStream};
+#[cfg(feature = "format_display_names")]
+use quote::quote;
+
+use leptos_i18n_parser::utils::key::Key;
+
+/// Generate the `native_name` and `display_name_in` methods of the `Locale` trait,
+/// the names are resolved here with the CLDR data so the generated code only contains string literals.
+#[cfg(feature = "format_display_names")]
+pub fn display_names_methods(
+    enum_ident: &Ident,
+    locids: &[(&Key, LanguageIdentifier)],
+) -> TokenStream {
+    use icu_experimental::displaynames::LocaleDisplayNamesFormatter;
+
+    let formatters = locids
+        .iter()
+        .map(|(_, locid)| LocaleDisplayNamesFormatter::try_new(&locid.into(), Default::default()))
+        .collect::<Vec<_>>();
+
+    let display_name = |locid: &LanguageIdentifier, in_index: usize| -> String {
+        match &formatters[in_index] {
+            Ok(formatter) => formatter.of(&locid.clone().into()).into_owned(),
+            Err(_) => locid.to_string(),
+        }
+    };
+
+    let native_name_match_arms = locids.iter().enumerate().map(|(index, (locale, locid))| {
+        let name = display_name(locid, index);
+        quote!(#enum_ident::#locale => #name)
+    });
+
+    let display_name_in_match_arms = locids.iter().flat_map(|(locale, locid)| {
+        let display_name = &display_name;
+        locids
+            .iter()
+            .enumerate()
+            .map(move |(in_index, (in_locale, _))| {
+                let name = display_name(locid, in_index);
+                quote!((#enum_ident::#locale, #enum_ident::#in_locale) => #name)
+            })
+    });
+
+    quote! {
+        fn native_name(self) -> &'static str {
+            match self {
+                #(
+                    #native_name_match_arms,
+                )*
+            }
+        }
+
+        fn display_name_in(self, other: Self) -> &'static str {
+            match (self, other) {
+                #(
+                    #display_name_in_match_arms,
+                )*
+            }
+        }
+    }
+}
+
+#[cfg(not(feature = "format_display_names"))]
+pub fn display_names_methods(
+    _enum_ident: &Ident,
+    _locids: &[(&Key, LanguageIdentifier)],
+) -> TokenStream {
+    TokenStream::new()
+}
@@ -1,6 +1,7 @@
 use std::collections::BTreeMap;
 use std::collections::BTreeSet;
 
+use leptos_i18n_parser::parse_locales::locale::ComponentKind;
 use leptos_i18n_parser::parse_locales::locale::DefaultedLocales;
 use leptos_i18n_parser::parse_locales::locale::InterpolationKeys;
 use leptos_i18n_parser::parse_locales::locale::Locale;
@@ -18,6 +19,7 @@ use super::parsed_value;
 // use super::parsed_value::RangeOrPlural;
 use super::parsed_value::TRANSLATIONS_KEY;
 use super::ranges::RangeType;
+use super::select;
 use super::strings_accessor_method_name;
 use crate::utils::formatter::Formatter;
 use crate::utils::EitherOfWrapper;
@@ -46,14 +48,15 @@ pub struct Interpolation {
     pub imp: TokenStream,
 }
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Clone)]
 enum RangeOrPlural {
     Range(RangeType),
     Plural,
+    Select(syn::Ident),
 }
 
-impl From<leptos_i18n_parser::parse_locales::locale::RangeOrPlural> for RangeOrPlural {
-    fn from(value: leptos_i18n_parser::parse_locales::locale::RangeOrPlural) -> Self {
+impl RangeOrPlural {
+    fn new(value: leptos_i18n_parser::parse_locales::locale::RangeOrPlural, key: &Key) -> Self {
         match value {
             leptos_i18n_parser::parse_locales::locale::RangeOrPlural::Range(range_type) => {
                 RangeOrPlural::Range(range_type.into())
@@ -61,12 +64,13 @@ impl From<leptos_i18n_parser::parse_locales::locale::RangeOrPlural> for RangeOrP
             leptos_i18n_parser::parse_locales::locale::RangeOrPlural::Plural => {
                 RangeOrPlural::Plural
             }
+            leptos_i18n_parser::parse_locales::locale::RangeOrPlural::Select => {
+                RangeOrPlural::Select(select::enum_ident(key))
+            }
         }
     }
-}
 
-impl RangeOrPlural {
-    pub fn to_bound(self) -> TokenStream {
+    pub fn to_bound(&self) -> TokenStream {
         match self {
             RangeOrPlural::Range(range_type) => {
                 quote!(l_i18n_crate::__private::InterpolateRangeCount<#range_type>)
@@ -74,6 +78,9 @@ impl RangeOrPlural {
             RangeOrPlural::Plural => {
                 quote!(l_i18n_crate::__private::InterpolatePluralCount)
             }
+            RangeOrPlural::Select(enum_ident) => {
+                quote!(l_i18n_crate::__private::InterpolateSelect<selects::#enum_ident>)
+            }
         }
     }
 }
@@ -85,6 +92,8 @@ enum VarOrComp {
     },
     Comp {
         into_view: syn::Ident,
+        kind: ComponentKind,
+        with_attrs: bool,
     },
 }
 
@@ -98,7 +107,7 @@ impl Field {
     fn get_var_generics(
         generic: &syn::Ident,
         formatters: &[Formatter],
-        plural: Option<RangeOrPlural>,
+        plural: Option<&RangeOrPlural>,
     ) -> TokenStream {
         let bounds = formatters.iter().copied().map(Formatter::to_bound);
         let plural_bound = plural.map(RangeOrPlural::to_bound);
@@ -110,7 +119,7 @@ impl Field {
     fn get_string_var_generics(
         generic: &syn::Ident,
         formatters: &[Formatter],
-        range: Option<RangeOrPlural>,
+        range: Option<&RangeOrPlural>,
     ) -> Option<TokenStream> {
         match range {
             None => {
@@ -124,6 +133,10 @@ impl Field {
                     quote!(#generic: #(#bounds +)* Clone + Into<l_i18n_crate::reexports::icu::plurals::PluralOperands>),
                 )
             }
+            Some(RangeOrPlural::Select(enum_ident)) => {
+                let bounds = formatters.iter().copied().map(Formatter::to_string_bound);
+                Some(quote!(#generic: #(#bounds +)* Clone + Into<selects::#enum_ident>))
+            }
         }
     }
 
@@ -134,12 +147,30 @@ impl Field {
                 formatters,
                 plural: range,
             } => {
-                let ts = Self::get_var_generics(generic, formatters, *range);
+                let ts = Self::get_var_generics(generic, formatters, range.as_ref());
                 EitherIter::Iter1(std::iter::once(ts))
             }
-            VarOrComp::Comp { into_view } => {
+            VarOrComp::Comp {
+                into_view,
+                kind,
+                with_attrs,
+            } => {
+                let comp_bound = match (kind, with_attrs) {
+                    (ComponentKind::Paired, false) => {
+                        quote!(l_i18n_crate::__private::InterpolateComp<#into_view>)
+                    }
+                    (ComponentKind::SelfClosing, false) => {
+                        quote!(l_i18n_crate::__private::InterpolateCompSelfClosing<#into_view>)
+                    }
+                    (ComponentKind::Paired, true) => {
+                        quote!(l_i18n_crate::__private::InterpolateCompWithAttrs<#into_view>)
+                    }
+                    (ComponentKind::SelfClosing, true) => {
+                        quote!(l_i18n_crate::__private::InterpolateCompSelfClosingWithAttrs<#into_view>)
+                    }
+                };
                 let ts = [
-                    quote!(#generic: l_i18n_crate::__private::InterpolateComp<#into_view>),
+                    quote!(#generic: #comp_bound),
                     quote!(#into_view: l_i18n_crate::reexports::leptos::IntoView + 'static),
                 ];
                 EitherIter::Iter2(ts.into_iter())
@@ -153,7 +184,7 @@ impl Field {
             VarOrComp::Var {
                 formatters,
                 plural: range,
-            } => Self::get_string_var_generics(generic, formatters, *range),
+            } => Self::get_string_var_generics(generic, formatters, range.as_ref()),
             VarOrComp::Comp { .. } => {
                 Some(quote!(#generic: l_i18n_crate::display::DisplayComponent))
             }
@@ -164,7 +195,7 @@ impl Field {
         let generic = std::iter::once(&self.generic);
         match &self.var_or_comp {
             VarOrComp::Var { .. } => EitherIter::Iter1(generic),
-            VarOrComp::Comp { into_view } => EitherIter::Iter2(generic.chain(Some(into_view))),
+            VarOrComp::Comp { into_view, .. } => EitherIter::Iter2(generic.chain(Some(into_view))),
         }
     }
 
@@ -198,7 +229,7 @@ impl Field {
     pub fn as_into_view_generic(&self) -> Option<&syn::Ident> {
         match &self.var_or_comp {
             VarOrComp::Var { .. } => None,
-            VarOrComp::Comp { into_view } => Some(into_view),
+            VarOrComp::Comp { into_view, .. } => Some(into_view),
         }
     }
 }
@@ -215,7 +246,9 @@ impl Interpolation {
             formatters.sort_unstable();
             let var_or_comp = VarOrComp::Var {
                 formatters,
-                plural: infos.range_count.map(Into::into),
+                plural: infos
+                    .range_count
+                    .map(|range_count| RangeOrPlural::new(range_count, &key)),
             };
             let generic = format_ident!("__{}__", key);
             Field {
@@ -225,9 +258,14 @@ impl Interpolation {
             }
         });
 
-        let comps = keys.iter_comps().map(|key| {
+        let comps = keys.iter_comps().map(|(key, kind)| {
             let into_view = format_ident!("__into_view_{}__", key);
-            let var_or_comp = VarOrComp::Comp { into_view };
+            let with_attrs = keys.comp_has_attrs(&key);
+            let var_or_comp = VarOrComp::Comp {
+                into_view,
+                kind,
+                with_attrs,
+            };
             let generic = format_ident!("__{}__", key);
             Field {
                 key,
@@ -724,6 +762,21 @@ impl Interpolation {
 
         let destructure = quote!(let Self { #(#fields_key,)* #locale_field, .. } = self;);
 
+        let comps_with_attrs = fields
+            .iter()
+            .filter(|field| {
+                matches!(
+                    field.var_or_comp,
+                    VarOrComp::Comp {
+                        with_attrs: true,
+                        ..
+                    }
+                )
+            })
+            .map(|field| field.key.clone())
+            .collect();
+        // the locales impls are lazy, the guard must live until they are quoted.
+        let _comps_with_attrs_guard = parsed_value::ComponentsWithAttrsGuard::new(comps_with_attrs);
         let locales_impls =
             Self::create_locale_impl(key, enum_ident, locales, locale_type_ident, defaults);
         if cfg!(all(feature = "dynamic_load", not(feature = "ssr"))) {
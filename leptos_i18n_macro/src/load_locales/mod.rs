@@ -1,15 +1,16 @@
 use std::{collections::BTreeMap, ops::Not};
 
 pub mod declare_locales;
+pub mod display_names;
 pub mod interpolate;
 pub mod locale;
 pub mod parsed_value;
 pub mod plurals;
 pub mod ranges;
+pub mod select;
 pub mod tracking;
 pub mod warning;
 
-use icu_locid::LanguageIdentifier;
 use interpolate::Interpolation;
 use leptos_i18n_parser::{
     parse_locales::{
@@ -72,6 +73,8 @@ fn load_locales_inner(
         return Err(Error::MissingTranslationsURI.into());
     }
 
+    let _bidi_isolation_guard = parsed_value::BidiIsolationGuard::new(cfg_file.bidi_isolation);
+
     let keys = leptos_i18n_parser::parse_locales::make_builder_keys(
         locales,
         cfg_file,
@@ -84,6 +87,8 @@ fn load_locales_inner(
     let keys_ident = syn::Ident::new("I18nKeys", Span::call_site());
     let translation_unit_enum_ident = syn::Ident::new("I18nTranslationUnitsId", Span::call_site());
 
+    let select_enums = select::create_select_enums(&keys)?;
+
     let locale_type = create_locale_type(
         &keys,
         &keys_ident,
@@ -256,6 +261,8 @@ fn load_locales_inner(
 
             #locale_enum
 
+            #select_enums
+
             #locale_type
 
             #[inline]
@@ -320,18 +327,25 @@ fn create_locales_enum(
         })
         .collect::<Vec<_>>();
 
-    let const_icu_locales = constant_names_ident
+    let (const_icu_locales, as_icu_locale_match_arms): (Vec<_>, Vec<_>) = constant_names_ident
         .iter()
         .map(|(key, ident)| {
             let locale = &key.name;
-            quote!(const #ident: &l_i18n_crate::reexports::icu::locid::Locale = &l_i18n_crate::reexports::icu::locid::locale!(#locale);)
+            // `locale!` only supports a single variant and a single unicode extension keyword,
+            // other locales such as "th-TH-u-ca-buddhist-nu-thai" are parsed once at runtime.
+            if icu_locid::Locale::try_from_bytes_with_single_variant_single_keyword_unicode_extension(locale.as_bytes()).is_ok() {
+                (
+                    quote!(const #ident: &l_i18n_crate::reexports::icu::locid::Locale = &l_i18n_crate::reexports::icu::locid::locale!(#locale);),
+                    quote!(#enum_ident::#key => #ident),
+                )
+            } else {
+                (
+                    quote!(static #ident: std::sync::OnceLock<l_i18n_crate::reexports::icu::locid::Locale> = std::sync::OnceLock::new();),
+                    quote!(#enum_ident::#key => #ident.get_or_init(|| #locale.parse().unwrap())),
+                )
+            }
         })
-        .collect::<Vec<_>>();
-
-    let as_icu_locale_match_arms = constant_names_ident
-        .iter()
-        .map(|(variant, constant)| quote!(#enum_ident::#variant => #constant))
-        .collect::<Vec<_>>();
+        .unzip();
 
     let server_fn_mod = if cfg!(all(feature = "dynamic_load", not(feature = "csr"))) {
         quote! {
@@ -396,8 +410,8 @@ fn create_locales_enum(
 
     let locids = locales
         .iter()
-        .map(|locale| match locale.name.parse::<LanguageIdentifier>() {
-            Ok(locid) => Ok((locale, locid)),
+        .map(|locale| match locale.name.parse::<icu_locid::Locale>() {
+            Ok(locid) => Ok((locale, locid.id)),
             Err(err) => Err(Error::InvalidLocale {
                 locale: locale.name.clone(),
                 err,
@@ -417,6 +431,8 @@ fn create_locales_enum(
         }
     });
 
+    let display_names_methods = display_names::display_names_methods(enum_ident, &locids);
+
     let ts = quote! {
         #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, Default)]
         #[allow(non_camel_case_types)]
@@ -482,6 +498,8 @@ fn create_locales_enum(
                 }
             }
 
+            #display_names_methods
+
             fn get_all() -> &'static [Self] {
                 &[#(#enum_ident::#locales,)*]
             }
@@ -748,6 +766,8 @@ fn create_locale_type_inner<const IS_TOP: bool>(
         quote! {
             pub mod #subkey_mod_ident {
                 use super::{#enum_ident, l_i18n_crate};
+                #[allow(unused_imports)]
+                use super::selects;
 
                 #subkey_impl
             }
@@ -771,6 +791,8 @@ fn create_locale_type_inner<const IS_TOP: bool>(
             #[doc(hidden)]
             pub mod subkeys {
                 use super::{#enum_ident, l_i18n_crate};
+                #[allow(unused_imports)]
+                use super::selects;
 
                 #(
                     #subkeys_ts
@@ -818,6 +840,8 @@ fn create_locale_type_inner<const IS_TOP: bool>(
             #[doc(hidden)]
             pub mod builders {
                 use super::{#enum_ident, l_i18n_crate};
+                #[allow(unused_imports)]
+                use super::selects;
 
                 #(
                     #builder_impls
@@ -1132,6 +1156,88 @@ fn create_namespace_mod_ident(namespace_ident: &syn::Ident) -> syn::Ident {
     format_ident!("ns_{}", namespace_ident)
 }
 
+/// Nested namespaces ("admin/users") are accessed through a group for each of their parents ("admin").
+#[derive(Default)]
+struct NamespaceGroup<'a> {
+    namespaces: Vec<(&'a str, &'a Key, &'a syn::Ident)>,
+    groups: BTreeMap<&'a str, NamespaceGroup<'a>>,
+}
+
+impl<'a> NamespaceGroup<'a> {
+    fn new(namespaces: &'a [(&'a Namespace, syn::Ident)]) -> Self {
+        let mut root = NamespaceGroup::default();
+        for (namespace, namespace_module_ident) in namespaces {
+            let mut segments = namespace.key.namespace_segments().collect::<Vec<_>>();
+            let name = segments.pop().unwrap_at("NamespaceGroup::new_1");
+            let group = segments.into_iter().fold(&mut root, |group, segment| {
+                group.groups.entry(segment).or_default()
+            });
+            group
+                .namespaces
+                .push((name, &namespace.key, namespace_module_ident));
+        }
+        root
+    }
+
+    fn segment_ident(segment: &str) -> syn::Ident {
+        format_ident!("{}", segment.replace('-', "_"))
+    }
+
+    fn accessors(
+        &self,
+        prefix: &TokenStream,
+        enum_ident: &syn::Ident,
+        group_path: &mut Vec<&'a str>,
+        groups_ts: &mut Vec<TokenStream>,
+    ) -> Vec<TokenStream> {
+        let mut accessors = Vec::with_capacity(self.namespaces.len() + self.groups.len());
+        for (name, key, namespace_module_ident) in &self.namespaces {
+            let name = Self::segment_ident(name);
+            accessors.push(quote! {
+                pub fn #name(self) -> #prefix #namespace_module_ident::#key {
+                    #prefix #namespace_module_ident::#key::__new_internal(self.0)
+                }
+            });
+        }
+        for (name, group) in &self.groups {
+            group_path.push(name);
+            let group_ident = format_ident!("group_{}", group_path.join("__").replace('-', "_"));
+            let group_accessors = group.accessors(&quote!(), enum_ident, group_path, groups_ts);
+            group_path.pop();
+            groups_ts.push(quote! {
+                #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+                #[allow(non_camel_case_types)]
+                pub struct #group_ident(#enum_ident);
+
+                impl #group_ident {
+                    pub const fn __new_internal(locale: #enum_ident) -> Self {
+                        Self(locale)
+                    }
+
+                    #(
+                        #[allow(non_snake_case)]
+                        #group_accessors
+                    )*
+                }
+
+                impl l_i18n_crate::LocaleKeys for #group_ident {
+                    type Locale = #enum_ident;
+                    fn from_locale(locale: #enum_ident) -> Self {
+                        Self::__new_internal(locale)
+                    }
+                }
+            });
+            let name = Self::segment_ident(name);
+            accessors.push(quote! {
+                pub fn #name(self) -> #prefix #group_ident {
+                    #prefix #group_ident::__new_internal(self.0)
+                }
+            });
+        }
+        accessors
+    }
+}
+
 fn create_namespaces_types(
     keys_ident: &syn::Ident,
     enum_ident: &syn::Ident,
@@ -1170,24 +1276,24 @@ fn create_namespaces_types(
             );
 
             quote! {
+                #[allow(non_snake_case)]
                 pub mod #namespace_module_ident {
                     use super::{#enum_ident, l_i18n_crate};
+                    #[allow(unused_imports)]
+                    use super::selects;
 
                     #type_impl
                 }
             }
         });
 
-    let namespaces_accessors = namespaces
-        .iter()
-        .map(|(namespace, namespace_module_ident)| {
-            let key = &namespace.key;
-            quote! {
-                pub fn #key(self) -> namespaces::#namespace_module_ident::#key {
-                    namespaces::#namespace_module_ident::#key::__new_internal(self.0)
-                }
-            }
-        });
+    let mut namespaces_groups = vec![];
+    let namespaces_accessors = NamespaceGroup::new(&namespaces).accessors(
+        &quote!(namespaces::),
+        enum_ident,
+        &mut vec![],
+        &mut namespaces_groups,
+    );
 
     let translations_unit_variants = namespaces.iter().map(|(ns, _)| ns.key.to_token_stream());
 
@@ -1275,11 +1381,17 @@ fn create_namespaces_types(
         #[doc(hidden)]
         pub mod namespaces {
             use super::{#enum_ident, l_i18n_crate};
+            #[allow(unused_imports)]
+            use super::selects;
 
             #(
                 #namespaces_ts
             )*
 
+            #(
+                #namespaces_groups
+            )*
+
         }
 
         #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
@@ -1,3 +1,6 @@
+use std::cell::{Cell, RefCell};
+use std::collections::BTreeSet;
+
 use crate::utils::{fit_in_leptos_tuple, formatter::Formatter};
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
@@ -7,7 +10,7 @@ use leptos_i18n_parser::{
     utils::{Key, KeyPath, UnwrapAt},
 };
 
-use super::{interpolate::LOCALE_FIELD_KEY, plurals, ranges};
+use super::{interpolate::LOCALE_FIELD_KEY, plurals, ranges, select};
 
 pub const TRANSLATIONS_KEY: &str = if cfg!(feature = "dynamic_load") {
     "__i18n_translations__"
@@ -15,6 +18,45 @@ pub const TRANSLATIONS_KEY: &str = if cfg!(feature = "dynamic_load") {
     "I18N_TRANSLATIONS"
 };
 
+thread_local! {
+    static BIDI_ISOLATION: Cell<bool> = const { Cell::new(false) };
+    static COMPONENTS_WITH_ATTRS: RefCell<BTreeSet<Key>> = const { RefCell::new(BTreeSet::new()) };
+}
+
+/// Wrap the interpolated variables in bidi isolates for as long as it lives,
+/// set from the "bidi-isolation" config flag.
+pub struct BidiIsolationGuard(());
+
+impl BidiIsolationGuard {
+    pub fn new(bidi_isolation: bool) -> Self {
+        BIDI_ISOLATION.set(bidi_isolation);
+        BidiIsolationGuard(())
+    }
+}
+
+impl Drop for BidiIsolationGuard {
+    fn drop(&mut self) {
+        BIDI_ISOLATION.set(false);
+    }
+}
+
+/// Pass the translation attributes to the given components for as long as it lives,
+/// they are the components used with attributes in at least one locale.
+pub struct ComponentsWithAttrsGuard(());
+
+impl ComponentsWithAttrsGuard {
+    pub fn new(keys: BTreeSet<Key>) -> Self {
+        COMPONENTS_WITH_ATTRS.set(keys);
+        ComponentsWithAttrsGuard(())
+    }
+}
+
+impl Drop for ComponentsWithAttrsGuard {
+    fn drop(&mut self) {
+        COMPONENTS_WITH_ATTRS.take();
+    }
+}
+
 impl<'a> From<&'a leptos_i18n_parser::parse_locales::parsed_value::Literal> for Literal<'a> {
     fn from(value: &'a leptos_i18n_parser::parse_locales::parsed_value::Literal) -> Self {
         match value {
@@ -81,34 +123,63 @@ fn flatten(
         ParsedValue::Ranges(ranges) => tokens.push(ranges::to_token_stream(ranges, strings_count)),
         ParsedValue::Variable { key, formatter } => {
             let ts = Formatter::from(*formatter).var_to_view(&key.ident, &locale_field.ident);
+            let ts = if BIDI_ISOLATION.get() {
+                let locale_field = &locale_field.ident;
+                quote!(l_i18n_crate::__private::bidi_isolate_view(#locale_field, #ts))
+            } else {
+                ts
+            };
             tokens.push(quote! {{
                 let #key = core::clone::Clone::clone(&#key);
                 #ts
             }});
         }
-        ParsedValue::Component { key, inner } => {
-            let mut key_path = KeyPath::new(None);
-            let captured_keys = inner
-                .get_keys(&mut key_path)
-                .unwrap_at("parsed_value::flatten_1")
-                .is_interpol()
-                .map(|keys| {
-                    let keys = keys
-                        .iter_keys()
-                        .map(|key| quote!(let #key = core::clone::Clone::clone(&#key);));
-                    quote!(#(#keys)*)
-                });
-
-            let inner = to_token_stream(inner, strings_count);
-            let f = quote!({
-                #captured_keys
-                move || #inner
+        ParsedValue::Component { key, inner, attrs } => {
+            // components used with attributes in any locale receive them, possibly empty.
+            let with_attrs = COMPONENTS_WITH_ATTRS.with_borrow(|keys| keys.contains(key));
+            let comp_trait = match (inner.is_some(), with_attrs) {
+                (true, false) => quote!(l_i18n_crate::__private::InterpolateComp),
+                (false, false) => quote!(l_i18n_crate::__private::InterpolateCompSelfClosing),
+                (true, true) => quote!(l_i18n_crate::__private::InterpolateCompWithAttrs),
+                (false, true) => {
+                    quote!(l_i18n_crate::__private::InterpolateCompSelfClosingWithAttrs)
+                }
+            };
+            let attrs = with_attrs.then(|| {
+                let attrs = attrs.iter().map(|(name, value)| quote!((#name, #value)));
+                quote!(, &[#(#attrs,)*])
             });
-            tokens.push(quote!({
-                let __boxed_children_fn = l_i18n_crate::reexports::leptos::children::ToChildren::to_children(#f);
-                let #key = core::clone::Clone::clone(&#key);
-                move || #key(core::clone::Clone::clone(&__boxed_children_fn))
-            }));
+            let comp_view = match inner {
+                Some(inner) => {
+                    let mut key_path = KeyPath::new(None);
+                    let captured_keys = inner
+                        .get_keys(&mut key_path)
+                        .unwrap_at("parsed_value::flatten_1")
+                        .is_interpol()
+                        .map(|keys| {
+                            let keys = keys
+                                .iter_keys()
+                                .map(|key| quote!(let #key = core::clone::Clone::clone(&#key);));
+                            quote!(#(#keys)*)
+                        });
+
+                    let inner = to_token_stream(inner, strings_count);
+                    let f = quote!({
+                        #captured_keys
+                        move || #inner
+                    });
+                    quote!({
+                        let __boxed_children_fn = l_i18n_crate::reexports::leptos::children::ToChildren::to_children(#f);
+                        let #key = core::clone::Clone::clone(&#key);
+                        move || #comp_trait::to_view(&#key, core::clone::Clone::clone(&__boxed_children_fn) #attrs)
+                    })
+                }
+                None => quote!({
+                    let #key = core::clone::Clone::clone(&#key);
+                    move || #comp_trait::to_view(&#key #attrs)
+                }),
+            };
+            tokens.push(comp_view);
         }
         ParsedValue::Bloc(values) => {
             for value in values {
@@ -123,6 +194,7 @@ fn flatten(
         ParsedValue::Plurals(plurals) => {
             tokens.push(plurals::to_token_stream(plurals, strings_count))
         }
+        ParsedValue::Select(select) => tokens.push(select::to_token_stream(select, strings_count)),
     }
 }
 
@@ -142,11 +214,25 @@ fn flatten_string(
         ParsedValue::Ranges(ranges) => tokens.push(ranges::as_string_impl(ranges, strings_count)),
         ParsedValue::Variable { key, formatter } => {
             let ts = Formatter::from(*formatter).var_fmt(key, locale_field);
-            tokens.push(ts);
+            if BIDI_ISOLATION.get() {
+                tokens.push(quote!(l_i18n_crate::__private::fmt_bidi_isolated(__formatter, *#locale_field, |__formatter| #ts)));
+            } else {
+                tokens.push(ts);
+            }
         }
-        ParsedValue::Component { key, inner } => {
-            let inner = as_string_impl(inner, strings_count);
-            tokens.push(quote!(l_i18n_crate::display::DisplayComponent::fmt(#key, __formatter, |__formatter| #inner)))
+        ParsedValue::Component { key, inner, attrs } => {
+            let attrs_ts = attrs.iter().map(|(name, value)| quote!((#name, #value)));
+            match inner {
+                Some(inner) if attrs.is_empty() => {
+                    let inner = as_string_impl(inner, strings_count);
+                    tokens.push(quote!(l_i18n_crate::display::DisplayComponent::fmt(#key, __formatter, |__formatter| #inner)))
+                }
+                Some(inner) => {
+                    let inner = as_string_impl(inner, strings_count);
+                    tokens.push(quote!(l_i18n_crate::display::DisplayComponent::fmt_with_attrs(#key, __formatter, &[#(#attrs_ts,)*], |__formatter| #inner)))
+                }
+                None => tokens.push(quote!(l_i18n_crate::display::DisplayComponent::fmt_self_closing(#key, __formatter, &[#(#attrs_ts,)*]))),
+            }
         }
         ParsedValue::Bloc(values) => {
             for value in values {
@@ -163,6 +249,7 @@ fn flatten_string(
             &plurals.count_key,
             strings_count,
         )),
+        ParsedValue::Select(select) => tokens.push(select::as_string_impl(select, strings_count)),
     }
 }
 
@@ -90,7 +90,16 @@ impl ToTokens for PluralForm {
     }
 }
 
+// Exact-number overrides are guarded arms at the top of the category match.
+fn exact_arm(number: u64, ts: TokenStream) -> TokenStream {
+    quote!(_ if __plural_operands == l_i18n_crate::reexports::icu::plurals::PluralOperands::from(#number) => { #ts })
+}
+
 pub fn as_string_impl(this: &Plurals, count_key: &Key, strings_count: usize) -> TokenStream {
+    let exact_arms = this.exact.iter().map(|(number, value)| {
+        let ts = parsed_value::as_string_impl(value, strings_count);
+        exact_arm(*number, ts)
+    });
     let match_arms = this.forms.iter().map(|(form, value)| {
         let form = PluralForm::from(*form);
         let ts = parsed_value::as_string_impl(value, strings_count);
@@ -105,7 +114,9 @@ pub fn as_string_impl(this: &Plurals, count_key: &Key, strings_count: usize) ->
 
     quote! {{
         let _plural_rules = l_i18n_crate::__private::get_plural_rules(*#locale_field, #rule_type);
-        match _plural_rules.category_for(core::clone::Clone::clone(#count_key)) {
+        let __plural_operands: l_i18n_crate::reexports::icu::plurals::PluralOperands = core::convert::Into::into(core::clone::Clone::clone(#count_key));
+        match _plural_rules.category_for(__plural_operands) {
+            #(#exact_arms,)*
             #(#match_arms,)*
             _ => #other,
         }
@@ -113,21 +124,26 @@ pub fn as_string_impl(this: &Plurals, count_key: &Key, strings_count: usize) ->
 }
 
 pub fn to_token_stream(this: &Plurals, strings_count: usize) -> TokenStream {
-    let either_of = EitherOfWrapper::new(this.forms.len() + 1);
+    let exact_count = this.exact.len();
+    let either_of = EitherOfWrapper::new(exact_count + this.forms.len() + 1);
+    let exact_arms = this.exact.iter().enumerate().map(|(i, (number, value))| {
+        let ts = parsed_value::to_token_stream(value, strings_count);
+        let ts = either_of.wrap(i, ts);
+        exact_arm(*number, ts)
+    });
     let match_arms = this.forms.iter().enumerate().map(|(i, (form, value))| {
         let form = PluralForm::from(*form);
         let ts = parsed_value::to_token_stream(value, strings_count);
-        let ts = either_of.wrap(i, ts);
+        let ts = either_of.wrap(exact_count + i, ts);
         quote!(#form => { #ts })
     });
 
     let locale_field = Key::new(LOCALE_FIELD_KEY).unwrap_at("LOCALE_FIELD_KEY");
-    let other = &*this.other;
 
     let mut captured_values = InterpolOrLit::Lit(LiteralType::String);
     let mut key_path = KeyPath::new(None);
 
-    for value in this.forms.values().chain(Some(other)) {
+    for value in this.values() {
         value
             .get_keys_inner(&mut key_path, &mut captured_values, false)
             .unwrap_at("plurals::to_token_stream_1");
@@ -145,14 +161,16 @@ pub fn to_token_stream(this: &Plurals, strings_count: usize) -> TokenStream {
     let count_key = &this.count_key;
 
     let other_ts = parsed_value::to_token_stream(&this.other, strings_count);
-    let other = either_of.wrap(this.forms.len(), other_ts);
+    let other = either_of.wrap(exact_count + this.forms.len(), other_ts);
 
     quote! {
         {
             #captured_values
             let _plural_rules = l_i18n_crate::__private::get_plural_rules(#locale_field, #rule_type);
             move || {
-                match _plural_rules.category_for(#count_key()) {
+                let __plural_operands: l_i18n_crate::reexports::icu::plurals::PluralOperands = core::convert::Into::into(#count_key());
+                match _plural_rules.category_for(__plural_operands) {
+                    #(#exact_arms,)*
                     #(#match_arms,)*
                     _ => #other,
                 }
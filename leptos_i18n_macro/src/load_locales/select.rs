@@ -0,0 +1,247 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use leptos_i18n_parser::{
+    parse_locales::{
+        error::{Error, Result},
+        locale::{
+            BuildersKeys, BuildersKeysInner, InterpolOrLit, LiteralType, LocaleValue, RangeOrPlural,
+        },
+        select::{Select, SELECT_OTHER},
+    },
+    utils::{Key, KeyPath, UnwrapAt},
+};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+use crate::{load_locales::parsed_value, utils::EitherOfWrapper};
+
+// Names of the items generated in the `i18n` module, select enums can't use them.
+// The module is glob imported, so the std prelude names are reserved too, `Option` or `Result`
+// would shadow the prelude in the user's code.
+const RESERVED_NAMES: &[&str] = &[
+    "Locale",
+    "I18nKeys",
+    "I18nTranslationUnitsId",
+    "I18nContextProvider",
+    "I18nSubContextProvider",
+    "I18nLocaleTrait",
+    // std prelude
+    "Option",
+    "Some",
+    "None",
+    "Result",
+    "Ok",
+    "Err",
+    "String",
+    "ToString",
+    "Vec",
+    "Box",
+    "ToOwned",
+    "Clone",
+    "Copy",
+    "Send",
+    "Sync",
+    "Sized",
+    "Unpin",
+    "Drop",
+    "Fn",
+    "FnMut",
+    "FnOnce",
+    "AsRef",
+    "AsMut",
+    "From",
+    "Into",
+    "TryFrom",
+    "TryInto",
+    "Default",
+    "Eq",
+    "PartialEq",
+    "Ord",
+    "PartialOrd",
+    "Iterator",
+    "IntoIterator",
+    "DoubleEndedIterator",
+    "ExactSizeIterator",
+    "Extend",
+    "FromIterator",
+];
+
+pub fn enum_ident(select_key: &Key) -> syn::Ident {
+    format_ident!("{}", Select::enum_name(select_key))
+}
+
+fn variant_ident(selector: &str) -> syn::Ident {
+    let name = Select::variant_name(selector).unwrap_at("select::variant_ident");
+    format_ident!("{}", name)
+}
+
+fn collect_selects_inner(keys: &BuildersKeysInner, selects: &mut BTreeMap<Key, BTreeSet<String>>) {
+    for value in keys.0.values() {
+        match value {
+            LocaleValue::Subkeys { keys, .. } => collect_selects_inner(keys, selects),
+            LocaleValue::Value {
+                value: InterpolOrLit::Interpol(keys),
+                ..
+            } => {
+                for (key, infos) in keys.iter_vars() {
+                    if infos.range_count == Some(RangeOrPlural::Select) {
+                        selects
+                            .entry(key)
+                            .or_default()
+                            .extend(infos.selectors.iter().cloned());
+                    }
+                }
+            }
+            LocaleValue::Value { .. } => {}
+        }
+    }
+}
+
+/// Generate the enums used as select arguments, one per select variable, with the selectors of all keys using it.
+pub fn create_select_enums(keys: &BuildersKeys) -> Result<TokenStream> {
+    let mut selects = BTreeMap::new();
+    match keys {
+        BuildersKeys::NameSpaces { keys, .. } => {
+            for keys in keys.values() {
+                collect_selects_inner(keys, &mut selects);
+            }
+        }
+        BuildersKeys::Locales { keys, .. } => collect_selects_inner(keys, &mut selects),
+    }
+
+    let mut enums = Vec::with_capacity(selects.len());
+    for (select_key, selectors) in selects {
+        let name = Select::enum_name(&select_key);
+        if RESERVED_NAMES.contains(&name.as_str()) {
+            return Err(Error::SelectNameCollision { name }.into());
+        }
+        let ident = enum_ident(&select_key);
+        let mut variant_names = BTreeSet::new();
+        for selector in &selectors {
+            let variant = Select::variant_name(selector).unwrap_at("create_select_enums_1");
+            // two keys can use different selectors mapping to the same variant
+            if !variant_names.insert(variant) {
+                return Err(Error::DuplicateSelector(selector.clone()).into());
+            }
+        }
+        let variants = selectors
+            .iter()
+            .map(|selector| variant_ident(selector))
+            .collect::<Vec<_>>();
+        let selectors = selectors.iter().collect::<Vec<_>>();
+        enums.push(quote! {
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+            pub enum #ident {
+                #(#variants,)*
+                Other,
+            }
+
+            impl #ident {
+                /// Return the selector of this variant as written in the translations.
+                pub const fn as_str(self) -> &'static str {
+                    match self {
+                        #(#ident::#variants => #selectors,)*
+                        #ident::Other => #SELECT_OTHER,
+                    }
+                }
+            }
+
+            impl core::fmt::Display for #ident {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    core::fmt::Display::fmt(self.as_str(), f)
+                }
+            }
+
+            impl From<&str> for #ident {
+                fn from(selector: &str) -> Self {
+                    match selector {
+                        #(#selectors => #ident::#variants,)*
+                        _ => #ident::Other,
+                    }
+                }
+            }
+
+            impl l_i18n_crate::__private::InterpolateSelect<#ident> for #ident {
+                fn select(&self) -> #ident {
+                    *self
+                }
+            }
+        });
+    }
+
+    Ok(quote! {
+        #[doc(hidden)]
+        pub mod selects {
+            #[allow(unused_imports)]
+            use super::l_i18n_crate;
+
+            #(#enums)*
+        }
+
+        pub use selects::*;
+    })
+}
+
+pub fn as_string_impl(this: &Select, strings_count: usize) -> TokenStream {
+    let enum_ident = enum_ident(&this.select_key);
+    let select_key = &this.select_key;
+    let match_arms = this.arms.iter().map(|(selector, value)| {
+        let variant = variant_ident(selector);
+        let ts = parsed_value::as_string_impl(value, strings_count);
+        quote!(selects::#enum_ident::#variant => { #ts })
+    });
+
+    let other = parsed_value::as_string_impl(&this.other, strings_count);
+
+    quote! {{
+        match core::convert::Into::<selects::#enum_ident>::into(core::clone::Clone::clone(#select_key)) {
+            #(#match_arms,)*
+            _ => #other,
+        }
+    }}
+}
+
+pub fn to_token_stream(this: &Select, strings_count: usize) -> TokenStream {
+    let either_of = EitherOfWrapper::new(this.arms.len() + 1);
+    let enum_ident = enum_ident(&this.select_key);
+    let match_arms = this.arms.iter().enumerate().map(|(i, (selector, value))| {
+        let variant = variant_ident(selector);
+        let ts = parsed_value::to_token_stream(value, strings_count);
+        let ts = either_of.wrap(i, ts);
+        quote!(selects::#enum_ident::#variant => { #ts })
+    });
+
+    let mut captured_values = InterpolOrLit::Lit(LiteralType::String);
+    let mut key_path = KeyPath::new(None);
+
+    for value in this.values() {
+        value
+            .get_keys_inner(&mut key_path, &mut captured_values, false)
+            .unwrap_at("select::to_token_stream_1");
+    }
+
+    let captured_values = captured_values.is_interpol().map(|keys| {
+        let keys = keys
+            .iter_keys()
+            .map(|key| quote!(let #key = core::clone::Clone::clone(&#key);));
+        quote!(#(#keys)*)
+    });
+
+    let select_key = &this.select_key;
+
+    let other_ts = parsed_value::to_token_stream(&this.other, strings_count);
+    let other = either_of.wrap(this.arms.len(), other_ts);
+
+    quote! {
+        {
+            #captured_values
+            let #select_key = core::clone::Clone::clone(&#select_key);
+            move || {
+                match l_i18n_crate::__private::InterpolateSelect::<selects::#enum_ident>::select(&#select_key) {
+                    #(#match_arms,)*
+                    _ => #other,
+                }
+            }
+        }
+    }
+}
@@ -5,7 +5,7 @@ use quote::ToTokens;
 use syn::{
     parse::{ParseBuffer, ParseStream},
     token::Comma,
-    Expr, Ident, Token,
+    Expr, Ident, LitInt, LitStr, Token,
 };
 
 pub struct ParsedInput {
@@ -18,11 +18,18 @@ fn emit_err<A, T: ToTokens, U: Display>(tokens: T, message: U) -> syn::Result<A>
     Err(syn::Error::new_spanned(tokens, message))
 }
 
-fn parse_arg(input: ParseStream) -> syn::Result<(Ident, Ident)> {
+// Values are identifiers, integers or strings, such as `maximum_fraction_digits: 2` or `skeleton: "yMMMd"`.
+fn parse_arg(input: ParseStream) -> syn::Result<(String, String)> {
     let arg_name = input.parse::<Ident>()?;
     input.parse::<Token![:]>()?;
-    let arg_value = input.parse::<Ident>()?;
-    Ok((arg_name, arg_value))
+    let arg_value = if input.peek(LitInt) {
+        input.parse::<LitInt>()?.base10_digits().to_string()
+    } else if input.peek(LitStr) {
+        input.parse::<LitStr>()?.value()
+    } else {
+        input.parse::<Ident>()?.to_string()
+    };
+    Ok((arg_name.to_string(), arg_value))
 }
 
 fn is_parenthesized(input: ParseStream) -> syn::Result<ParseBuffer> {
@@ -34,7 +41,7 @@ fn is_parenthesized(input: ParseStream) -> syn::Result<ParseBuffer> {
 fn convert_formatter_result(
     res: Result<
         Option<leptos_i18n_parser::utils::formatter::Formatter>,
-        leptos_i18n_parser::utils::formatter::Formatter,
+        leptos_i18n_parser::utils::formatter::FormatterError,
     >,
     span: proc_macro2::Span,
     err: syn::Error,
@@ -42,7 +49,7 @@ fn convert_formatter_result(
     match res {
         Ok(Some(formatter)) => Ok(formatter.into()),
         Ok(None) => Err(err),
-        Err(formatter) => Err(syn::Error::new(span, formatter.err_message())),
+        Err(err) => Err(syn::Error::new(span, err)),
     }
 }
 
@@ -56,7 +63,7 @@ fn parse_formatter(
 
     let span = formatter_name.span();
     let res = leptos_i18n_parser::utils::formatter::Formatter::from_name_and_args(
-        formatter_name,
+        formatter_name.to_string(),
         Some(&args),
     );
     convert_formatter_result(res, span, err)
@@ -17,11 +17,12 @@ pub enum InterpolatedValue {
         key: Ident,
         value: Expr,
     },
-    // form t!(i18n, key, <count> = <count attrs...>)
+    // form t!(i18n, key, <count> = <count attrs...>) or t!(i18n, key, <count/> = <count attrs...>)
     DirectComp {
         key: Ident,
         comp_name: Ident,
         attrs: TokenStream,
+        self_closing: bool,
     },
 }
 
@@ -62,7 +63,13 @@ impl syn::parse::Parse for InterpolatedValue {
             input.parse::<Token![<]>()?;
         }
         let key = input.parse::<Ident>()?;
+        // `<key/>` marks a self-closing component, it only matters for direct components.
+        let mut self_closing = false;
         if is_comp {
+            if input.peek(Token![/]) {
+                input.parse::<Token![/]>()?;
+                self_closing = true;
+            }
             input.parse::<Token![>]>()?;
         }
         let value = if input.peek(Token![=]) {
@@ -73,6 +80,7 @@ impl syn::parse::Parse for InterpolatedValue {
                     key,
                     comp_name,
                     attrs,
+                    self_closing,
                 }
             } else {
                 let value = input.parse()?;
@@ -129,9 +137,17 @@ impl InterpolatedValue {
                 key,
                 comp_name,
                 attrs,
+                self_closing,
             } => {
-                let ts = quote! {
-                    move |__children: leptos::children::ChildrenFn| { leptos::view! { <#comp_name #attrs>{move || __children()}</#comp_name> } }
+                // wrapped so it can also receive the attributes declared in the translation.
+                let ts = if *self_closing {
+                    quote! {
+                        leptos_i18n::__private::DirectComp(move || { leptos::view! { <#comp_name #attrs/> } })
+                    }
+                } else {
+                    quote! {
+                        leptos_i18n::__private::DirectComp(move |__children: leptos::children::ChildrenFn| { leptos::view! { <#comp_name #attrs>{move || __children()}</#comp_name> } })
+                    }
                 };
                 let key = key.clone();
                 *self = InterpolatedValue::Comp(key.clone());
@@ -51,6 +51,122 @@ impl ToTokens for GroupingStrategy {
 
 impl_from!(GroupingStrategy, Auto, Never, Always, Min2);
 
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum CompactDisplay {
+    Short,
+    Long,
+}
+
+impl ToTokens for CompactDisplay {
+    fn to_token_stream(&self) -> TokenStream {
+        match self {
+            CompactDisplay::Short => quote!(l_i18n_crate::__private::CompactDisplay::Short),
+            CompactDisplay::Long => quote!(l_i18n_crate::__private::CompactDisplay::Long),
+        }
+    }
+
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let ts = Self::to_token_stream(self);
+        tokens.extend(ts);
+    }
+}
+
+impl_from!(CompactDisplay, Short, Long);
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum RoundingMode {
+    Ceil,
+    Floor,
+    Expand,
+    Trunc,
+    HalfCeil,
+    HalfFloor,
+    HalfExpand,
+    HalfTrunc,
+    HalfEven,
+}
+
+impl ToTokens for RoundingMode {
+    fn to_token_stream(&self) -> TokenStream {
+        match self {
+            RoundingMode::Ceil => quote!(l_i18n_crate::__private::RoundingMode::Ceil),
+            RoundingMode::Floor => quote!(l_i18n_crate::__private::RoundingMode::Floor),
+            RoundingMode::Expand => quote!(l_i18n_crate::__private::RoundingMode::Expand),
+            RoundingMode::Trunc => quote!(l_i18n_crate::__private::RoundingMode::Trunc),
+            RoundingMode::HalfCeil => quote!(l_i18n_crate::__private::RoundingMode::HalfCeil),
+            RoundingMode::HalfFloor => quote!(l_i18n_crate::__private::RoundingMode::HalfFloor),
+            RoundingMode::HalfExpand => quote!(l_i18n_crate::__private::RoundingMode::HalfExpand),
+            RoundingMode::HalfTrunc => quote!(l_i18n_crate::__private::RoundingMode::HalfTrunc),
+            RoundingMode::HalfEven => quote!(l_i18n_crate::__private::RoundingMode::HalfEven),
+        }
+    }
+
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let ts = Self::to_token_stream(self);
+        tokens.extend(ts);
+    }
+}
+
+impl_from!(
+    RoundingMode,
+    Ceil,
+    Floor,
+    Expand,
+    Trunc,
+    HalfCeil,
+    HalfFloor,
+    HalfExpand,
+    HalfTrunc,
+    HalfEven
+);
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct NumberPrecision {
+    minimum_integer_digits: Option<u8>,
+    minimum_fraction_digits: Option<u8>,
+    maximum_fraction_digits: Option<u8>,
+    rounding_mode: RoundingMode,
+}
+
+impl ToTokens for NumberPrecision {
+    fn to_token_stream(&self) -> TokenStream {
+        fn digits_to_tokens(digits: Option<u8>) -> TokenStream {
+            match digits {
+                Some(digits) => quote!(Some(#digits)),
+                None => quote!(None),
+            }
+        }
+        let minimum_integer_digits = digits_to_tokens(self.minimum_integer_digits);
+        let minimum_fraction_digits = digits_to_tokens(self.minimum_fraction_digits);
+        let maximum_fraction_digits = digits_to_tokens(self.maximum_fraction_digits);
+        let rounding_mode = self.rounding_mode;
+        quote!(
+            l_i18n_crate::__private::NumberPrecision {
+                minimum_integer_digits: #minimum_integer_digits,
+                minimum_fraction_digits: #minimum_fraction_digits,
+                maximum_fraction_digits: #maximum_fraction_digits,
+                rounding_mode: #rounding_mode,
+            }
+        )
+    }
+
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let ts = Self::to_token_stream(self);
+        tokens.extend(ts);
+    }
+}
+
+impl From<leptos_i18n_parser::utils::formatter::NumberPrecision> for NumberPrecision {
+    fn from(value: leptos_i18n_parser::utils::formatter::NumberPrecision) -> Self {
+        NumberPrecision {
+            minimum_integer_digits: value.minimum_integer_digits,
+            minimum_fraction_digits: value.minimum_fraction_digits,
+            maximum_fraction_digits: value.maximum_fraction_digits,
+            rounding_mode: value.rounding_mode.into(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub enum CurrencyWidth {
     Short,
@@ -161,6 +277,185 @@ macro_rules! impl_length {
 impl_length!(DateLength, Date);
 impl_length!(TimeLength, Time);
 
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DateTimeKind {
+    Date,
+    Time,
+    DateTime,
+    ZonedDateTime,
+}
+
+impl_from!(DateTimeKind, Date, Time, DateTime, ZonedDateTime);
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct DateTimeComponents(pub leptos_i18n_parser::utils::formatter::DateTimeComponents);
+
+impl ToTokens for DateTimeComponents {
+    fn to_token_stream(&self) -> TokenStream {
+        use leptos_i18n_parser::utils::formatter::{
+            ComponentMonth, ComponentNumeric, ComponentText, ComponentTimeZoneName, HourCycle,
+        };
+
+        fn option_to_tokens<T: Copy>(
+            value: Option<T>,
+            f: impl FnOnce(T) -> TokenStream,
+        ) -> TokenStream {
+            match value {
+                Some(value) => {
+                    let value = f(value);
+                    quote!(core::option::Option::Some(#value))
+                }
+                None => quote!(core::option::Option::None),
+            }
+        }
+
+        fn text(text: ComponentText) -> TokenStream {
+            match text {
+                ComponentText::Long => {
+                    quote!(l_i18n_crate::reexports::icu::datetime::options::components::Text::Long)
+                }
+                ComponentText::Short => {
+                    quote!(l_i18n_crate::reexports::icu::datetime::options::components::Text::Short)
+                }
+                ComponentText::Narrow => {
+                    quote!(
+                        l_i18n_crate::reexports::icu::datetime::options::components::Text::Narrow
+                    )
+                }
+            }
+        }
+
+        fn numeric(numeric: ComponentNumeric) -> TokenStream {
+            match numeric {
+                ComponentNumeric::Numeric => {
+                    quote!(l_i18n_crate::reexports::icu::datetime::options::components::Numeric::Numeric)
+                }
+                ComponentNumeric::TwoDigit => {
+                    quote!(l_i18n_crate::reexports::icu::datetime::options::components::Numeric::TwoDigit)
+                }
+            }
+        }
+
+        fn year(year: ComponentNumeric) -> TokenStream {
+            match year {
+                ComponentNumeric::Numeric => {
+                    quote!(
+                        l_i18n_crate::reexports::icu::datetime::options::components::Year::Numeric
+                    )
+                }
+                ComponentNumeric::TwoDigit => {
+                    quote!(
+                        l_i18n_crate::reexports::icu::datetime::options::components::Year::TwoDigit
+                    )
+                }
+            }
+        }
+
+        fn day(day: ComponentNumeric) -> TokenStream {
+            match day {
+                ComponentNumeric::Numeric => {
+                    quote!(l_i18n_crate::reexports::icu::datetime::options::components::Day::NumericDayOfMonth)
+                }
+                ComponentNumeric::TwoDigit => {
+                    quote!(l_i18n_crate::reexports::icu::datetime::options::components::Day::TwoDigitDayOfMonth)
+                }
+            }
+        }
+
+        fn month(month: ComponentMonth) -> TokenStream {
+            match month {
+                ComponentMonth::Numeric => {
+                    quote!(
+                        l_i18n_crate::reexports::icu::datetime::options::components::Month::Numeric
+                    )
+                }
+                ComponentMonth::TwoDigit => {
+                    quote!(l_i18n_crate::reexports::icu::datetime::options::components::Month::TwoDigit)
+                }
+                ComponentMonth::Long => {
+                    quote!(l_i18n_crate::reexports::icu::datetime::options::components::Month::Long)
+                }
+                ComponentMonth::Short => {
+                    quote!(
+                        l_i18n_crate::reexports::icu::datetime::options::components::Month::Short
+                    )
+                }
+                ComponentMonth::Narrow => {
+                    quote!(
+                        l_i18n_crate::reexports::icu::datetime::options::components::Month::Narrow
+                    )
+                }
+            }
+        }
+
+        fn hour_cycle(hour_cycle: HourCycle) -> TokenStream {
+            match hour_cycle {
+                HourCycle::H11 => {
+                    quote!(l_i18n_crate::reexports::icu::datetime::options::preferences::HourCycle::H11)
+                }
+                HourCycle::H12 => {
+                    quote!(l_i18n_crate::reexports::icu::datetime::options::preferences::HourCycle::H12)
+                }
+                HourCycle::H23 => {
+                    quote!(l_i18n_crate::reexports::icu::datetime::options::preferences::HourCycle::H23)
+                }
+                HourCycle::H24 => {
+                    quote!(l_i18n_crate::reexports::icu::datetime::options::preferences::HourCycle::H24)
+                }
+            }
+        }
+
+        fn time_zone_name(time_zone_name: ComponentTimeZoneName) -> TokenStream {
+            match time_zone_name {
+                ComponentTimeZoneName::ShortSpecific => {
+                    quote!(l_i18n_crate::reexports::icu::datetime::options::components::TimeZoneName::ShortSpecific)
+                }
+                ComponentTimeZoneName::LongSpecific => {
+                    quote!(l_i18n_crate::reexports::icu::datetime::options::components::TimeZoneName::LongSpecific)
+                }
+                ComponentTimeZoneName::GmtOffset => {
+                    quote!(l_i18n_crate::reexports::icu::datetime::options::components::TimeZoneName::GmtOffset)
+                }
+                ComponentTimeZoneName::ShortGeneric => {
+                    quote!(l_i18n_crate::reexports::icu::datetime::options::components::TimeZoneName::ShortGeneric)
+                }
+                ComponentTimeZoneName::LongGeneric => {
+                    quote!(l_i18n_crate::reexports::icu::datetime::options::components::TimeZoneName::LongGeneric)
+                }
+            }
+        }
+
+        let components = &self.0;
+        let era = option_to_tokens(components.era, text);
+        let year = option_to_tokens(components.year, year);
+        let month = option_to_tokens(components.month, month);
+        let day = option_to_tokens(components.day, day);
+        let weekday = option_to_tokens(components.weekday, text);
+        let hour = option_to_tokens(components.hour, numeric);
+        let minute = option_to_tokens(components.minute, numeric);
+        let second = option_to_tokens(components.second, numeric);
+        let hour_cycle = option_to_tokens(components.hour_cycle, hour_cycle);
+        let time_zone_name = option_to_tokens(components.time_zone_name, time_zone_name);
+        quote!(l_i18n_crate::__private::DateTimeComponents {
+            era: #era,
+            year: #year,
+            month: #month,
+            day: #day,
+            weekday: #weekday,
+            hour: #hour,
+            minute: #minute,
+            second: #second,
+            hour_cycle: #hour_cycle,
+            time_zone_name: #time_zone_name,
+        })
+    }
+
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let ts = self.to_token_stream();
+        tokens.extend(ts);
+    }
+}
+
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub enum ListType {
     And,
@@ -208,27 +503,123 @@ impl ToTokens for ListStyle {
 
 impl_from!(ListStyle, Wide, Short, Narrow);
 
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ListSort {
+    Unsorted,
+    Sorted,
+}
+
+impl_from!(ListSort, Unsorted, Sorted);
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum RelativeTimeStyle {
+    Long,
+    Short,
+    Narrow,
+}
+
+impl ToTokens for RelativeTimeStyle {
+    fn to_token_stream(&self) -> TokenStream {
+        match self {
+            RelativeTimeStyle::Long => quote!(l_i18n_crate::__private::RelativeTimeStyle::Long),
+            RelativeTimeStyle::Short => quote!(l_i18n_crate::__private::RelativeTimeStyle::Short),
+            RelativeTimeStyle::Narrow => {
+                quote!(l_i18n_crate::__private::RelativeTimeStyle::Narrow)
+            }
+        }
+    }
+
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let ts = Self::to_token_stream(self);
+        tokens.extend(ts);
+    }
+}
+
+impl_from!(RelativeTimeStyle, Long, Short, Narrow);
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum RelativeTimeNumeric {
+    Always,
+    Auto,
+}
+
+impl ToTokens for RelativeTimeNumeric {
+    fn to_token_stream(&self) -> TokenStream {
+        match self {
+            RelativeTimeNumeric::Always => {
+                quote!(l_i18n_crate::reexports::icu::relativetime::options::Numeric::Always)
+            }
+            RelativeTimeNumeric::Auto => {
+                quote!(l_i18n_crate::reexports::icu::relativetime::options::Numeric::Auto)
+            }
+        }
+    }
+
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let ts = Self::to_token_stream(self);
+        tokens.extend(ts);
+    }
+}
+
+impl_from!(RelativeTimeNumeric, Always, Auto);
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DisplayNameStyle {
+    Long,
+    Short,
+}
+
+impl ToTokens for DisplayNameStyle {
+    fn to_token_stream(&self) -> TokenStream {
+        match self {
+            DisplayNameStyle::Long => quote!(l_i18n_crate::__private::DisplayNameStyle::Long),
+            DisplayNameStyle::Short => quote!(l_i18n_crate::__private::DisplayNameStyle::Short),
+        }
+    }
+
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let ts = Self::to_token_stream(self);
+        tokens.extend(ts);
+    }
+}
+
+impl_from!(DisplayNameStyle, Long, Short);
+
 #[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub enum Formatter {
     #[default]
     None,
-    Currency(CurrencyWidth, CurrencyCode),
-    Number(GroupingStrategy),
+    Currency(CurrencyWidth, CurrencyCode, NumberPrecision),
+    Number(GroupingStrategy, NumberPrecision),
+    CompactNumber(CompactDisplay),
+    Percent(GroupingStrategy),
     Date(DateLength),
     Time(TimeLength),
     DateTime(DateLength, TimeLength),
-    List(ListType, ListStyle),
+    DateTimeComponents(DateTimeKind, DateTimeComponents),
+    ZonedDateTime(DateLength, TimeLength),
+    List(ListType, ListStyle, ListSort),
+    RelativeTime(RelativeTimeStyle, RelativeTimeNumeric),
+    LanguageName(DisplayNameStyle),
+    RegionName(DisplayNameStyle),
 }
 
 impl From<leptos_i18n_parser::utils::formatter::Formatter> for Formatter {
     fn from(value: leptos_i18n_parser::utils::formatter::Formatter) -> Self {
         match value {
             leptos_i18n_parser::utils::formatter::Formatter::None => Self::None,
-            leptos_i18n_parser::utils::formatter::Formatter::Currency(width, code) => {
-                Self::Currency(width.into(), code.into())
+            leptos_i18n_parser::utils::formatter::Formatter::Currency(width, code, precision) => {
+                Self::Currency(width.into(), code.into(), precision.into())
             }
-            leptos_i18n_parser::utils::formatter::Formatter::Number(grouping_strategy) => {
-                Self::Number(grouping_strategy.into())
+            leptos_i18n_parser::utils::formatter::Formatter::Number(
+                grouping_strategy,
+                precision,
+            ) => Self::Number(grouping_strategy.into(), precision.into()),
+            leptos_i18n_parser::utils::formatter::Formatter::CompactNumber(compact_display) => {
+                Self::CompactNumber(compact_display.into())
+            }
+            leptos_i18n_parser::utils::formatter::Formatter::Percent(grouping_strategy) => {
+                Self::Percent(grouping_strategy.into())
             }
             leptos_i18n_parser::utils::formatter::Formatter::Date(date_length) => {
                 Self::Date(date_length.into())
@@ -239,8 +630,27 @@ impl From<leptos_i18n_parser::utils::formatter::Formatter> for Formatter {
             leptos_i18n_parser::utils::formatter::Formatter::DateTime(date_length, time_length) => {
                 Self::DateTime(date_length.into(), time_length.into())
             }
-            leptos_i18n_parser::utils::formatter::Formatter::List(list_type, list_style) => {
-                Self::List(list_type.into(), list_style.into())
+            leptos_i18n_parser::utils::formatter::Formatter::DateTimeComponents(
+                kind,
+                components,
+            ) => Self::DateTimeComponents(kind.into(), DateTimeComponents(components)),
+            leptos_i18n_parser::utils::formatter::Formatter::ZonedDateTime(
+                date_length,
+                time_length,
+            ) => Self::ZonedDateTime(date_length.into(), time_length.into()),
+            leptos_i18n_parser::utils::formatter::Formatter::List(
+                list_type,
+                list_style,
+                list_sort,
+            ) => Self::List(list_type.into(), list_style.into(), list_sort.into()),
+            leptos_i18n_parser::utils::formatter::Formatter::RelativeTime(style, numeric) => {
+                Self::RelativeTime(style.into(), numeric.into())
+            }
+            leptos_i18n_parser::utils::formatter::Formatter::LanguageName(style) => {
+                Self::LanguageName(style.into())
+            }
+            leptos_i18n_parser::utils::formatter::Formatter::RegionName(style) => {
+                Self::RegionName(style.into())
             }
         }
     }
@@ -252,11 +662,17 @@ impl Formatter {
             Formatter::None => {
                 quote!(#key)
             }
-            Formatter::Currency(width, code) => {
-                quote!(l_i18n_crate::__private::format_currency_to_view(#locale_field, #key, #width, #code))
+            Formatter::Currency(width, code, precision) => {
+                quote!(l_i18n_crate::__private::format_currency_to_view(#locale_field, #key, #width, #code, #precision))
+            }
+            Formatter::Number(grouping_strategy, precision) => {
+                quote!(l_i18n_crate::__private::format_number_to_view(#locale_field, #key, #grouping_strategy, #precision))
+            }
+            Formatter::CompactNumber(compact_display) => {
+                quote!(l_i18n_crate::__private::format_compact_number_to_view(#locale_field, #key, #compact_display))
             }
-            Formatter::Number(grouping_strategy) => {
-                quote!(l_i18n_crate::__private::format_number_to_view(#locale_field, #key, #grouping_strategy))
+            Formatter::Percent(grouping_strategy) => {
+                quote!(l_i18n_crate::__private::format_percent_to_view(#locale_field, #key, #grouping_strategy))
             }
             Formatter::Date(length) => {
                 quote!(l_i18n_crate::__private::format_date_to_view(#locale_field, #key, #length))
@@ -267,9 +683,38 @@ impl Formatter {
             Formatter::DateTime(date_length, time_length) => {
                 quote!(l_i18n_crate::__private::format_datetime_to_view(#locale_field, #key, #date_length, #time_length))
             }
-            Formatter::List(list_type, list_style) => {
+            Formatter::DateTimeComponents(kind, components) => match kind {
+                DateTimeKind::Date => {
+                    quote!(l_i18n_crate::__private::format_date_components_to_view(#locale_field, #key, #components))
+                }
+                DateTimeKind::Time => {
+                    quote!(l_i18n_crate::__private::format_time_components_to_view(#locale_field, #key, #components))
+                }
+                DateTimeKind::DateTime => {
+                    quote!(l_i18n_crate::__private::format_datetime_components_to_view(#locale_field, #key, #components))
+                }
+                DateTimeKind::ZonedDateTime => {
+                    quote!(l_i18n_crate::__private::format_zoned_datetime_to_view(#locale_field, #key, l_i18n_crate::__private::ZonedDateTimeOptions::Components(#components)))
+                }
+            },
+            Formatter::ZonedDateTime(date_length, time_length) => {
+                quote!(l_i18n_crate::__private::format_zoned_datetime_to_view(#locale_field, #key, l_i18n_crate::__private::ZonedDateTimeOptions::Length(#date_length, #time_length)))
+            }
+            Formatter::List(list_type, list_style, ListSort::Unsorted) => {
                 quote!(l_i18n_crate::__private::format_list_to_view(#locale_field, #key, #list_type, #list_style))
             }
+            Formatter::List(list_type, list_style, ListSort::Sorted) => {
+                quote!(l_i18n_crate::__private::format_sorted_list_to_view(#locale_field, #key, #list_type, #list_style))
+            }
+            Formatter::RelativeTime(style, numeric) => {
+                quote!(l_i18n_crate::__private::format_relative_time_to_view(#locale_field, #key, #style, #numeric))
+            }
+            Formatter::LanguageName(style) => {
+                quote!(l_i18n_crate::__private::format_language_name_to_view(#locale_field, #key, #style))
+            }
+            Formatter::RegionName(style) => {
+                quote!(l_i18n_crate::__private::format_region_name_to_view(#locale_field, #key, #style))
+            }
         }
     }
 
@@ -278,11 +723,17 @@ impl Formatter {
             Formatter::None => unreachable!(
                 "This function should not have been called on a variable with no formatter."
             ),
-            Formatter::Currency(width, code) => {
-                quote!(l_i18n_crate::__private::format_currency_to_display(#locale_field, #key, #width, #code))
+            Formatter::Currency(width, code, precision) => {
+                quote!(l_i18n_crate::__private::format_currency_to_display(#locale_field, #key, #width, #code, #precision))
+            }
+            Formatter::Number(grouping_strategy, precision) => {
+                quote!(l_i18n_crate::__private::format_number_to_display(#locale_field, #key, #grouping_strategy, #precision))
             }
-            Formatter::Number(grouping_strategy) => {
-                quote!(l_i18n_crate::__private::format_number_to_display(#locale_field, #key, #grouping_strategy))
+            Formatter::CompactNumber(compact_display) => {
+                quote!(l_i18n_crate::__private::format_compact_number_to_display(#locale_field, #key, #compact_display))
+            }
+            Formatter::Percent(grouping_strategy) => {
+                quote!(l_i18n_crate::__private::format_percent_to_display(#locale_field, #key, #grouping_strategy))
             }
             Formatter::Date(length) => {
                 quote!(l_i18n_crate::__private::format_date_to_display(#locale_field, #key, #length))
@@ -293,9 +744,38 @@ impl Formatter {
             Formatter::DateTime(date_length, time_length) => {
                 quote!(l_i18n_crate::__private::format_datetime_to_display(#locale_field, #key, #date_length, #time_length))
             }
-            Formatter::List(list_type, list_style) => {
+            Formatter::DateTimeComponents(kind, components) => match kind {
+                DateTimeKind::Date => {
+                    quote!(l_i18n_crate::__private::format_date_components_to_display(#locale_field, #key, #components))
+                }
+                DateTimeKind::Time => {
+                    quote!(l_i18n_crate::__private::format_time_components_to_display(#locale_field, #key, #components))
+                }
+                DateTimeKind::DateTime => {
+                    quote!(l_i18n_crate::__private::format_datetime_components_to_display(#locale_field, #key, #components))
+                }
+                DateTimeKind::ZonedDateTime => {
+                    quote!(l_i18n_crate::__private::format_zoned_datetime_to_display(#locale_field, #key, l_i18n_crate::__private::ZonedDateTimeOptions::Components(#components)))
+                }
+            },
+            Formatter::ZonedDateTime(date_length, time_length) => {
+                quote!(l_i18n_crate::__private::format_zoned_datetime_to_display(#locale_field, #key, l_i18n_crate::__private::ZonedDateTimeOptions::Length(#date_length, #time_length)))
+            }
+            Formatter::List(list_type, list_style, ListSort::Unsorted) => {
                 quote!(l_i18n_crate::__private::format_list_to_display(#locale_field, #key, #list_type, #list_style))
             }
+            Formatter::List(list_type, list_style, ListSort::Sorted) => {
+                quote!(l_i18n_crate::__private::format_sorted_list_to_display(#locale_field, #key, #list_type, #list_style))
+            }
+            Formatter::RelativeTime(style, numeric) => {
+                quote!(l_i18n_crate::__private::format_relative_time_to_display(#locale_field, #key, #style, #numeric))
+            }
+            Formatter::LanguageName(style) => {
+                quote!(l_i18n_crate::__private::format_language_name_to_display(#locale_field, #key, #style))
+            }
+            Formatter::RegionName(style) => {
+                quote!(l_i18n_crate::__private::format_region_name_to_display(#locale_field, #key, #style))
+            }
         }
     }
 
@@ -304,11 +784,17 @@ impl Formatter {
             Formatter::None => {
                 quote!(core::fmt::Display::fmt(#key, __formatter))
             }
-            Formatter::Currency(width, code) => {
-                quote!(l_i18n_crate::__private::format_currency_to_formatter(__formatter, *#locale_field, core::clone::Clone::clone(#key), #width, #code))
+            Formatter::Currency(width, code, precision) => {
+                quote!(l_i18n_crate::__private::format_currency_to_formatter(__formatter, *#locale_field, core::clone::Clone::clone(#key), #width, #code, #precision))
             }
-            Formatter::Number(grouping_strategy) => {
-                quote!(l_i18n_crate::__private::format_number_to_formatter(__formatter, *#locale_field, core::clone::Clone::clone(#key), #grouping_strategy))
+            Formatter::Number(grouping_strategy, precision) => {
+                quote!(l_i18n_crate::__private::format_number_to_formatter(__formatter, *#locale_field, core::clone::Clone::clone(#key), #grouping_strategy, #precision))
+            }
+            Formatter::CompactNumber(compact_display) => {
+                quote!(l_i18n_crate::__private::format_compact_number_to_formatter(__formatter, *#locale_field, core::clone::Clone::clone(#key), #compact_display))
+            }
+            Formatter::Percent(grouping_strategy) => {
+                quote!(l_i18n_crate::__private::format_percent_to_formatter(__formatter, *#locale_field, core::clone::Clone::clone(#key), #grouping_strategy))
             }
             Formatter::Date(length) => {
                 quote!(l_i18n_crate::__private::format_date_to_formatter(__formatter, *#locale_field, #key, #length))
@@ -319,33 +805,100 @@ impl Formatter {
             Formatter::DateTime(date_length, time_length) => {
                 quote!(l_i18n_crate::__private::format_datetime_to_formatter(__formatter, *#locale_field, #key, #date_length, #time_length))
             }
-            Formatter::List(list_type, list_style) => {
+            Formatter::DateTimeComponents(kind, components) => match kind {
+                DateTimeKind::Date => {
+                    quote!(l_i18n_crate::__private::format_date_components_to_formatter(__formatter, *#locale_field, #key, #components))
+                }
+                DateTimeKind::Time => {
+                    quote!(l_i18n_crate::__private::format_time_components_to_formatter(__formatter, *#locale_field, #key, #components))
+                }
+                DateTimeKind::DateTime => {
+                    quote!(l_i18n_crate::__private::format_datetime_components_to_formatter(__formatter, *#locale_field, #key, #components))
+                }
+                DateTimeKind::ZonedDateTime => {
+                    quote!(l_i18n_crate::__private::format_zoned_datetime_to_formatter(__formatter, *#locale_field, #key, l_i18n_crate::__private::ZonedDateTimeOptions::Components(#components)))
+                }
+            },
+            Formatter::ZonedDateTime(date_length, time_length) => {
+                quote!(l_i18n_crate::__private::format_zoned_datetime_to_formatter(__formatter, *#locale_field, #key, l_i18n_crate::__private::ZonedDateTimeOptions::Length(#date_length, #time_length)))
+            }
+            Formatter::List(list_type, list_style, ListSort::Unsorted) => {
                 quote!(l_i18n_crate::__private::format_list_to_formatter(__formatter, *#locale_field, core::clone::Clone::clone(#key), #list_type, #list_style))
             }
+            Formatter::List(list_type, list_style, ListSort::Sorted) => {
+                quote!(l_i18n_crate::__private::format_sorted_list_to_formatter(__formatter, *#locale_field, core::clone::Clone::clone(#key), #list_type, #list_style))
+            }
+            Formatter::RelativeTime(style, numeric) => {
+                quote!(l_i18n_crate::__private::format_relative_time_to_formatter(__formatter, *#locale_field, core::clone::Clone::clone(#key), #style, #numeric))
+            }
+            Formatter::LanguageName(style) => {
+                quote!(l_i18n_crate::__private::format_language_name_to_formatter(__formatter, *#locale_field, core::clone::Clone::clone(#key), #style))
+            }
+            Formatter::RegionName(style) => {
+                quote!(l_i18n_crate::__private::format_region_name_to_formatter(__formatter, *#locale_field, core::clone::Clone::clone(#key), #style))
+            }
         }
     }
 
     pub fn to_bound(self) -> TokenStream {
         match self {
             Formatter::None => quote!(l_i18n_crate::__private::InterpolateVar),
-            Formatter::Currency(_, _) => quote!(l_i18n_crate::__private::NumberFormatterInputFn),
-            Formatter::Number(_) => quote!(l_i18n_crate::__private::NumberFormatterInputFn),
+            Formatter::Currency(_, _, _) => {
+                quote!(l_i18n_crate::__private::CurrencyFormatterInputFn)
+            }
+            Formatter::Number(_, _) | Formatter::CompactNumber(_) | Formatter::Percent(_) => {
+                quote!(l_i18n_crate::__private::NumberFormatterInputFn)
+            }
             Formatter::Date(_) => quote!(l_i18n_crate::__private::DateFormatterInputFn),
             Formatter::Time(_) => quote!(l_i18n_crate::__private::TimeFormatterInputFn),
             Formatter::DateTime(_, _) => quote!(l_i18n_crate::__private::DateTimeFormatterInputFn),
-            Formatter::List(_, _) => quote!(l_i18n_crate::__private::ListFormatterInputFn),
+            Formatter::DateTimeComponents(kind, _) => match kind {
+                DateTimeKind::Date => quote!(l_i18n_crate::__private::DateFormatterInputFn),
+                DateTimeKind::Time => quote!(l_i18n_crate::__private::TimeFormatterInputFn),
+                DateTimeKind::DateTime => {
+                    quote!(l_i18n_crate::__private::DateTimeFormatterInputFn)
+                }
+                DateTimeKind::ZonedDateTime => {
+                    quote!(l_i18n_crate::__private::ZonedDateTimeFormatterInputFn)
+                }
+            },
+            Formatter::ZonedDateTime(_, _) => {
+                quote!(l_i18n_crate::__private::ZonedDateTimeFormatterInputFn)
+            }
+            Formatter::List(..) => quote!(l_i18n_crate::__private::ListFormatterInputFn),
+            Formatter::RelativeTime(_, _) => {
+                quote!(l_i18n_crate::__private::RelativeTimeFormatterInputFn)
+            }
+            Formatter::LanguageName(_) => {
+                quote!(l_i18n_crate::__private::LanguageNameFormatterInputFn)
+            }
+            Formatter::RegionName(_) => quote!(l_i18n_crate::__private::RegionNameFormatterInputFn),
         }
     }
 
     pub fn to_string_bound(self) -> TokenStream {
         match self {
             Formatter::None => quote!(::std::fmt::Display),
-            Formatter::Currency(_, _) => quote!(l_i18n_crate::__private::IntoFixedDecimal),
-            Formatter::Number(_) => quote!(l_i18n_crate::__private::IntoFixedDecimal),
+            Formatter::Currency(_, _, _) => quote!(l_i18n_crate::__private::IntoCurrency),
+            Formatter::Number(_, _) | Formatter::CompactNumber(_) | Formatter::Percent(_) => {
+                quote!(l_i18n_crate::__private::IntoFixedDecimal)
+            }
             Formatter::Date(_) => quote!(l_i18n_crate::__private::AsIcuDate),
             Formatter::Time(_) => quote!(l_i18n_crate::__private::AsIcuTime),
             Formatter::DateTime(_, _) => quote!(l_i18n_crate::__private::AsIcuDateTime),
-            Formatter::List(_, _) => quote!(l_i18n_crate::__private::WriteableList),
+            Formatter::DateTimeComponents(kind, _) => match kind {
+                DateTimeKind::Date => quote!(l_i18n_crate::__private::AsIcuDate),
+                DateTimeKind::Time => quote!(l_i18n_crate::__private::AsIcuTime),
+                DateTimeKind::DateTime => quote!(l_i18n_crate::__private::AsIcuDateTime),
+                DateTimeKind::ZonedDateTime => {
+                    quote!(l_i18n_crate::__private::AsIcuZonedDateTime)
+                }
+            },
+            Formatter::ZonedDateTime(_, _) => quote!(l_i18n_crate::__private::AsIcuZonedDateTime),
+            Formatter::List(..) => quote!(l_i18n_crate::__private::WriteableList),
+            Formatter::RelativeTime(_, _) => quote!(l_i18n_crate::__private::IntoRelativeTime),
+            Formatter::LanguageName(_) => quote!(l_i18n_crate::__private::IntoLanguageCode),
+            Formatter::RegionName(_) => quote!(l_i18n_crate::__private::IntoRegionCode),
         }
     }
 }
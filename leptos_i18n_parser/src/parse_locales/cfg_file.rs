@@ -1,7 +1,8 @@
 use std::{
     borrow::Cow,
     collections::{BTreeMap, BTreeSet},
-    path::PathBuf,
+    marker::PhantomData,
+    path::{Path, PathBuf},
 };
 
 use super::error::{Error, Result};
@@ -15,31 +16,142 @@ pub struct ConfigFile {
     pub locales_dir: Cow<'static, str>,
     pub translations_uri: Option<String>,
     pub extensions: BTreeMap<Key, Key>,
+    pub message_format: MessageFormat,
+    pub message_format_files: BTreeMap<String, MessageFormat>,
+    pub key_separator: Option<String>,
+    pub bidi_isolation: bool,
 }
 
-impl ConfigFile {
-    pub fn new(manifest_dir_path: &mut PathBuf) -> Result<ConfigFile> {
-        manifest_dir_path.push("Cargo.toml");
+/// Syntax used to parse the translations strings.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum MessageFormat {
+    /// `{{ var }}`, `<comp>` and `$t(..)`.
+    #[default]
+    Default,
+    /// ICU MessageFormat.
+    Icu,
+}
+
+/// Name of the standalone configuration file, looked for next to the `Cargo.toml`.
+pub const CONFIG_FILE_NAME: &str = "i18n.toml";
 
-        let cfg_file_str =
-            std::fs::read_to_string(&manifest_dir_path).map_err(Error::ManifestNotFound)?;
+const METADATA_KEY: &str = "leptos-i18n";
+
+// A crate configuration with `workspace = true` extends the workspace configuration.
+const WORKSPACE_KEY: &str = "workspace";
+
+const PACKAGE_SECTION: &[&str] = &["package", "metadata", METADATA_KEY];
+const WORKSPACE_SECTION: &[&str] = &[WORKSPACE_KEY, "metadata", METADATA_KEY];
+
+fn read_file(path: &Path) -> Result<Option<String>> {
+    match std::fs::read_to_string(path) {
+        Ok(content) => Ok(Some(content)),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(Error::ManifestNotFound(err).into()),
+    }
+}
 
+// Deserialize the table at `section` in the document,
+// deserializing from the document itself keeps the line numbers in the errors.
+fn deserialize_section<T: serde::de::DeserializeOwned>(
+    path: &Path,
+    content: &str,
+    section: &[&str],
+) -> Result<Option<T>> {
+    let seed = SectionSeed {
+        section,
+        marker: PhantomData,
+    };
+    serde::de::DeserializeSeed::deserialize(seed, toml::Deserializer::new(content))
+        .map_err(|err| Error::ConfigFileDeser {
+            path: path.to_owned(),
+            err,
+        })
+        .map_err(Box::new)
+}
+
+// Walk up the parent directories for the manifest declaring the workspace,
+// manifests that can't be read or parsed are not the workspace manifest and are skipped.
+fn find_workspace_manifest(manifest_dir_path: &Path) -> Option<(PathBuf, String)> {
+    manifest_dir_path.ancestors().find_map(|dir| {
+        let path = dir.join("Cargo.toml");
+        let content = std::fs::read_to_string(&path).ok()?;
+        let manifest = toml::from_str::<toml::Table>(&content).ok()?;
+        manifest
+            .contains_key(WORKSPACE_KEY)
+            .then_some((path, content))
+    })
+}
+
+impl ConfigFile {
+    /// Locate and parse the configuration, in order:
+    ///
+    /// - an `i18n.toml` file next to the `Cargo.toml`
+    /// - the `[package.metadata.leptos-i18n]` section of the `Cargo.toml`
+    /// - the `[workspace.metadata.leptos-i18n]` section of the workspace `Cargo.toml`
+    ///
+    /// A crate configuration containing `workspace = true` extends the workspace one, overriding its values.
+    /// Files other than the crate `Cargo.toml` are pushed to `tracked_files`.
+    pub fn new(
+        manifest_dir_path: &mut PathBuf,
+        tracked_files: &mut Vec<String>,
+    ) -> Result<ConfigFile> {
+        manifest_dir_path.push("Cargo.toml");
+        let manifest_path = manifest_dir_path.clone();
         manifest_dir_path.pop();
 
-        let Some((before, i18n_cfg)) = cfg_file_str.split_once("[package.metadata.leptos-i18n]")
-        else {
-            return Err(Error::ConfigNotPresent.into());
+        let manifest = read_file(&manifest_path)?
+            .ok_or_else(|| Error::ManifestNotFound(std::io::ErrorKind::NotFound.into()))?;
+
+        let cfg_file_path = manifest_dir_path.join(CONFIG_FILE_NAME);
+        let (crate_cfg_path, crate_cfg_content, crate_section) = match read_file(&cfg_file_path)? {
+            Some(content) => {
+                track_config_file(tracked_files, &cfg_file_path);
+                (cfg_file_path, content, &[][..])
+            }
+            None => (manifest_path.clone(), manifest, PACKAGE_SECTION),
         };
 
-        // this is to have the correct line number in the reported error.
-        let cfg_file_whitespaced = before
-            .chars()
-            .filter(|c| *c == '\n')
-            .chain(i18n_cfg.chars())
-            .collect::<String>();
+        let crate_cfg: Option<PartialConfig> =
+            deserialize_section(&crate_cfg_path, &crate_cfg_content, crate_section)?;
 
-        let mut cfg: ConfigFile =
-            toml::de::from_str(&cfg_file_whitespaced).map_err(Error::ConfigFileDeser)?;
+        let mut cfg = match crate_cfg {
+            Some(crate_cfg) if !crate_cfg.extends_workspace => {
+                deserialize_section(&crate_cfg_path, &crate_cfg_content, crate_section)?
+                    .ok_or(Error::ConfigNotPresent)?
+            }
+            crate_cfg => {
+                let (workspace_manifest_path, workspace_manifest) =
+                    find_workspace_manifest(manifest_dir_path).ok_or(Error::ConfigNotPresent)?;
+                if workspace_manifest_path != manifest_path {
+                    track_config_file(tracked_files, &workspace_manifest_path);
+                }
+                match crate_cfg {
+                    None => deserialize_section(
+                        &workspace_manifest_path,
+                        &workspace_manifest,
+                        WORKSPACE_SECTION,
+                    )?
+                    .ok_or(Error::ConfigNotPresent)?,
+                    Some(crate_cfg) => {
+                        let workspace_cfg: PartialConfig = deserialize_section(
+                            &workspace_manifest_path,
+                            &workspace_manifest,
+                            WORKSPACE_SECTION,
+                        )?
+                        .ok_or(Error::ConfigNotPresent)?;
+                        // the values come from both files, so the errors can only point to the crate configuration.
+                        workspace_cfg
+                            .extend(crate_cfg)
+                            .into_config()
+                            .map_err(|err| Error::ConfigFileDeser {
+                                path: crate_cfg_path,
+                                err,
+                            })?
+                    }
+                }
+            }
+        };
 
         if let Some(i) = cfg.locales.iter().position(|l| l == &cfg.default) {
             // put default as first locale
@@ -63,6 +175,19 @@ impl ConfigFile {
         }
     }
 
+    /// Return the syntax of the file of the given locale and namespace,
+    /// files are referenced by their path relative to the locales directory without extension ("en" or "en/namespace").
+    pub fn message_format_of(&self, locale: &Key, namespace: Option<&Key>) -> MessageFormat {
+        let file_format = namespace.and_then(|namespace| {
+            let file = format!("{}/{}", locale.name, namespace.name);
+            self.message_format_files.get(&file)
+        });
+        file_format
+            .or_else(|| self.message_format_files.get(&*locale.name))
+            .copied()
+            .unwrap_or(self.message_format)
+    }
+
     fn contain_duplicates(locales: &[Key]) -> Option<BTreeSet<Key>> {
         // monkey time
 
@@ -82,6 +207,12 @@ impl ConfigFile {
     }
 }
 
+fn track_config_file(tracked_files: &mut Vec<String>, path: &Path) {
+    if let Some(path) = path.to_str() {
+        tracked_files.push(path.to_owned());
+    }
+}
+
 // -----------------------------------------
 // Deserialization
 // -----------------------------------------
@@ -97,6 +228,84 @@ impl<'de> serde::Deserialize<'de> for ConfigFile {
     }
 }
 
+// Values of a configuration before the required fields are checked,
+// so the crate configuration can override the workspace one.
+#[derive(Debug, Default)]
+struct PartialConfig {
+    default: Option<Key>,
+    locales: Option<Vec<Key>>,
+    name_spaces: Option<Vec<String>>,
+    locales_dir: Option<String>,
+    translations_uri: Option<String>,
+    extensions: Option<BTreeMap<Key, Key>>,
+    message_format: Option<MessageFormat>,
+    message_format_files: Option<BTreeMap<String, MessageFormat>>,
+    key_separator: Option<String>,
+    bidi_isolation: Option<bool>,
+    extends_workspace: bool,
+}
+
+struct PartialConfigVisitor;
+
+impl<'de> serde::Deserialize<'de> for PartialConfig {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_struct("ConfigFile", Field::FIELDS, PartialConfigVisitor)
+    }
+}
+
+// Deserialize the table at the given path of keys, `None` if a key is missing.
+struct SectionSeed<'a, T> {
+    section: &'a [&'a str],
+    marker: PhantomData<T>,
+}
+
+impl<'de, T: serde::Deserialize<'de>> serde::de::DeserializeSeed<'de> for SectionSeed<'_, T> {
+    type Value = Option<T>;
+
+    fn deserialize<D>(self, deserializer: D) -> std::result::Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        if self.section.is_empty() {
+            T::deserialize(deserializer).map(Some)
+        } else {
+            deserializer.deserialize_map(self)
+        }
+    }
+}
+
+impl<'de, T: serde::Deserialize<'de>> serde::de::Visitor<'de> for SectionSeed<'_, T> {
+    type Value = Option<T>;
+
+    fn visit_map<A>(self, mut map: A) -> std::result::Result<Self::Value, A::Error>
+    where
+        A: serde::de::MapAccess<'de>,
+    {
+        let Some((first, rest)) = self.section.split_first() else {
+            return Ok(None);
+        };
+        let mut value = None;
+        while let Some(key) = map.next_key::<String>()? {
+            if key == *first {
+                value = map.next_value_seed(SectionSeed {
+                    section: rest,
+                    marker: PhantomData,
+                })?;
+            } else {
+                map.next_value::<serde::de::IgnoredAny>()?;
+            }
+        }
+        Ok(value)
+    }
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(formatter, "a table")
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub enum Field {
     Default,
@@ -105,6 +314,11 @@ pub enum Field {
     LocalesDir,
     TranslationsUri,
     Extensions,
+    MessageFormat,
+    MessageFormatFiles,
+    KeySeparator,
+    BidiIsolation,
+    Workspace,
     Unknown,
 }
 
@@ -115,6 +329,11 @@ impl Field {
     pub const LOCALES_DIR: &'static str = "locales-dir";
     pub const TRANSLATIONS_URI: &'static str = "translations-path";
     pub const EXTENSIONS: &'static str = "inherits";
+    pub const MESSAGE_FORMAT: &'static str = "message-format";
+    pub const MESSAGE_FORMAT_FILES: &'static str = "message-format-files";
+    pub const KEY_SEPARATOR: &'static str = "key-separator";
+    pub const BIDI_ISOLATION: &'static str = "bidi-isolation";
+    pub const WORKSPACE: &'static str = WORKSPACE_KEY;
     pub const FIELDS: &'static [&'static str] = &[
         Self::DEFAULT,
         Self::LOCALES,
@@ -122,6 +341,11 @@ impl Field {
         Self::LOCALES_DIR,
         Self::TRANSLATIONS_URI,
         Self::EXTENSIONS,
+        Self::MESSAGE_FORMAT,
+        Self::MESSAGE_FORMAT_FILES,
+        Self::KEY_SEPARATOR,
+        Self::BIDI_ISOLATION,
+        Self::WORKSPACE,
     ];
 }
 
@@ -158,15 +382,18 @@ impl serde::de::Visitor<'_> for FieldVisitor {
             Field::LOCALES_DIR => Ok(Field::LocalesDir),
             Field::TRANSLATIONS_URI => Ok(Field::TranslationsUri),
             Field::EXTENSIONS => Ok(Field::Extensions),
+            Field::MESSAGE_FORMAT => Ok(Field::MessageFormat),
+            Field::MESSAGE_FORMAT_FILES => Ok(Field::MessageFormatFiles),
+            Field::KEY_SEPARATOR => Ok(Field::KeySeparator),
+            Field::BIDI_ISOLATION => Ok(Field::BidiIsolation),
+            Field::WORKSPACE => Ok(Field::Workspace),
             _ => Ok(Field::Unknown), // skip unknown fields
         }
     }
 }
 
-impl<'de> serde::de::Visitor<'de> for CfgFileVisitor {
-    type Value = ConfigFile;
-
-    fn visit_map<A>(self, mut map: A) -> std::result::Result<Self::Value, A::Error>
+impl PartialConfig {
+    fn from_map<'de, A>(mut map: A) -> std::result::Result<Self, A::Error>
     where
         A: serde::de::MapAccess<'de>,
     {
@@ -187,10 +414,15 @@ impl<'de> serde::de::Visitor<'de> for CfgFileVisitor {
         }
         let mut default = None;
         let mut locales: Option<Vec<Key>> = None;
-        let mut name_spaces = None;
+        let mut name_spaces: Option<Vec<String>> = None;
         let mut locales_dir = None;
         let mut translations_uri = None;
         let mut extensions: Option<BTreeMap<Key, Key>> = None;
+        let mut message_format = None;
+        let mut message_format_files = None;
+        let mut key_separator: Option<String> = None;
+        let mut bidi_isolation = None;
+        let mut workspace = None;
         while let Some(field) = map.next_key::<Field>()? {
             match field {
                 Field::Default => deser_field(&mut default, &mut map, Field::DEFAULT)?,
@@ -201,9 +433,71 @@ impl<'de> serde::de::Visitor<'de> for CfgFileVisitor {
                     deser_field(&mut translations_uri, &mut map, Field::TRANSLATIONS_URI)?
                 }
                 Field::Extensions => deser_field(&mut extensions, &mut map, Field::EXTENSIONS)?,
+                Field::MessageFormat => {
+                    deser_field(&mut message_format, &mut map, Field::MESSAGE_FORMAT)?
+                }
+                Field::MessageFormatFiles => deser_field(
+                    &mut message_format_files,
+                    &mut map,
+                    Field::MESSAGE_FORMAT_FILES,
+                )?,
+                Field::KeySeparator => {
+                    deser_field(&mut key_separator, &mut map, Field::KEY_SEPARATOR)?
+                }
+                Field::BidiIsolation => {
+                    deser_field(&mut bidi_isolation, &mut map, Field::BIDI_ISOLATION)?
+                }
+                Field::Workspace => deser_field(&mut workspace, &mut map, Field::WORKSPACE)?,
                 Field::Unknown => continue,
             }
         }
+        Ok(PartialConfig {
+            default,
+            locales,
+            name_spaces,
+            locales_dir,
+            translations_uri,
+            extensions,
+            message_format,
+            message_format_files,
+            key_separator,
+            bidi_isolation,
+            extends_workspace: workspace.unwrap_or_default(),
+        })
+    }
+
+    // Override the values with the ones set in `other`.
+    fn extend(self, other: Self) -> Self {
+        PartialConfig {
+            default: other.default.or(self.default),
+            locales: other.locales.or(self.locales),
+            name_spaces: other.name_spaces.or(self.name_spaces),
+            locales_dir: other.locales_dir.or(self.locales_dir),
+            translations_uri: other.translations_uri.or(self.translations_uri),
+            extensions: other.extensions.or(self.extensions),
+            message_format: other.message_format.or(self.message_format),
+            message_format_files: other.message_format_files.or(self.message_format_files),
+            key_separator: other.key_separator.or(self.key_separator),
+            bidi_isolation: other.bidi_isolation.or(self.bidi_isolation),
+            extends_workspace: other.extends_workspace,
+        }
+    }
+
+    fn into_config<E: serde::de::Error>(self) -> std::result::Result<ConfigFile, E> {
+        let PartialConfig {
+            default,
+            locales,
+            name_spaces,
+            locales_dir,
+            translations_uri,
+            extensions,
+            message_format,
+            message_format_files,
+            key_separator,
+            bidi_isolation,
+            extends_workspace: _,
+        } = self;
+
         let Some(default) = default else {
             return Err(serde::de::Error::missing_field("default"));
         };
@@ -216,6 +510,36 @@ impl<'de> serde::de::Visitor<'de> for CfgFileVisitor {
             .map(Cow::Owned)
             .unwrap_or(Cow::Borrowed("locales"));
 
+        let name_spaces = name_spaces
+            .map(|name_spaces| {
+                name_spaces
+                    .iter()
+                    .map(|name| {
+                        Key::new_namespace(name).ok_or_else(|| {
+                            serde::de::Error::custom(format!("invalid namespace {:?}", name))
+                        })
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .transpose()?;
+
+        // a namespace can't also be the parent of nested namespaces ("admin" and "admin/users")
+        for namespace in name_spaces.iter().flatten() {
+            for (i, _) in namespace.name.match_indices('/') {
+                let parent = &namespace.name[..i];
+                if name_spaces.iter().flatten().any(|ns| &*ns.name == parent) {
+                    return Err(serde::de::Error::custom(format!(
+                        "namespace {:?} is also the parent of namespace {:?}",
+                        parent, namespace.name
+                    )));
+                }
+            }
+        }
+
+        if key_separator.as_deref() == Some("") {
+            return Err(serde::de::Error::custom("key separator can't be empty"));
+        }
+
         let extensions = extensions.unwrap_or_default();
 
         for (k, v) in &extensions {
@@ -239,8 +563,23 @@ impl<'de> serde::de::Visitor<'de> for CfgFileVisitor {
             locales_dir,
             translations_uri,
             extensions,
+            message_format: message_format.unwrap_or_default(),
+            message_format_files: message_format_files.unwrap_or_default(),
+            key_separator,
+            bidi_isolation: bidi_isolation.unwrap_or_default(),
         })
     }
+}
+
+impl<'de> serde::de::Visitor<'de> for CfgFileVisitor {
+    type Value = ConfigFile;
+
+    fn visit_map<A>(self, map: A) -> std::result::Result<Self::Value, A::Error>
+    where
+        A: serde::de::MapAccess<'de>,
+    {
+        PartialConfig::from_map(map)?.into_config()
+    }
 
     fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
         write!(
@@ -249,3 +588,199 @@ impl<'de> serde::de::Visitor<'de> for CfgFileVisitor {
         )
     }
 }
+
+impl<'de> serde::de::Visitor<'de> for PartialConfigVisitor {
+    type Value = PartialConfig;
+
+    fn visit_map<A>(self, map: A) -> std::result::Result<Self::Value, A::Error>
+    where
+        A: serde::de::MapAccess<'de>,
+    {
+        PartialConfig::from_map(map)
+    }
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(formatter, "a table of configuration values")
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for MessageFormat {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        match s.as_str() {
+            "default" => Ok(MessageFormat::Default),
+            "icu" => Ok(MessageFormat::Icu),
+            _ => Err(serde::de::Error::unknown_variant(&s, &["default", "icu"])),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new(name: &str) -> Self {
+            let path = std::env::temp_dir().join(format!(
+                "leptos_i18n_cfg_file_{}_{}",
+                name,
+                std::process::id()
+            ));
+            let _ = std::fs::remove_dir_all(&path);
+            std::fs::create_dir_all(path.join("member")).unwrap();
+            TempDir(path)
+        }
+
+        fn write(&self, file: &str, content: &str) {
+            std::fs::write(self.0.join(file), content).unwrap();
+        }
+
+        fn parse_member(&self) -> (Result<ConfigFile>, Vec<String>) {
+            let mut tracked_files = vec![];
+            let cfg = ConfigFile::new(&mut self.0.join("member"), &mut tracked_files);
+            (cfg, tracked_files)
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.0);
+        }
+    }
+
+    fn keys(keys: &[&str]) -> Vec<Key> {
+        keys.iter().map(|key| Key::new(key).unwrap()).collect()
+    }
+
+    const WORKSPACE_MANIFEST: &str = r#"
+[workspace]
+members = ["member"]
+
+[workspace.metadata.leptos-i18n]
+default = "en"
+locales = ["en", "fr"]
+locales-dir = "translations"
+"#;
+
+    #[test]
+    fn workspace_config() {
+        let dir = TempDir::new("workspace");
+        dir.write("Cargo.toml", WORKSPACE_MANIFEST);
+        dir.write("member/Cargo.toml", "[package]\nname = \"member\"\n");
+
+        let (cfg, tracked_files) = dir.parse_member();
+        let cfg = cfg.unwrap();
+        assert_eq!(cfg.locales, keys(&["en", "fr"]));
+        assert_eq!(cfg.locales_dir, "translations");
+        assert_eq!(tracked_files.len(), 1);
+        assert!(!cfg.bidi_isolation);
+    }
+
+    #[test]
+    fn extend_workspace_config() {
+        let dir = TempDir::new("extend");
+        dir.write("Cargo.toml", WORKSPACE_MANIFEST);
+        dir.write(
+            "member/Cargo.toml",
+            r#"
+[package]
+name = "member"
+
+[package.metadata.leptos-i18n]
+workspace = true
+locales = ["en", "fr", "de"]
+"#,
+        );
+
+        let (cfg, _) = dir.parse_member();
+        let cfg = cfg.unwrap();
+        assert_eq!(cfg.default, Key::new("en").unwrap());
+        assert_eq!(cfg.locales, keys(&["en", "fr", "de"]));
+        assert_eq!(cfg.locales_dir, "translations");
+    }
+
+    #[test]
+    fn standalone_config_file() {
+        let dir = TempDir::new("standalone");
+        dir.write("Cargo.toml", WORKSPACE_MANIFEST);
+        dir.write(
+            "member/Cargo.toml",
+            r#"
+[package]
+name = "member"
+
+[package.metadata.leptos-i18n]
+default = "de"
+locales = ["de"]
+"#,
+        );
+        dir.write(
+            "member/i18n.toml",
+            "default = \"fr\"\nlocales = [\"en\", \"fr\"]\n",
+        );
+
+        let (cfg, tracked_files) = dir.parse_member();
+        let cfg = cfg.unwrap();
+        assert_eq!(cfg.locales, keys(&["fr", "en"]));
+        assert_eq!(cfg.locales_dir, "locales");
+        assert!(tracked_files[0].ends_with(CONFIG_FILE_NAME));
+    }
+
+    #[test]
+    fn bidi_isolation() {
+        let dir = TempDir::new("bidi_isolation");
+        dir.write("Cargo.toml", WORKSPACE_MANIFEST);
+        dir.write("member/Cargo.toml", "[package]\nname = \"member\"\n");
+        dir.write(
+            "member/i18n.toml",
+            "default = \"en\"\nlocales = [\"en\", \"ar\"]\nbidi-isolation = true\n",
+        );
+
+        let (cfg, _) = dir.parse_member();
+        assert!(cfg.unwrap().bidi_isolation);
+    }
+
+    #[test]
+    fn error_line_number() {
+        let dir = TempDir::new("error_line");
+        dir.write(
+            "member/Cargo.toml",
+            "[package]\nname = \"member\"\n\n[package.metadata.leptos-i18n]\ndefault = \"en\"\nlocales = \"en\"\n",
+        );
+
+        let (cfg, _) = dir.parse_member();
+        let err = cfg.unwrap_err().to_string();
+        assert!(err.contains("line 6"), "{}", err);
+    }
+
+    #[test]
+    fn skip_broken_ancestor_manifest() {
+        let dir = TempDir::new("broken_ancestor");
+        dir.write("Cargo.toml", WORKSPACE_MANIFEST);
+        std::fs::create_dir_all(dir.0.join("member/crate")).unwrap();
+        dir.write("member/Cargo.toml", "this is not toml");
+        dir.write("member/crate/Cargo.toml", "[package]\nname = \"crate\"\n");
+
+        let mut tracked_files = vec![];
+        let cfg = ConfigFile::new(&mut dir.0.join("member/crate"), &mut tracked_files).unwrap();
+        assert_eq!(cfg.locales_dir, "translations");
+    }
+
+    #[test]
+    fn missing_config() {
+        let dir = TempDir::new("missing");
+        dir.write("Cargo.toml", "[workspace]\nmembers = [\"member\"]\n");
+        dir.write("member/Cargo.toml", "[package]\nname = \"member\"\n");
+
+        let (cfg, _) = dir.parse_member();
+        assert!(matches!(
+            cfg.map_err(|err| *err),
+            Err(Error::ConfigNotPresent)
+        ));
+    }
+}
@@ -18,7 +18,10 @@ pub enum Error {
     CargoDirEnvNotPresent(std::env::VarError),
     ManifestNotFound(std::io::Error),
     ConfigNotPresent,
-    ConfigFileDeser(toml::de::Error),
+    ConfigFileDeser {
+        path: PathBuf,
+        err: toml::de::Error,
+    },
     LocaleFileNotFound(Vec<(PathBuf, std::io::Error)>),
     LocaleFileDeser {
         path: PathBuf,
@@ -48,6 +51,10 @@ pub enum Error {
     EmptyRange,
     InvalidRangeType(String),
     NestedRanges,
+    EmptySelect,
+    InvalidSelector(String),
+    DuplicateSelector(String),
+    MissingSelectOther,
     InvalidFallback,
     MultipleFallbacks,
     MissingFallback(RangeType),
@@ -111,6 +118,17 @@ pub enum Error {
     RangeAndPluralsMix {
         key_path: KeyPath,
     },
+    SelectAndCountMix {
+        key_path: KeyPath,
+    },
+    InvalidSelectArg {
+        locale: Key,
+        key_path: KeyPath,
+        foreign_key: KeyPath,
+    },
+    SelectNameCollision {
+        name: String,
+    },
     PluralsAtNormalKey {
         locale: Key,
         key_path: KeyPath,
@@ -120,12 +138,43 @@ pub enum Error {
         key_path: KeyPath,
         formatter: crate::utils::formatter::Formatter,
     },
+    InvalidFormatterArgs {
+        locale: Key,
+        key_path: KeyPath,
+        message: String,
+    },
     DisabledPlurals {
         locale: Key,
         key_path: KeyPath,
     },
+    UnsupportedFtl {
+        locale: Key,
+        key_path: KeyPath,
+        message: String,
+    },
+    UnknownFtlTerm {
+        locale: Key,
+        key_path: KeyPath,
+        term: String,
+    },
+    InvalidMessageFormat {
+        locale: Key,
+        key_path: KeyPath,
+        message: String,
+    },
     NoFileFormats,
-    MultipleFilesFormats,
+    MultipleLocaleFiles(Vec<PathBuf>),
+    FlatKeyCollision(KeyPath),
+    UnknownMetadataKey(KeyPath),
+    ComponentKindMismatch {
+        key_path: KeyPath,
+        comp: Key,
+    },
+    InvalidInlinePlural {
+        locale: Key,
+        key_path: KeyPath,
+        message: String,
+    },
     MissingTranslationsURI,
 }
 
@@ -139,10 +188,10 @@ impl Display for Error {
                 write!(f, "Error accessing cargo manifest (Cargo.toml) : {}", err)
             },
             Error::ConfigNotPresent => {
-                write!(f, "Could not found \"[package.metadata.leptos-i18n]\" in cargo manifest (Cargo.toml)")
+                write!(f, "Could not found the configuration, expected an \"i18n.toml\" file, or a \"[package.metadata.leptos-i18n]\" or \"[workspace.metadata.leptos-i18n]\" section in cargo manifest (Cargo.toml)")
             }
-            Error::ConfigFileDeser(err) => {
-                write!(f, "Parsing of cargo manifest (Cargo.toml) failed: {}", err)
+            Error::ConfigFileDeser { path, err } => {
+                write!(f, "Parsing of configuration file {:?} failed: {}", path, err)
             }
             Error::LocaleFileNotFound(errs) => {
                 for (path, err) in errs {
@@ -165,7 +214,7 @@ impl Display for Error {
                 range, range_type
             ),
             Error::DuplicateLocalesInConfig(duplicates) => write!(f,
-                "Found duplicates locales in configuration (Cargo.toml): {:?}", 
+                "Found duplicates locales in configuration: {:?}", 
                 duplicates
             ),
             Error::InvalidBoundEnd {
@@ -186,7 +235,7 @@ impl Display for Error {
                 range
             ),
             Error::DuplicateNamespacesInConfig(duplicates) => write!(f,
-                "Found duplicates namespaces in configuration (Cargo.toml): {:?}", 
+                "Found duplicates namespaces in configuration: {:?}", 
                 duplicates
             ),
             Error::RangeTypeMissmatch { key_path, type1, type2 } => write!(f, "Conflicting range value type at key \"{}\", found type {} but also type {}.", key_path, type1, type2),
@@ -194,6 +243,10 @@ impl Display for Error {
             Error::EmptyRange => write!(f, "empty ranges are not allowed"),
             Error::InvalidRangeType(t) => write!(f, "invalid range type {:?}", t),
             Error::NestedRanges => write!(f, "nested ranges are not allowed"),
+            Error::EmptySelect => write!(f, "empty selects are not allowed"),
+            Error::InvalidSelector(selector) => write!(f, "invalid selector {:?}, selectors can only contain alphanumeric characters and underscores and must start with a letter", selector),
+            Error::DuplicateSelector(selector) => write!(f, "selector {:?} is used multiple times", selector),
+            Error::MissingSelectOther => write!(f, "selects require an \"other\" fallback"),
             Error::InvalidFallback => write!(f, "fallbacks are only allowed in last position"),
             Error::MultipleFallbacks => write!(f, "only one fallback is allowed"),
             Error::MissingFallback(t) => write!(f, "range type {} require a fallback (or a fullrange \"..\")", t),
@@ -214,11 +267,22 @@ impl Display for Error {
             Error::CountArgOutsideRange { locale, key_path, foreign_key, err } => write!(f, "Invalid arg \"count\" in locale {:?} at key \"{}\" to foreign key \"{}\": argument \"count\" is outside range: {}", locale, key_path, foreign_key, err),
             Error::UnexpectedToken { locale, key_path, message } => write!(f, "Unexpected error occured while parsing key \"{}\" in locale {:?}: {}", key_path, locale, message),
             Error::RangeAndPluralsMix { key_path } => write!(f, "mixing plurals and ranges are not supported yet, for key \"{}\"", key_path),
+            Error::SelectAndCountMix { key_path } => write!(f, "a variable can't be used both as a select and as a count for plurals or ranges, for key \"{}\"", key_path),
+            Error::InvalidSelectArg { locale, key_path, foreign_key } => write!(f, "Invalid select arg in locale {:?} at key \"{}\" to foreign key \"{}\": argument for selects can only be a string literal or a single variable.", locale, key_path, foreign_key),
+            Error::SelectNameCollision { name } => write!(f, "the enum {} generated for a select variable collides with an item generated by the i18n module or with the std prelude, rename the variable", name),
             Error::PluralsAtNormalKey { key_path, locale } => write!(f, "In locale {:?} at key \"{}\", Found plurals but a key of that name is already present.", locale, key_path),
             Error::DisabledFormatter { locale, key_path, formatter } => write!(f, "{}, at key \"{}\" in locale {:?}", formatter.err_message(), key_path, locale),
+            Error::InvalidFormatterArgs { locale, key_path, message } => write!(f, "Invalid formatter arguments at key \"{}\" in locale {:?}: {}", key_path, locale, message),
             Error::DisabledPlurals { locale, key_path } => write!(f, "Plurals are not enabled, enable the \"plurals\" feature to use them, at key \"{}\" in locale {:?}", key_path, locale),
-            Error::NoFileFormats => write!(f, "No file formats has been provided for leptos_i18n. Supported formats are: json, json5 and yaml."),
-            Error::MultipleFilesFormats => write!(f, "Multiple file formats have been provided for leptos_i18n, choose only one. Supported formats are: json, json5 and yaml."),
+            Error::UnsupportedFtl { locale, key_path, message } => write!(f, "Unsupported fluent syntax at key \"{}\" in locale {:?}: {}", key_path, locale, message),
+            Error::UnknownFtlTerm { locale, key_path, term } => write!(f, "Unknown fluent term \"-{}\" at key \"{}\" in locale {:?}.", term, key_path, locale),
+            Error::InvalidMessageFormat { locale, key_path, message } => write!(f, "Invalid ICU MessageFormat string at key \"{}\" in locale {:?}: {}", key_path, locale, message),
+            Error::NoFileFormats => write!(f, "No file formats has been provided for leptos_i18n. Supported formats are: json, json5, yaml and ftl."),
+            Error::FlatKeyCollision(key_path) => write!(f, "Key \"{}\" is declared both as a flattened key and as a nested key, or with a value and with subkeys.", key_path),
+            Error::UnknownMetadataKey(key_path) => write!(f, "Metadata is declared for a key inside of \"{}\", but it is not a subkeys.", key_path),
+            Error::ComponentKindMismatch { key_path, comp } => write!(f, "Component \"{}\" at key \"{}\" is used both as a self-closing component and with children.", comp.name.strip_prefix("comp_").unwrap_or(&comp.name), key_path),
+            Error::InvalidInlinePlural { locale, key_path, message } => write!(f, "Invalid inline plural at key \"{}\" in locale {:?}: {}", key_path, locale, message),
+            Error::MultipleLocaleFiles(paths) => write!(f, "Multiple files have been found for the same locale: {:?}, keep only one.", paths),
             Error::InvalidLocale {
                 locale,
                 err
@@ -0,0 +1,796 @@
+use std::{
+    cell::RefCell,
+    collections::{BTreeMap, HashMap},
+    fmt::Display,
+};
+
+use fluent_syntax::{
+    ast::{
+        CallArguments, Entry, Expression, InlineExpression, Pattern, PatternElement, Term, Variant,
+        VariantKey,
+    },
+    parser::ParserError,
+};
+
+use super::{
+    error::{Error, Result},
+    locale::{Locale, LocaleSeed},
+    metadata::KeyMetadata,
+    parsed_value::{ForeignKey, Literal, ParsedValue},
+    plurals::{PluralForm, PluralRuleType, Plurals},
+    ranges::{Range, RangeNumber, Ranges, RangesInner, UntypedRangesInner},
+    select::{Select, SELECT_OTHER},
+    ForeignKeysPaths,
+};
+use crate::utils::{
+    formatter::{Formatter, FormatterError, SKIP_ICU_CFG},
+    Key, KeyPath, UnwrapAt,
+};
+
+/// Key under which the value of a message is put when the message also has attributes.
+pub const FTL_MESSAGE_VALUE_KEY: &str = "value";
+
+#[derive(Debug)]
+pub enum FtlError {
+    Syntax(Vec<ParserError>),
+    Invalid(Box<Error>),
+}
+
+impl Display for FtlError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FtlError::Syntax(errs) => {
+                for err in errs {
+                    writeln!(f, "at byte {}: {}", err.pos.start, err)?;
+                }
+                Ok(())
+            }
+            FtlError::Invalid(err) => Display::fmt(err, f),
+        }
+    }
+}
+
+impl From<Box<Error>> for FtlError {
+    fn from(value: Box<Error>) -> Self {
+        FtlError::Invalid(value)
+    }
+}
+
+struct FtlContext<'a> {
+    top_locale_name: &'a Key,
+    key_path: KeyPath,
+    foreign_keys_paths: &'a ForeignKeysPaths,
+    terms: HashMap<&'a str, &'a Term<&'a str>>,
+    terms_stack: Vec<&'a str>,
+}
+
+pub fn de_ftl(source: &str, seed: LocaleSeed) -> Result<Locale, FtlError> {
+    let resource =
+        fluent_syntax::parser::parse(source).map_err(|(_, errs)| FtlError::Syntax(errs))?;
+
+    let LocaleSeed {
+        name,
+        top_locale_name,
+        key_path,
+        foreign_keys_paths,
+        ..
+    } = seed;
+
+    let terms = resource
+        .body
+        .iter()
+        .filter_map(|entry| match entry {
+            Entry::Term(term) => Some((term.id.name, term)),
+            _ => None,
+        })
+        .collect();
+
+    let mut ctx = FtlContext {
+        top_locale_name: &top_locale_name,
+        key_path,
+        foreign_keys_paths,
+        terms,
+        terms_stack: vec![],
+    };
+
+    let mut keys = BTreeMap::new();
+    let mut metadata = BTreeMap::new();
+
+    for entry in &resource.body {
+        let Entry::Message(message) = entry else {
+            continue;
+        };
+        let key = Key::try_new(message.id.name)?;
+        // the comment attached to a message is its description for translators.
+        if let Some(comment) = &message.comment {
+            let key_metadata = KeyMetadata {
+                description: Some(comment.content.join("\n")),
+                ..Default::default()
+            };
+            metadata.insert(key.clone(), key_metadata);
+        }
+        ctx.key_path.push_key(key.clone());
+        let value = if message.attributes.is_empty() {
+            // the parser guarantee that a message without attributes has a value.
+            let pattern = message.value.as_ref().unwrap_at("de_ftl_1");
+            ctx.pattern_to_value(pattern)?
+        } else {
+            let mut subkeys = BTreeMap::new();
+            if let Some(pattern) = &message.value {
+                let value_key = Key::new(FTL_MESSAGE_VALUE_KEY).unwrap_at("FTL_MESSAGE_VALUE_KEY");
+                ctx.key_path.push_key(value_key.clone());
+                subkeys.insert(value_key, ctx.pattern_to_value(pattern)?);
+                ctx.key_path.pop_key();
+            }
+            for attribute in &message.attributes {
+                let attr_key = Key::try_new(attribute.id.name)?;
+                ctx.key_path.push_key(attr_key.clone());
+                subkeys.insert(attr_key, ctx.pattern_to_value(&attribute.value)?);
+                ctx.key_path.pop_key();
+            }
+            ParsedValue::Subkeys(Some(Locale {
+                top_locale_name: top_locale_name.clone(),
+                name: key.clone(),
+                keys: subkeys,
+                metadata: BTreeMap::new(),
+                strings: vec![],
+                top_locale_string_count: 0,
+            }))
+        };
+        ctx.key_path.pop_key();
+        keys.insert(key, value);
+    }
+
+    Ok(Locale {
+        top_locale_name: top_locale_name.clone(),
+        name,
+        keys,
+        metadata,
+        strings: vec![],
+        top_locale_string_count: 0,
+    })
+}
+
+fn var_key(name: &str) -> Result<Key> {
+    Key::try_new(&format!("var_{}", name))
+}
+
+fn unescape_string_literal(s: &str) -> String {
+    let mut unescaped = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        let len = match chars.next() {
+            Some('u') => 4,
+            Some('U') => 6,
+            Some(c) => {
+                unescaped.push(c);
+                continue;
+            }
+            None => break,
+        };
+        let code: String = chars.by_ref().take(len).collect();
+        match u32::from_str_radix(&code, 16).ok().and_then(char::from_u32) {
+            Some(c) => unescaped.push(c),
+            None => unescaped.push(char::REPLACEMENT_CHARACTER),
+        }
+    }
+    unescaped
+}
+
+fn new_ranges<T: RangeNumber>(variants: &[(Option<&str>, ParsedValue)]) -> Result<RangesInner<T>> {
+    let mut ranges = Vec::with_capacity(variants.len() + 1);
+    let mut fallback = None;
+    for (key, value) in variants {
+        match key {
+            Some(key) => ranges.push((Range::new(key)?, value.clone())),
+            None => fallback = Some(value.clone()),
+        }
+    }
+    if let Some(fallback) = fallback {
+        ranges.push((Range::Fallback, fallback));
+    }
+    Ok(ranges)
+}
+
+impl<'a> FtlContext<'a> {
+    fn unsupported(&self, message: impl ToString) -> Box<Error> {
+        Box::new(Error::UnsupportedFtl {
+            locale: self.top_locale_name.clone(),
+            key_path: self.key_path.clone(),
+            message: message.to_string(),
+        })
+    }
+
+    fn pattern_to_value(&mut self, pattern: &'a Pattern<&'a str>) -> Result<ParsedValue> {
+        let mut values = pattern
+            .elements
+            .iter()
+            .map(|element| match element {
+                PatternElement::TextElement { value } => Ok(ParsedValue::Literal(Literal::String(
+                    value.to_string(),
+                    usize::MAX,
+                ))),
+                PatternElement::Placeable { expression } => self.expression_to_value(expression),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        if values.len() == 1 {
+            Ok(values.pop().unwrap_at("pattern_to_value_1"))
+        } else {
+            Ok(ParsedValue::Bloc(values))
+        }
+    }
+
+    fn expression_to_value(&mut self, expression: &'a Expression<&'a str>) -> Result<ParsedValue> {
+        match expression {
+            Expression::Inline(inline) => self.inline_to_value(inline),
+            Expression::Select { selector, variants } => self.select_to_value(selector, variants),
+        }
+    }
+
+    fn inline_to_value(&mut self, inline: &'a InlineExpression<&'a str>) -> Result<ParsedValue> {
+        match inline {
+            InlineExpression::StringLiteral { value } => Ok(ParsedValue::Literal(Literal::String(
+                unescape_string_literal(value),
+                usize::MAX,
+            ))),
+            InlineExpression::NumberLiteral { value } => Ok(ParsedValue::Literal(Literal::String(
+                value.to_string(),
+                usize::MAX,
+            ))),
+            InlineExpression::VariableReference { id } => Ok(ParsedValue::Variable {
+                key: var_key(id.name)?,
+                formatter: Formatter::None,
+            }),
+            InlineExpression::FunctionReference { id, arguments } => {
+                let (key, formatter) = self.function_to_formatter(id.name, arguments)?;
+                Ok(ParsedValue::Variable { key, formatter })
+            }
+            InlineExpression::MessageReference { id, attribute } => {
+                let mut target_key_path = KeyPath::new(self.key_path.namespace.clone());
+                target_key_path.push_key(Key::try_new(id.name)?);
+                if let Some(attribute) = attribute {
+                    target_key_path.push_key(Key::try_new(attribute.name)?);
+                }
+                Ok(ParsedValue::ForeignKey(RefCell::new(ForeignKey::new(
+                    self.key_path.clone(),
+                    target_key_path,
+                    BTreeMap::new(),
+                    self.top_locale_name,
+                    self.foreign_keys_paths,
+                ))))
+            }
+            InlineExpression::TermReference {
+                id,
+                attribute,
+                arguments,
+            } => {
+                let pattern =
+                    self.find_term_pattern(id.name, attribute.as_ref().map(|a| a.name))?;
+                if self.terms_stack.contains(&id.name) {
+                    return Err(
+                        self.unsupported(format!("recursive term reference \"-{}\"", id.name))
+                    );
+                }
+                self.terms_stack.push(id.name);
+                let value = self.pattern_to_value(pattern);
+                self.terms_stack.pop();
+                let value = value?;
+                let Some(arguments) = arguments else {
+                    return Ok(value);
+                };
+                let mut args = BTreeMap::new();
+                for arg in &arguments.named {
+                    let arg_value = self.inline_to_value(&arg.value)?;
+                    args.insert(format!("var_{}", arg.name.name), arg_value);
+                }
+                let term_path = KeyPath::new(None);
+                value.populate(&args, &term_path, self.top_locale_name, &self.key_path)
+            }
+            InlineExpression::Placeable { expression } => self.expression_to_value(expression),
+        }
+    }
+
+    fn find_term_pattern(
+        &self,
+        name: &str,
+        attribute: Option<&str>,
+    ) -> Result<&'a Pattern<&'a str>> {
+        let Some(term) = self.terms.get(name).copied() else {
+            return Err(Box::new(Error::UnknownFtlTerm {
+                locale: self.top_locale_name.clone(),
+                key_path: self.key_path.clone(),
+                term: name.to_string(),
+            }));
+        };
+        match attribute {
+            None => Ok(&term.value),
+            Some(attribute) => term
+                .attributes
+                .iter()
+                .find(|attr| attr.id.name == attribute)
+                .map(|attr| &attr.value)
+                .ok_or_else(|| {
+                    Box::new(Error::UnknownFtlTerm {
+                        locale: self.top_locale_name.clone(),
+                        key_path: self.key_path.clone(),
+                        term: format!("{}.{}", name, attribute),
+                    })
+                }),
+        }
+    }
+
+    fn single_variable_arg(&self, name: &str, arguments: &CallArguments<&str>) -> Result<Key> {
+        match arguments.positional.as_slice() {
+            [InlineExpression::VariableReference { id }] => var_key(id.name),
+            _ => Err(self.unsupported(format!(
+                "function {} only accept a single variable as positional argument",
+                name
+            ))),
+        }
+    }
+
+    fn named_args<'b>(arguments: &'b CallArguments<&'b str>) -> Vec<(&'b str, &'b str)> {
+        arguments
+            .named
+            .iter()
+            .filter_map(|arg| match &arg.value {
+                InlineExpression::StringLiteral { value }
+                | InlineExpression::NumberLiteral { value } => Some((arg.name.name, *value)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn function_to_formatter(
+        &self,
+        name: &str,
+        arguments: &CallArguments<&str>,
+    ) -> Result<(Key, Formatter)> {
+        let key = self.single_variable_arg(name, arguments)?;
+        let named_args = Self::named_args(arguments);
+        let get_arg = |arg_name: &str| {
+            named_args
+                .iter()
+                .find(|(name, _)| *name == arg_name)
+                .map(|(_, value)| *value)
+        };
+        let mut args: Vec<(&str, &str)> = vec![];
+        let formatter_name = match name {
+            "NUMBER" => {
+                if get_arg("useGrouping") == Some("false") {
+                    args.push(("grouping_strategy", "never"));
+                }
+                for (ftl_name, arg_name) in [
+                    ("minimumIntegerDigits", "minimum_integer_digits"),
+                    ("minimumFractionDigits", "minimum_fraction_digits"),
+                    ("maximumFractionDigits", "maximum_fraction_digits"),
+                ] {
+                    if let Some(digits) = get_arg(ftl_name) {
+                        args.push((arg_name, digits));
+                    }
+                }
+                match (get_arg("style"), get_arg("currency")) {
+                    (Some("currency"), currency_code) => {
+                        if let Some(currency_code) = currency_code {
+                            args.push(("currency_code", currency_code));
+                        }
+                        if get_arg("currencyDisplay") == Some("narrowSymbol") {
+                            args.push(("width", "narrow"));
+                        }
+                        "currency"
+                    }
+                    _ => "number",
+                }
+            }
+            "DATETIME" => {
+                let date_style = get_arg("dateStyle");
+                let time_style = get_arg("timeStyle");
+                if let Some(date_style) = date_style {
+                    args.push(("date_length", date_style));
+                }
+                if let Some(time_style) = time_style {
+                    args.push(("time_length", time_style));
+                }
+                for (ftl_name, arg_name) in [
+                    ("era", "era"),
+                    ("year", "year"),
+                    ("month", "month"),
+                    ("day", "day"),
+                    ("weekday", "weekday"),
+                    ("hour", "hour"),
+                    ("minute", "minute"),
+                    ("second", "second"),
+                    ("hourCycle", "hour_cycle"),
+                ] {
+                    if let Some(value) = get_arg(ftl_name) {
+                        // `Intl.DateTimeFormat` spells it "2-digit".
+                        let value = if value == "2-digit" {
+                            "two_digit"
+                        } else {
+                            value
+                        };
+                        args.push((arg_name, value));
+                    }
+                }
+                match (date_style, time_style) {
+                    (Some(_), None) => "date",
+                    (None, Some(_)) => "time",
+                    _ => "datetime",
+                }
+            }
+            _ => {
+                return Err(Box::new(Error::UnknownFormatter {
+                    name: name.to_string(),
+                    locale: self.top_locale_name.clone(),
+                    key_path: self.key_path.clone(),
+                }))
+            }
+        };
+        match Formatter::from_name_and_args(formatter_name, Some(&args)) {
+            Ok(Some(formatter)) => Ok((key, formatter)),
+            Ok(None) => unreachable!("known formatter {:?} not recognized.", formatter_name),
+            Err(FormatterError::Disabled(formatter)) => Err(Box::new(Error::DisabledFormatter {
+                locale: self.top_locale_name.clone(),
+                key_path: self.key_path.clone(),
+                formatter,
+            })),
+            Err(FormatterError::InvalidArgs(message)) => {
+                Err(Box::new(Error::InvalidFormatterArgs {
+                    locale: self.top_locale_name.clone(),
+                    key_path: self.key_path.clone(),
+                    message,
+                }))
+            }
+        }
+    }
+
+    fn select_to_value(
+        &mut self,
+        selector: &'a InlineExpression<&'a str>,
+        variants: &'a [Variant<&'a str>],
+    ) -> Result<ParsedValue> {
+        let (count_key, rule_type) =
+            match selector {
+                InlineExpression::VariableReference { id } => {
+                    (var_key(id.name)?, PluralRuleType::Cardinal)
+                }
+                InlineExpression::FunctionReference { id, arguments } if id.name == "NUMBER" => {
+                    let key = self.single_variable_arg(id.name, arguments)?;
+                    let is_ordinal = Self::named_args(arguments).contains(&("type", "ordinal"));
+                    let rule_type = if is_ordinal {
+                        PluralRuleType::Ordinal
+                    } else {
+                        PluralRuleType::Cardinal
+                    };
+                    (key, rule_type)
+                }
+                InlineExpression::TermReference {
+                    id,
+                    attribute: Some(attribute),
+                    ..
+                } => return self.static_select(id.name, attribute.name, variants),
+                _ => return Err(self.unsupported(
+                    "selectors can only be a variable, a NUMBER function call or a term attribute",
+                )),
+            };
+
+        let is_numeric = variants
+            .iter()
+            .any(|variant| matches!(variant.key, VariantKey::NumberLiteral { .. }));
+
+        // a variable selector with variants that are not plural categories is a select
+        let is_select = matches!(selector, InlineExpression::VariableReference { .. })
+            && variants.iter().any(|variant| {
+                matches!(variant.key, VariantKey::Identifier { name } if PluralForm::try_from_str(name).is_none())
+            });
+
+        // numeric variants next to plural categories are exact-number overrides, `[0]` and `[one]`
+        let is_plural_with_exact = is_numeric
+            && variants.iter().any(|variant| {
+                !variant.default && matches!(variant.key, VariantKey::Identifier { .. })
+            });
+
+        if is_plural_with_exact {
+            self.select_to_plurals(count_key, rule_type, variants)
+        } else if is_numeric {
+            self.select_to_ranges(count_key, variants)
+        } else if is_select {
+            self.select_to_select(count_key, variants)
+        } else {
+            self.select_to_plurals(count_key, rule_type, variants)
+        }
+    }
+
+    fn static_select(
+        &mut self,
+        term: &'a str,
+        attribute: &'a str,
+        variants: &'a [Variant<&'a str>],
+    ) -> Result<ParsedValue> {
+        let pattern = self.find_term_pattern(term, Some(attribute))?;
+        let selected = match pattern.elements.as_slice() {
+            [PatternElement::TextElement { value }] => variants.iter().find(|variant| {
+                matches!(variant.key, VariantKey::Identifier { name } if name == value.trim())
+            }),
+            _ => None,
+        };
+        let variant = selected
+            .or_else(|| variants.iter().find(|variant| variant.default))
+            .unwrap_at("static_select_1");
+        self.pattern_to_value(&variant.value)
+    }
+
+    fn select_to_plurals(
+        &mut self,
+        count_key: Key,
+        rule_type: PluralRuleType,
+        variants: &'a [Variant<&'a str>],
+    ) -> Result<ParsedValue> {
+        if !cfg!(feature = "plurals") && !SKIP_ICU_CFG.get() {
+            return Err(Box::new(Error::DisabledPlurals {
+                locale: self.top_locale_name.clone(),
+                key_path: self.key_path.clone(),
+            }));
+        }
+        let mut forms = BTreeMap::new();
+        let mut exact = BTreeMap::new();
+        let mut default = None;
+        for variant in variants {
+            let name = match variant.key {
+                VariantKey::Identifier { name } => name,
+                VariantKey::NumberLiteral { value: number } => {
+                    let Ok(number) = number.parse::<u64>() else {
+                        return Err(self.unsupported(format!(
+                            "numeric variants next to plural categories must be non-negative integers, found \"{}\"",
+                            number
+                        )));
+                    };
+                    let value = self.pattern_to_value(&variant.value)?;
+                    if variant.default {
+                        default = Some(value.clone());
+                    }
+                    exact.insert(number, value);
+                    continue;
+                }
+            };
+            let Some(form) = PluralForm::try_from_str(name) else {
+                return Err(self.unsupported(format!(
+                    "unknown plural category \"{}\", expected one of zero, one, two, few, many or other",
+                    name
+                )));
+            };
+            let value = self.pattern_to_value(&variant.value)?;
+            if variant.default {
+                default = Some(value.clone());
+            }
+            forms.insert(form, value);
+        }
+        let other = match forms.remove(&PluralForm::Other) {
+            Some(other) => other,
+            // the parser guarantee that there is always a default variant.
+            None => default.unwrap_at("select_to_plurals_1"),
+        };
+        Ok(ParsedValue::Plurals(Plurals {
+            rule_type,
+            count_key,
+            other: Box::new(other),
+            forms,
+            exact,
+        }))
+    }
+
+    fn select_to_select(
+        &mut self,
+        select_key: Key,
+        variants: &'a [Variant<&'a str>],
+    ) -> Result<ParsedValue> {
+        let mut arms = Vec::with_capacity(variants.len() + 1);
+        for variant in variants {
+            let VariantKey::Identifier { name } = variant.key else {
+                unreachable!("numeric variant keys are handled by ranges.");
+            };
+            let value = self.pattern_to_value(&variant.value)?;
+            // the default variant is used as the "other" fallback
+            if variant.default && name != SELECT_OTHER {
+                arms.push((SELECT_OTHER, value.clone()));
+            }
+            arms.push((name, value));
+        }
+        Select::new(select_key, arms)
+            .map(ParsedValue::Select)
+            .map_err(|err| self.unsupported(err))
+    }
+
+    fn select_to_ranges(
+        &mut self,
+        count_key: Key,
+        variants: &'a [Variant<&'a str>],
+    ) -> Result<ParsedValue> {
+        let mut parsed_variants = Vec::with_capacity(variants.len() + 1);
+        let mut is_float = false;
+        for variant in variants {
+            let value = self.pattern_to_value(&variant.value)?;
+            match variant.key {
+                VariantKey::NumberLiteral { value: key } => {
+                    is_float |= key.contains('.');
+                    if variant.default {
+                        parsed_variants.push((None, value.clone()));
+                    }
+                    parsed_variants.push((Some(key), value));
+                }
+                VariantKey::Identifier { .. } if variant.default => {
+                    parsed_variants.push((None, value))
+                }
+                VariantKey::Identifier { name } => {
+                    return Err(self.unsupported(format!(
+                        "mixing numeric and named variants is not supported, found \"{}\"",
+                        name
+                    )))
+                }
+            }
+        }
+        // the fallback must be in last position
+        parsed_variants.sort_by_key(|(key, _)| key.is_none());
+        let inner = if is_float {
+            UntypedRangesInner::F64(new_ranges(&parsed_variants)?)
+        } else {
+            UntypedRangesInner::I32(new_ranges(&parsed_variants)?)
+        };
+        Ok(ParsedValue::Ranges(Ranges { count_key, inner }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_locales::plurals::PluralForm;
+    use crate::utils::formatter::{GroupingStrategy, NumberPrecision};
+
+    fn new_key(key: &str) -> Key {
+        Key::new(key).unwrap()
+    }
+
+    fn parse(source: &str) -> Locale {
+        let _guard = crate::utils::formatter::SkipIcuCfgGuard::new(true);
+        let foreign_keys_paths = ForeignKeysPaths::new();
+        let seed = LocaleSeed {
+            name: new_key("en"),
+            top_locale_name: new_key("en"),
+            key_path: KeyPath::new(None),
+            foreign_keys_paths: &foreign_keys_paths,
+            message_format: Default::default(),
+            key_separator: None,
+        };
+        de_ftl(source, seed).unwrap()
+    }
+
+    fn lit(s: &str) -> ParsedValue {
+        ParsedValue::Literal(Literal::String(s.to_string(), usize::MAX))
+    }
+
+    #[test]
+    fn parse_message_with_variable() {
+        let locale = parse("hello = Hello { $name }!\n");
+
+        assert_eq!(
+            locale.keys[&new_key("hello")],
+            ParsedValue::Bloc(vec![
+                lit("Hello "),
+                ParsedValue::Variable {
+                    key: new_key("var_name"),
+                    formatter: Formatter::None
+                },
+                lit("!")
+            ])
+        );
+    }
+
+    #[test]
+    fn parse_number_function() {
+        let locale = parse(
+            "price = { NUMBER($amount, minimumFractionDigits: 2, maximumFractionDigits: 2) }\n",
+        );
+
+        let ParsedValue::Variable { formatter, .. } = &locale.keys[&new_key("price")] else {
+            panic!("expected a variable");
+        };
+        let precision = NumberPrecision {
+            minimum_fraction_digits: Some(2),
+            maximum_fraction_digits: Some(2),
+            ..Default::default()
+        };
+        assert_eq!(
+            *formatter,
+            Formatter::Number(GroupingStrategy::Auto, precision)
+        );
+        assert_eq!(
+            formatter.to_string(),
+            "number(minimum_fraction_digits: 2; maximum_fraction_digits: 2)"
+        );
+    }
+
+    #[test]
+    fn parse_attributes() {
+        let locale = parse("login = Login\n    .placeholder = email\n");
+
+        let ParsedValue::Subkeys(Some(subkeys)) = &locale.keys[&new_key("login")] else {
+            panic!("expected subkeys");
+        };
+        assert_eq!(subkeys.keys[&new_key(FTL_MESSAGE_VALUE_KEY)], lit("Login"));
+        assert_eq!(subkeys.keys[&new_key("placeholder")], lit("email"));
+    }
+
+    #[test]
+    fn parse_term() {
+        let locale = parse("-brand = Leptos\nabout = About { -brand }\n");
+
+        assert!(!locale.keys.contains_key(&new_key("-brand")));
+        assert_eq!(
+            locale.keys[&new_key("about")],
+            ParsedValue::Bloc(vec![lit("About "), lit("Leptos")])
+        );
+    }
+
+    #[test]
+    fn parse_plural_selector() {
+        let locale = parse("items = { $n ->\n    [one] one item\n   *[other] { $n } items\n}\n");
+
+        let ParsedValue::Plurals(plurals) = &locale.keys[&new_key("items")] else {
+            panic!("expected plurals");
+        };
+        assert_eq!(plurals.count_key, new_key("var_n"));
+        assert_eq!(plurals.rule_type, PluralRuleType::Cardinal);
+        assert_eq!(plurals.forms[&PluralForm::One], lit("one item"));
+        assert!(!plurals.forms.contains_key(&PluralForm::Other));
+    }
+
+    #[test]
+    fn parse_numeric_selector() {
+        let locale = parse("items = { $n ->\n    [0] no items\n   *[other] some items\n}\n");
+
+        let ParsedValue::Ranges(ranges) = &locale.keys[&new_key("items")] else {
+            panic!("expected ranges");
+        };
+        assert_eq!(
+            ranges.inner,
+            UntypedRangesInner::I32(vec![
+                (Range::Exact(0), lit("no items")),
+                (Range::Fallback, lit("some items"))
+            ])
+        );
+    }
+
+    #[test]
+    fn parse_plural_selector_with_exact() {
+        let locale = parse(
+            "items = { $n ->\n    [0] no items\n    [one] one item\n   *[other] { $n } items\n}\n",
+        );
+
+        let ParsedValue::Plurals(plurals) = &locale.keys[&new_key("items")] else {
+            panic!("expected plurals");
+        };
+        assert_eq!(plurals.exact[&0], lit("no items"));
+        assert_eq!(plurals.forms[&PluralForm::One], lit("one item"));
+    }
+
+    #[test]
+    fn parse_string_selector() {
+        let locale = parse("invited = { $gender ->\n    [male] He\n    [female] She\n   *[other] They\n} invited you\n");
+
+        let ParsedValue::Bloc(values) = &locale.keys[&new_key("invited")] else {
+            panic!("expected a bloc");
+        };
+        let ParsedValue::Select(select) = &values[0] else {
+            panic!("expected a select");
+        };
+        assert_eq!(select.select_key, new_key("var_gender"));
+        assert_eq!(select.arms["male"], lit("He"));
+        assert_eq!(select.arms["female"], lit("She"));
+        assert_eq!(*select.other, lit("They"));
+    }
+}
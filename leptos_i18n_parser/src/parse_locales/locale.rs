@@ -9,10 +9,12 @@ use std::io::{BufReader, Read};
 use std::path::{Path, PathBuf};
 use std::rc::Rc;
 
-use super::cfg_file::ConfigFile;
+use super::cfg_file::{ConfigFile, MessageFormat};
 use super::error::{Error, Result};
+use super::ftl::{de_ftl, FtlError};
+use super::metadata::KeyMetadata;
 use super::parsed_value::{ParsedValue, ParsedValueSeed};
-use super::plurals::{PluralForm, PluralRuleType, Plurals};
+use super::plurals::{PluralForm, PluralRuleType, PluralSuffix, Plurals};
 use super::ranges::RangeType;
 use super::warning::{Warning, Warnings};
 use super::{ForeignKeysPaths, StringIndexer};
@@ -22,9 +24,8 @@ pub enum SerdeError {
     Json(serde_json::Error),
     Yaml(serde_yaml::Error),
     Json5(json5::Error),
+    Ftl(FtlError),
     Io(std::io::Error),
-    None,
-    Multiple,
 }
 
 impl std::fmt::Display for SerdeError {
@@ -33,26 +34,34 @@ impl std::fmt::Display for SerdeError {
             SerdeError::Json(error) => std::fmt::Display::fmt(error, f),
             SerdeError::Yaml(error) => std::fmt::Display::fmt(error, f),
             SerdeError::Json5(error) => std::fmt::Display::fmt(error, f),
+            SerdeError::Ftl(error) => std::fmt::Display::fmt(error, f),
             SerdeError::Io(error) => std::fmt::Display::fmt(error, f),
-            SerdeError::None => write!(f, "No file formats has been provided for leptos_i18n. Supported formats are: json, json5 and yaml."),
-            SerdeError::Multiple => write!(f, "Multiple file formats have been provided for leptos_i18n, choose only one. Supported formats are: json, json5 and yaml."),
         }
     }
 }
 
-const fn get_files_exts() -> &'static [&'static str] {
-    if cfg!(feature = "json_files") {
-        &["json"]
-    } else if cfg!(feature = "yaml_files") {
-        &["yaml", "yml"]
-    } else if cfg!(feature = "json5_files") {
-        &["json5"]
-    } else {
-        &[]
-    }
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileFormat {
+    Json,
+    Yaml,
+    Json5,
+    Ftl,
 }
 
-const FILE_EXTS: &[&str] = get_files_exts();
+const FILE_EXTS: &[(&str, FileFormat, bool)] = &[
+    ("json", FileFormat::Json, cfg!(feature = "json_files")),
+    ("yaml", FileFormat::Yaml, cfg!(feature = "yaml_files")),
+    ("yml", FileFormat::Yaml, cfg!(feature = "yaml_files")),
+    ("json5", FileFormat::Json5, cfg!(feature = "json5_files")),
+    ("ftl", FileFormat::Ftl, cfg!(feature = "ftl_files")),
+];
+
+fn enabled_files_exts() -> impl Iterator<Item = (&'static str, FileFormat)> {
+    FILE_EXTS
+        .iter()
+        .filter(|(_, _, enabled)| *enabled)
+        .map(|(ext, format, _)| (*ext, *format))
+}
 
 fn de_inner_json<R: Read>(locale_file: R, seed: LocaleSeed) -> Result<Locale, SerdeError> {
     let mut deserializer = serde_json::Deserializer::from_reader(locale_file);
@@ -71,15 +80,22 @@ fn de_inner_yaml<R: Read>(locale_file: R, seed: LocaleSeed) -> Result<Locale, Se
     serde::de::DeserializeSeed::deserialize(seed, deserializer).map_err(SerdeError::Yaml)
 }
 
-fn de_inner<R: Read>(locale_file: R, seed: LocaleSeed) -> Result<Locale, SerdeError> {
-    if cfg!(feature = "json_files") {
-        de_inner_json(locale_file, seed)
-    } else if cfg!(feature = "yaml_files") {
-        de_inner_yaml(locale_file, seed)
-    } else if cfg!(feature = "json5_files") {
-        de_inner_json5(locale_file, seed)
-    } else {
-        unreachable!()
+fn de_inner_ftl<R: Read>(mut locale_file: R, seed: LocaleSeed) -> Result<Locale, SerdeError> {
+    let mut buff = String::new();
+    Read::read_to_string(&mut locale_file, &mut buff).map_err(SerdeError::Io)?;
+    de_ftl(&buff, seed).map_err(SerdeError::Ftl)
+}
+
+fn de_inner<R: Read>(
+    locale_file: R,
+    format: FileFormat,
+    seed: LocaleSeed,
+) -> Result<Locale, SerdeError> {
+    match format {
+        FileFormat::Json => de_inner_json(locale_file, seed),
+        FileFormat::Yaml => de_inner_yaml(locale_file, seed),
+        FileFormat::Json5 => de_inner_json5(locale_file, seed),
+        FileFormat::Ftl => de_inner_ftl(locale_file, seed),
     }
 }
 
@@ -88,6 +104,7 @@ pub struct Locale {
     pub top_locale_name: Key,
     pub name: Key,
     pub keys: BTreeMap<Key, ParsedValue>,
+    pub metadata: BTreeMap<Key, KeyMetadata>,
     pub strings: Vec<Rc<str>>,
     pub top_locale_string_count: usize,
 }
@@ -102,6 +119,7 @@ pub struct Namespace {
 pub enum RangeOrPlural {
     Range(RangeType),
     Plural,
+    Select,
 }
 
 #[derive(Debug)]
@@ -114,14 +132,26 @@ pub enum LocalesOrNamespaces {
 pub struct VarInfo {
     pub formatters: BTreeSet<Formatter>,
     pub range_count: Option<RangeOrPlural>,
+    pub selectors: BTreeSet<String>,
 }
 
 #[derive(Debug, Default)]
 pub struct InterpolationKeys {
-    components: BTreeSet<Key>,
+    components: BTreeMap<Key, ComponentKind>,
+    // components used with attributes in at least one locale, they receive the attributes.
+    components_with_attrs: BTreeSet<Key>,
     variables: BTreeMap<Key, VarInfo>,
 }
 
+/// How a component is used in the translations, it must be the same in every locale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ComponentKind {
+    /// `<b>...</b>`, the component receives its children.
+    Paired,
+    /// `<br/>`, the component has no children.
+    SelfClosing,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum LiteralType {
     String,
@@ -220,6 +250,9 @@ pub struct LocaleSeed<'a> {
     pub top_locale_name: Key,
     pub key_path: KeyPath,
     pub foreign_keys_paths: &'a ForeignKeysPaths,
+    pub message_format: MessageFormat,
+    /// Separator used to expand flattened keys ("a.b.c") into subkeys.
+    pub key_separator: Option<&'a str>,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -236,30 +269,35 @@ impl DefaultTo<'_> {
     }
 }
 
-fn find_file(path: &mut PathBuf) -> Result<File> {
+/// Look for the file with every enabled extension, the format used to parse it comes from the extension found.
+///
+/// On success `path` is set to the path of the file found.
+pub fn find_file(path: &mut PathBuf) -> Result<(File, FileFormat)> {
     let mut errs = vec![];
+    let mut found = vec![];
 
-    for ext in FILE_EXTS {
+    for (ext, format) in enabled_files_exts() {
         path.set_extension(ext);
         #[allow(clippy::needless_borrows_for_generic_args)]
         // see https://github.com/rust-lang/rust-clippy/issues/12856
         match File::open(&path) {
-            Ok(file) => return Ok(file),
+            Ok(file) => found.push((file, format, ext, path.to_owned())),
             Err(err) => {
                 errs.push((path.to_owned(), err));
             }
         };
     }
 
-    #[allow(clippy::const_is_empty)]
-    if !FILE_EXTS.is_empty() {
+    if found.len() > 1 {
+        let paths = found.into_iter().map(|(_, _, _, path)| path).collect();
+        return Err(Error::MultipleLocaleFiles(paths).into());
+    }
+
+    if let Some((file, format, ext, _)) = found.pop() {
+        path.set_extension(ext);
+        Ok((file, format))
+    } else if !errs.is_empty() {
         Err(Error::LocaleFileNotFound(errs).into())
-    } else if cfg!(any(
-        feature = "json_files",
-        feature = "yaml_files",
-        feature = "json5_files"
-    )) {
-        Err(Error::MultipleFilesFormats.into())
     } else {
         Err(Error::NoFileFormats.into())
     }
@@ -290,8 +328,43 @@ impl InterpolationKeys {
         var_infos.formatters.insert(formatter);
     }
 
-    pub fn push_comp(&mut self, key: Key) {
-        self.components.insert(key);
+    pub fn push_comp(
+        &mut self,
+        key_path: &mut KeyPath,
+        key: Key,
+        kind: ComponentKind,
+    ) -> Result<()> {
+        self.push_comp_inner(key_path, key, kind, false)
+    }
+
+    /// Same as `push_comp`, for a component used with attributes such as `<a href="/">`.
+    pub fn push_comp_with_attrs(
+        &mut self,
+        key_path: &mut KeyPath,
+        key: Key,
+        kind: ComponentKind,
+    ) -> Result<()> {
+        self.push_comp_inner(key_path, key, kind, true)
+    }
+
+    fn push_comp_inner(
+        &mut self,
+        key_path: &mut KeyPath,
+        key: Key,
+        kind: ComponentKind,
+        with_attrs: bool,
+    ) -> Result<()> {
+        if with_attrs {
+            self.components_with_attrs.insert(key.clone());
+        }
+        match self.components.insert(key.clone(), kind) {
+            Some(old) if old != kind => Err(Error::ComponentKindMismatch {
+                key_path: std::mem::take(key_path),
+                comp: key,
+            }
+            .into()),
+            _ => Ok(()),
+        }
     }
 
     pub fn push_count(
@@ -302,8 +375,16 @@ impl InterpolationKeys {
     ) -> Result<()> {
         let var_infos = self.variables.entry(count_key).or_default();
         match (var_infos.range_count.replace(ty), ty) {
-            (None, _) | (Some(RangeOrPlural::Plural), RangeOrPlural::Plural) => Ok(()),
+            (None, _)
+            | (Some(RangeOrPlural::Plural), RangeOrPlural::Plural)
+            | (Some(RangeOrPlural::Select), RangeOrPlural::Select) => Ok(()),
             (Some(RangeOrPlural::Range(old)), RangeOrPlural::Range(new)) if old == new => Ok(()),
+            (Some(RangeOrPlural::Select), _) | (Some(_), RangeOrPlural::Select) => {
+                Err(Error::SelectAndCountMix {
+                    key_path: std::mem::take(key_path),
+                }
+                .into())
+            }
             (Some(RangeOrPlural::Plural), RangeOrPlural::Range(_))
             | (Some(RangeOrPlural::Range(_)), RangeOrPlural::Plural) => {
                 Err(Error::RangeAndPluralsMix {
@@ -322,8 +403,20 @@ impl InterpolationKeys {
         }
     }
 
+    pub fn push_select<'a>(
+        &mut self,
+        key_path: &mut KeyPath,
+        select_key: Key,
+        selectors: impl Iterator<Item = &'a String>,
+    ) -> Result<()> {
+        self.push_count(key_path, RangeOrPlural::Select, select_key.clone())?;
+        let var_infos = self.variables.entry(select_key).or_default();
+        var_infos.selectors.extend(selectors.cloned());
+        Ok(())
+    }
+
     pub fn iter_keys(&self) -> impl Iterator<Item = &Key> {
-        self.components.iter().chain(self.variables.keys())
+        self.components.keys().chain(self.variables.keys())
     }
 
     pub fn iter_vars(&self) -> impl Iterator<Item = (Key, &VarInfo)> {
@@ -332,8 +425,15 @@ impl InterpolationKeys {
             .map(|(key, value)| (key.clone(), value))
     }
 
-    pub fn iter_comps(&self) -> impl Iterator<Item = Key> + '_ {
-        self.components.iter().cloned()
+    pub fn iter_comps(&self) -> impl Iterator<Item = (Key, ComponentKind)> + '_ {
+        self.components
+            .iter()
+            .map(|(key, kind)| (key.clone(), *kind))
+    }
+
+    /// Return `true` if the component is used with attributes in any locale.
+    pub fn comp_has_attrs(&self, key: &Key) -> bool {
+        self.components_with_attrs.contains(key)
     }
 }
 
@@ -354,31 +454,39 @@ impl Namespace {
     pub fn new(
         locales_dir_path: &mut PathBuf,
         key: Key,
-        locale_keys: &[Key],
+        cfg_file: &ConfigFile,
         foreign_keys_paths: &ForeignKeysPaths,
         warnings: &Warnings,
         tracked_files: &mut Vec<String>,
     ) -> Result<Self> {
-        let mut locales = Vec::with_capacity(locale_keys.len());
-        for locale in locale_keys.iter().cloned() {
-            let file_path: &Path = key.name.as_ref().as_ref();
+        let mut locales = Vec::with_capacity(cfg_file.locales.len());
+        for locale in cfg_file.locales.iter().cloned() {
             locales_dir_path.push(&*locale.name);
-            locales_dir_path.push(file_path);
+            // nested namespaces are in sub directories, "admin/users" is "<locale>/admin/users.json"
+            for segment in key.namespace_segments() {
+                locales_dir_path.push(segment);
+            }
 
-            let locale_file = find_file(locales_dir_path)?;
+            let (locale_file, file_format) = find_file(locales_dir_path)?;
 
+            let message_format = cfg_file.message_format_of(&locale, Some(&key));
             let locale = Locale::new(
                 locale_file,
+                file_format,
                 locales_dir_path,
                 locale,
                 Some(key.clone()),
+                message_format,
+                cfg_file.key_separator.as_deref(),
                 foreign_keys_paths,
                 warnings,
                 tracked_files,
             )?;
 
             locales.push(locale);
-            locales_dir_path.pop();
+            for _ in key.namespace_segments() {
+                locales_dir_path.pop();
+            }
             locales_dir_path.pop();
         }
         Ok(Namespace { key, locales })
@@ -401,7 +509,7 @@ impl LocalesOrNamespaces {
                 namespaces.push(Namespace::new(
                     manifest_dir_path,
                     namespace.clone(),
-                    locale_keys,
+                    cfg_file,
                     foreign_keys_paths,
                     warnings,
                     tracked_files,
@@ -412,12 +520,16 @@ impl LocalesOrNamespaces {
             let mut locales = Vec::with_capacity(locale_keys.len());
             for locale in locale_keys.iter().cloned() {
                 manifest_dir_path.push(&*locale.name);
-                let locale_file = find_file(manifest_dir_path)?;
+                let (locale_file, file_format) = find_file(manifest_dir_path)?;
+                let message_format = cfg_file.message_format_of(&locale, None);
                 let locale = Locale::new(
                     locale_file,
+                    file_format,
                     manifest_dir_path,
                     locale,
                     None,
+                    message_format,
+                    cfg_file.key_separator.as_deref(),
                     foreign_keys_paths,
                     warnings,
                     tracked_files,
@@ -486,11 +598,15 @@ impl LocalesOrNamespaces {
 }
 
 impl Locale {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         locale_file: File,
+        file_format: FileFormat,
         path: &mut PathBuf,
         locale: Key,
         namespace: Option<Key>,
+        message_format: MessageFormat,
+        key_separator: Option<&str>,
         foreign_keys_paths: &ForeignKeysPaths,
         warnings: &Warnings,
         tracked_files: &mut Vec<String>,
@@ -502,14 +618,21 @@ impl Locale {
             top_locale_name: locale,
             key_path: KeyPath::new(namespace),
             foreign_keys_paths,
+            message_format,
+            key_separator,
         };
 
-        Self::de(locale_file, path, seed)
+        Self::de(locale_file, file_format, path, seed)
     }
 
-    fn de(locale_file: File, path: &mut PathBuf, seed: LocaleSeed) -> Result<Self> {
+    fn de(
+        locale_file: File,
+        file_format: FileFormat,
+        path: &mut PathBuf,
+        seed: LocaleSeed,
+    ) -> Result<Self> {
         let reader = BufReader::new(locale_file);
-        de_inner(reader, seed)
+        de_inner(reader, file_format, seed)
             .map_err(|err| Error::LocaleFileDeser {
                 path: std::mem::take(path),
                 err,
@@ -537,17 +660,17 @@ impl Locale {
     pub fn is_possible_plural<'a>(
         key: &'a Key,
         value: &ParsedValue,
-    ) -> Option<(&'a str, PluralRuleType, PluralForm)> {
+    ) -> Option<(&'a str, PluralRuleType, PluralSuffix)> {
         if matches!(value, ParsedValue::Ranges(_) | ParsedValue::Subkeys(_)) {
             return None;
         }
-        let (base_key, suffix) = key.name.rsplit_once('_')?;
+        let (base_key, suffix) = PluralSuffix::split_key(&key.name)?;
         let (base_key, rule_type) = match base_key.strip_suffix("_ordinal") {
             Some(base_key) => (base_key, PluralRuleType::Ordinal),
             None => (base_key, PluralRuleType::Cardinal),
         };
 
-        PluralForm::try_from_str(suffix).map(|form| (base_key, rule_type, form))
+        PluralSuffix::try_from_str(suffix).map(|suffix| (base_key, rule_type, suffix))
     }
 
     pub fn merge_plurals(
@@ -560,7 +683,7 @@ impl Locale {
         #[allow(clippy::type_complexity)]
         let mut possible_plurals: BTreeMap<
             String,
-            BTreeMap<PluralForm, (Key, PluralRuleType, ParsedValue)>,
+            BTreeMap<PluralSuffix, (Key, PluralRuleType, ParsedValue)>,
         > = BTreeMap::new();
         for (key, mut value) in keys {
             if let ParsedValue::Subkeys(Some(subkeys)) = &mut value {
@@ -583,7 +706,8 @@ impl Locale {
                 }
                 continue;
             }
-            let Some((_, rule_type, other)) = plurals.remove(&PluralForm::Other) else {
+            let other = plurals.remove(&PluralSuffix::Form(PluralForm::Other));
+            let Some((_, rule_type, other)) = other else {
                 for (_, (key, _, value)) in plurals {
                     self.keys.insert(key, value);
                 }
@@ -599,23 +723,25 @@ impl Locale {
                 .into());
             }
 
-            let forms = plurals
-                .into_iter()
-                .map(|(form, (_, rule, value))| {
-                    if rule == rule_type {
-                        Ok((form, value))
-                    } else {
-                        Err(Error::ConflictingPluralRuleType {
-                            locale: locale.clone(),
-                            key_path: std::mem::take(key_path),
-                        }
-                        .into())
+            let mut forms = BTreeMap::new();
+            let mut exact = BTreeMap::new();
+            for (suffix, (_, rule, value)) in plurals {
+                if rule != rule_type {
+                    return Err(Error::ConflictingPluralRuleType {
+                        locale: locale.clone(),
+                        key_path: std::mem::take(key_path),
                     }
-                })
-                .collect::<Result<BTreeMap<_, _>>>()?;
+                    .into());
+                }
+                match suffix {
+                    PluralSuffix::Form(form) => forms.insert(form, value),
+                    PluralSuffix::Exact(number) => exact.insert(number, value),
+                };
+            }
             let plural = Plurals {
                 rule_type,
                 forms,
+                exact,
                 count_key: Key::count(),
                 other: Box::new(other),
             };
@@ -725,7 +851,7 @@ impl<'de> serde::de::DeserializeSeed<'de> for LocaleSeed<'_> {
     where
         D: serde::Deserializer<'de>,
     {
-        let keys = deserializer.deserialize_map(self.clone())?;
+        let (keys, metadata) = deserializer.deserialize_map(self.clone())?;
         let Self {
             name,
             top_locale_name,
@@ -734,6 +860,7 @@ impl<'de> serde::de::DeserializeSeed<'de> for LocaleSeed<'_> {
         Ok(Locale {
             name,
             keys,
+            metadata,
             top_locale_name,
             strings: vec![],
             top_locale_string_count: 0,
@@ -741,29 +868,84 @@ impl<'de> serde::de::DeserializeSeed<'de> for LocaleSeed<'_> {
     }
 }
 
+impl LocaleSeed<'_> {
+    fn split_key(&self, key: &str) -> Result<Vec<Key>> {
+        match self.key_separator {
+            Some(key_separator) => key
+                .split(key_separator)
+                .map(|key| Key::try_new(&PluralSuffix::normalize_key(key)))
+                .collect(),
+            None => Key::try_new(&PluralSuffix::normalize_key(key)).map(|key| vec![key]),
+        }
+    }
+}
+
 impl<'de> serde::de::Visitor<'de> for LocaleSeed<'_> {
-    type Value = BTreeMap<Key, ParsedValue>;
+    type Value = (BTreeMap<Key, ParsedValue>, BTreeMap<Key, KeyMetadata>);
 
     fn visit_map<A>(mut self, mut map: A) -> Result<Self::Value, A::Error>
     where
         A: MapAccess<'de>,
     {
         let mut keys = BTreeMap::new();
+        let mut metadata_entries = vec![];
 
-        while let Some(locale_key) = map.next_key::<Key>()? {
-            self.key_path.push_key(locale_key.clone());
+        while let Some(raw_key) = map.next_key::<String>()? {
+            if KeyMetadata::is_global_attribute(&raw_key) {
+                map.next_value::<serde::de::IgnoredAny>()?;
+                continue;
+            }
+            if let Some(described_key) = KeyMetadata::strip_prefix(&raw_key) {
+                let path = self
+                    .split_key(described_key)
+                    .map_err(serde::de::Error::custom)?;
+                metadata_entries.push((path, map.next_value::<KeyMetadata>()?));
+                continue;
+            }
+            let path = self.split_key(&raw_key).map_err(serde::de::Error::custom)?;
+            let locale_key = path.last().unwrap_at("LocaleSeed::visit_map_1");
+            for key in &path {
+                self.key_path.push_key(key.clone());
+            }
             let value = map.next_value_seed(ParsedValueSeed {
                 top_locale_name: &self.top_locale_name,
-                key: &locale_key,
+                key: locale_key,
                 key_path: &self.key_path,
                 in_range: false,
                 foreign_keys_paths: self.foreign_keys_paths,
+                message_format: self.message_format,
+                key_separator: self.key_separator,
             })?;
-            self.key_path.pop_key();
-            keys.insert(locale_key, value);
+            for _ in &path {
+                self.key_path.pop_key();
+            }
+            if self.key_separator.is_some() {
+                insert_flattened(
+                    &mut keys,
+                    &mut self.key_path,
+                    &path,
+                    value,
+                    &self.top_locale_name,
+                )
+                .map_err(serde::de::Error::custom)?;
+            } else {
+                keys.insert(locale_key.clone(), value);
+            }
         }
 
-        Ok(keys)
+        let mut metadata = BTreeMap::new();
+        for (path, key_metadata) in metadata_entries {
+            insert_metadata(
+                &mut keys,
+                &mut metadata,
+                &mut self.key_path,
+                &path,
+                key_metadata,
+            )
+            .map_err(serde::de::Error::custom)?;
+        }
+
+        Ok((keys, metadata))
     }
 
     fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -774,6 +956,86 @@ impl<'de> serde::de::Visitor<'de> for LocaleSeed<'_> {
     }
 }
 
+// Insert a value at the path of a flattened key, creating the intermediate subkeys.
+fn insert_flattened(
+    keys: &mut BTreeMap<Key, ParsedValue>,
+    key_path: &mut KeyPath,
+    path: &[Key],
+    value: ParsedValue,
+    top_locale_name: &Key,
+) -> Result<()> {
+    let Some((key, rest)) = path.split_first() else {
+        return Ok(());
+    };
+    key_path.push_key(key.clone());
+    let result = match keys.entry(key.clone()) {
+        Entry::Vacant(entry) if rest.is_empty() => {
+            entry.insert(value);
+            Ok(())
+        }
+        Entry::Vacant(entry) => {
+            let mut subkeys = BTreeMap::new();
+            let result = insert_flattened(&mut subkeys, key_path, rest, value, top_locale_name);
+            entry.insert(ParsedValue::Subkeys(Some(Locale {
+                top_locale_name: top_locale_name.clone(),
+                name: key.clone(),
+                keys: subkeys,
+                metadata: BTreeMap::new(),
+                strings: vec![],
+                top_locale_string_count: 0,
+            })));
+            result
+        }
+        Entry::Occupied(mut entry) => match (entry.get_mut(), value) {
+            (ParsedValue::Subkeys(Some(locale)), ParsedValue::Subkeys(Some(other)))
+                if rest.is_empty() =>
+            {
+                locale.metadata.extend(other.metadata);
+                other.keys.into_iter().try_for_each(|(key, value)| {
+                    insert_flattened(&mut locale.keys, key_path, &[key], value, top_locale_name)
+                })
+            }
+            (ParsedValue::Subkeys(Some(locale)), value) if !rest.is_empty() => {
+                insert_flattened(&mut locale.keys, key_path, rest, value, top_locale_name)
+            }
+            _ => Err(Error::FlatKeyCollision(key_path.clone()).into()),
+        },
+    };
+    key_path.pop_key();
+    result
+}
+
+// Attach the metadata of a `"@key"` entry to the subkeys containing the described key.
+fn insert_metadata(
+    keys: &mut BTreeMap<Key, ParsedValue>,
+    metadata: &mut BTreeMap<Key, KeyMetadata>,
+    key_path: &mut KeyPath,
+    path: &[Key],
+    key_metadata: KeyMetadata,
+) -> Result<()> {
+    let Some((key, rest)) = path.split_first() else {
+        return Ok(());
+    };
+    if rest.is_empty() {
+        // The described key is not checked, plurals forms are only merged later on.
+        metadata.insert(key.clone(), key_metadata);
+        return Ok(());
+    }
+    key_path.push_key(key.clone());
+    let result = match keys.get_mut(key) {
+        Some(ParsedValue::Subkeys(Some(locale))) => insert_metadata(
+            &mut locale.keys,
+            &mut locale.metadata,
+            key_path,
+            rest,
+            key_metadata,
+        ),
+        _ => Err(Error::UnknownMetadataKey(key_path.clone()).into()),
+    };
+    key_path.pop_key();
+    result
+}
+
 fn track_file(
     tracked_files: &mut Vec<String>,
     locale: &Key,
@@ -791,3 +1053,77 @@ fn track_file(
         });
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_locales::parsed_value::Literal;
+
+    fn new_key(key: &str) -> Key {
+        Key::new(key).unwrap()
+    }
+
+    fn parse_flattened(source: &str) -> Result<Locale, SerdeError> {
+        let _guard = crate::utils::formatter::SkipIcuCfgGuard::new(true);
+        let foreign_keys_paths = ForeignKeysPaths::new();
+        let seed = LocaleSeed {
+            name: new_key("en"),
+            top_locale_name: new_key("en"),
+            key_path: KeyPath::new(None),
+            foreign_keys_paths: &foreign_keys_paths,
+            message_format: Default::default(),
+            key_separator: Some("."),
+        };
+        de_inner_json(source.as_bytes(), seed)
+    }
+
+    #[test]
+    fn flattened_keys() {
+        let locale = parse_flattened(
+            r#"{ "settings.profile.title": "Profile", "settings": { "theme": "Theme" } }"#,
+        )
+        .unwrap();
+        let title =
+            locale.get_value_at(&[new_key("settings"), new_key("profile"), new_key("title")]);
+        assert_eq!(
+            title,
+            Some(&ParsedValue::Literal(Literal::String(
+                "Profile".to_string(),
+                usize::MAX
+            )))
+        );
+        let theme = locale.get_value_at(&[new_key("settings"), new_key("theme")]);
+        assert!(theme.is_some());
+    }
+
+    #[test]
+    fn flattened_keys_collision() {
+        let err =
+            parse_flattened(r#"{ "settings.title": "Title", "settings": { "title": "Title" } }"#)
+                .unwrap_err();
+        assert!(err.to_string().contains("settings.title"));
+
+        let err = parse_flattened(r#"{ "settings": "Settings", "settings.title": "Title" }"#)
+            .unwrap_err();
+        assert!(err.to_string().contains("settings"));
+    }
+
+    #[test]
+    fn exact_plurals() {
+        let mut locale = parse_flattened(
+            r#"{ "items_=0": "none", "items_one_exact": "single", "items_one": "one", "items_other": "many" }"#,
+        )
+        .unwrap();
+        let _guard = crate::utils::formatter::SkipIcuCfgGuard::new(true);
+        let warnings = Warnings::new();
+        locale
+            .merge_plurals(new_key("en"), &mut KeyPath::new(None), &warnings)
+            .unwrap();
+        let Some(ParsedValue::Plurals(plurals)) = locale.keys.get(&new_key("items")) else {
+            panic!("expected plurals");
+        };
+        assert_eq!(plurals.exact.keys().copied().collect::<Vec<_>>(), [0, 1]);
+        assert!(plurals.forms.contains_key(&PluralForm::One));
+        assert_eq!(locale.keys.len(), 1);
+    }
+}
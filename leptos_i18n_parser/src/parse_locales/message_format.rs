@@ -0,0 +1,661 @@
+use std::collections::BTreeMap;
+
+use super::{
+    error::{Error, Result},
+    parsed_value::{Literal, ParsedValue},
+    plurals::{PluralForm, PluralRuleType, Plurals},
+    ranges::{Range, RangeNumber, Ranges, RangesInner, UntypedRangesInner},
+    select::Select,
+};
+use crate::utils::{
+    formatter::{Formatter, FormatterError, SKIP_ICU_CFG},
+    Key, KeyPath,
+};
+
+/// Parse a string using the ICU MessageFormat syntax.
+///
+/// Arguments are mapped to variables and formatters, `plural` and `selectordinal` to plurals,
+/// with exact selectors (`=0`) as exact overrides, or to ranges if they only use exact selectors,
+/// and `select` to selects.
+/// `<tag>..</tag>` are parsed as components.
+pub fn parse_message_format(value: &str, key_path: &KeyPath, locale: &Key) -> Result<ParsedValue> {
+    let mut parser = MessageParser {
+        source: value,
+        pos: 0,
+        key_path,
+        locale,
+    };
+    parser.parse_message(None, None, false)
+}
+
+struct MessageParser<'a> {
+    source: &'a str,
+    pos: usize,
+    key_path: &'a KeyPath,
+    locale: &'a Key,
+}
+
+fn push_text(values: &mut Vec<ParsedValue>, text: &mut String) {
+    if !text.is_empty() {
+        let text = std::mem::take(text);
+        values.push(ParsedValue::Literal(Literal::String(text, usize::MAX)));
+    }
+}
+
+fn is_tag_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '-'
+}
+
+fn new_ranges<T: RangeNumber>(
+    options: Vec<(&str, ParsedValue)>,
+    other: ParsedValue,
+) -> Result<RangesInner<T>> {
+    let mut ranges = Vec::with_capacity(options.len() + 1);
+    for (selector, value) in options {
+        ranges.push((Range::new(selector)?, value));
+    }
+    ranges.push((Range::Fallback, other));
+    Ok(ranges)
+}
+
+impl<'a> MessageParser<'a> {
+    fn error(&self, message: impl ToString) -> Box<Error> {
+        Box::new(Error::InvalidMessageFormat {
+            locale: self.locale.clone(),
+            key_path: self.key_path.clone(),
+            message: message.to_string(),
+        })
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.source[self.pos..]
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn skip_whitespaces(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn expect(&mut self, expected: char) -> Result<()> {
+        match self.bump() {
+            Some(c) if c == expected => Ok(()),
+            Some(c) => Err(self.error(format!("expected {:?}, found {:?}", expected, c))),
+            None => Err(self.error(format!("expected {:?}, found end of string", expected))),
+        }
+    }
+
+    fn read_word(&mut self) -> &'a str {
+        let rest = self.rest();
+        let len = rest
+            .find(|c: char| c.is_whitespace() || matches!(c, ',' | '{' | '}'))
+            .unwrap_or(rest.len());
+        self.pos += len;
+        &rest[..len]
+    }
+
+    // Return the name of the tag if the rest start with `<name>` or `</name>`.
+    fn tag_ahead(&self, closing: bool) -> Option<&'a str> {
+        let rest = self.rest().strip_prefix('<')?;
+        let rest = if closing {
+            rest.strip_prefix('/')?
+        } else {
+            rest
+        };
+        let (name, _) = rest.split_once('>')?;
+        let valid =
+            name.chars().next().is_some_and(char::is_alphabetic) && name.chars().all(is_tag_char);
+        valid.then_some(name)
+    }
+
+    // Apostrophes quote special characters, `''` is a literal apostrophe.
+    fn parse_apostrophe(&mut self, text: &mut String, in_plural: bool) {
+        self.bump();
+        match self.peek() {
+            Some('\'') => {
+                self.bump();
+                text.push('\'');
+            }
+            Some('{' | '}' | '<') => self.parse_quoted(text),
+            Some('#') if in_plural => self.parse_quoted(text),
+            _ => text.push('\''),
+        }
+    }
+
+    fn parse_quoted(&mut self, text: &mut String) {
+        while let Some(c) = self.bump() {
+            if c != '\'' {
+                text.push(c);
+            } else if self.peek() == Some('\'') {
+                self.bump();
+                text.push('\'');
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn parse_message(
+        &mut self,
+        plural_key: Option<&Key>,
+        tag: Option<&str>,
+        nested: bool,
+    ) -> Result<ParsedValue> {
+        let mut values = vec![];
+        let mut text = String::new();
+        loop {
+            let Some(c) = self.peek() else {
+                if let Some(tag) = tag {
+                    return Err(self.error(format!("unclosed tag <{}>", tag)));
+                } else if nested {
+                    return Err(self.error("expected '}', found end of string"));
+                }
+                break;
+            };
+            match c {
+                '\'' => self.parse_apostrophe(&mut text, plural_key.is_some()),
+                '{' => {
+                    push_text(&mut values, &mut text);
+                    values.push(self.parse_argument()?);
+                }
+                '}' if nested => break,
+                '}' => return Err(self.error("unmatched '}'")),
+                '#' if plural_key.is_some() => {
+                    self.bump();
+                    push_text(&mut values, &mut text);
+                    values.push(ParsedValue::Variable {
+                        key: plural_key.cloned().unwrap_or_else(Key::count),
+                        formatter: Formatter::None,
+                    });
+                }
+                '<' => {
+                    if let Some(name) = self.tag_ahead(true) {
+                        if tag == Some(name) {
+                            break;
+                        }
+                        return Err(self.error(format!("unexpected closing tag </{}>", name)));
+                    }
+                    let Some(name) = self.tag_ahead(false) else {
+                        self.bump();
+                        text.push('<');
+                        continue;
+                    };
+                    push_text(&mut values, &mut text);
+                    values.push(self.parse_tag(name, plural_key, nested)?);
+                }
+                c => {
+                    self.bump();
+                    text.push(c);
+                }
+            }
+        }
+        push_text(&mut values, &mut text);
+        match values.len() {
+            0 => Ok(ParsedValue::default()),
+            1 => Ok(values.pop().unwrap_or_default()),
+            _ => Ok(ParsedValue::Bloc(values)),
+        }
+    }
+
+    fn parse_tag(
+        &mut self,
+        name: &'a str,
+        plural_key: Option<&Key>,
+        nested: bool,
+    ) -> Result<ParsedValue> {
+        let key = Key::new(&format!("comp_{}", name))
+            .ok_or_else(|| self.error(format!("invalid tag name {:?}", name)))?;
+        // skip `<name>`
+        self.pos += name.len() + 2;
+        let inner = self.parse_message(plural_key, Some(name), nested)?;
+        // a nested message can stop on its closing brace before the tag is closed
+        if self.tag_ahead(true) != Some(name) {
+            return Err(self.error(format!("unclosed tag <{}>", name)));
+        }
+        // skip `</name>`
+        self.pos += name.len() + 3;
+        Ok(ParsedValue::Component {
+            key,
+            inner: Some(Box::new(inner)),
+            attrs: vec![],
+        })
+    }
+
+    fn parse_argument(&mut self) -> Result<ParsedValue> {
+        self.expect('{')?;
+        self.skip_whitespaces();
+        let name = self.read_word();
+        let key = Key::new(&format!("var_{}", name))
+            .ok_or_else(|| self.error(format!("invalid argument name {:?}", name)))?;
+        self.skip_whitespaces();
+        if self.peek() == Some('}') {
+            self.bump();
+            return Ok(ParsedValue::Variable {
+                key,
+                formatter: Formatter::None,
+            });
+        }
+        self.expect(',')?;
+        self.skip_whitespaces();
+        let arg_type = self.read_word();
+        self.skip_whitespaces();
+        match arg_type {
+            "plural" => {
+                self.expect(',')?;
+                self.parse_plural(key, PluralRuleType::Cardinal)
+            }
+            "selectordinal" => {
+                self.expect(',')?;
+                self.parse_plural(key, PluralRuleType::Ordinal)
+            }
+            "select" => {
+                self.expect(',')?;
+                self.parse_select(key)
+            }
+            _ => {
+                let style = if self.peek() == Some(',') {
+                    self.bump();
+                    let rest = self.rest();
+                    let len = rest.find('}').unwrap_or(rest.len());
+                    self.pos += len;
+                    Some(rest[..len].trim())
+                } else {
+                    None
+                };
+                self.expect('}')?;
+                let formatter = self.parse_formatter(arg_type, style)?;
+                Ok(ParsedValue::Variable { key, formatter })
+            }
+        }
+    }
+
+    fn parse_formatter(&self, arg_type: &str, style: Option<&str>) -> Result<Formatter> {
+        let mut args: Vec<(&str, &str)> = vec![];
+        let unsupported_style =
+            |style: &str| self.error(format!("unsupported {} style {:?}", arg_type, style));
+        let name = match (arg_type, style) {
+            ("number", None) => "number",
+            ("number", Some("currency")) => "currency",
+            ("number", Some("percent")) => "percent",
+            ("number", Some(style)) => {
+                let Some(skeleton) = style.strip_prefix("::") else {
+                    return Err(unsupported_style(style));
+                };
+                let mut name = "number";
+                for token in skeleton.split_whitespace() {
+                    match token {
+                        "group-off" | ",_" => args.push(("grouping_strategy", "never")),
+                        "group-min2" | ",?" => args.push(("grouping_strategy", "min2")),
+                        "group-on-aligned" | ",!" => args.push(("grouping_strategy", "always")),
+                        "group-auto" => args.push(("grouping_strategy", "auto")),
+                        "unit-width-narrow" => args.push(("width", "narrow")),
+                        "unit-width-short" => args.push(("width", "short")),
+                        "compact-short" | "K" => args.push(("notation", "compact")),
+                        "compact-long" | "KK" => {
+                            args.push(("notation", "compact"));
+                            args.push(("compact_display", "long"));
+                        }
+                        token => match token.strip_prefix("currency/") {
+                            Some(currency_code) => {
+                                name = "currency";
+                                args.push(("currency_code", currency_code));
+                            }
+                            None => return Err(unsupported_style(token)),
+                        },
+                    }
+                }
+                name
+            }
+            ("date" | "time" | "datetime", style) => {
+                if let Some(skeleton) = style.and_then(|style| style.strip_prefix("::")) {
+                    args.push(("skeleton", skeleton));
+                } else if let Some(style) = style {
+                    if !matches!(style, "short" | "medium" | "long" | "full") {
+                        return Err(unsupported_style(style));
+                    }
+                    match arg_type {
+                        "date" => args.push(("date_length", style)),
+                        "time" => args.push(("time_length", style)),
+                        _ => {
+                            args.push(("date_length", style));
+                            args.push(("time_length", style));
+                        }
+                    }
+                }
+                arg_type
+            }
+            _ => {
+                return Err(Box::new(Error::UnknownFormatter {
+                    name: arg_type.to_string(),
+                    locale: self.locale.clone(),
+                    key_path: self.key_path.clone(),
+                }))
+            }
+        };
+        match Formatter::from_name_and_args(name, Some(&args)) {
+            Ok(Some(formatter)) => Ok(formatter),
+            Ok(None) => unreachable!("known formatter {:?} not recognized.", name),
+            Err(FormatterError::Disabled(formatter)) => Err(Box::new(Error::DisabledFormatter {
+                locale: self.locale.clone(),
+                key_path: self.key_path.clone(),
+                formatter,
+            })),
+            Err(FormatterError::InvalidArgs(message)) => {
+                Err(Box::new(Error::InvalidFormatterArgs {
+                    locale: self.locale.clone(),
+                    key_path: self.key_path.clone(),
+                    message,
+                }))
+            }
+        }
+    }
+
+    // Parse the `selector {message}` options until the closing brace.
+    fn parse_options(&mut self, plural_key: Option<&Key>) -> Result<Vec<(&'a str, ParsedValue)>> {
+        let mut options: Vec<(&'a str, ParsedValue)> = vec![];
+        loop {
+            self.skip_whitespaces();
+            match self.peek() {
+                Some('}') => {
+                    self.bump();
+                    break;
+                }
+                None => return Err(self.error("expected '}', found end of string")),
+                _ => {}
+            }
+            let selector = self.read_word();
+            if selector.is_empty() {
+                return Err(self.error("expected a selector"));
+            } else if selector.starts_with("offset:") {
+                return Err(self.error("plural offsets are not supported"));
+            } else if options.iter().any(|(s, _)| *s == selector) {
+                return Err(self.error(format!("duplicate selector {:?}", selector)));
+            }
+            self.skip_whitespaces();
+            self.expect('{')?;
+            let value = self.parse_message(plural_key, None, true)?;
+            self.expect('}')?;
+            options.push((selector, value));
+        }
+        Ok(options)
+    }
+
+    fn parse_select(&mut self, key: Key) -> Result<ParsedValue> {
+        let options = self.parse_options(None)?;
+        Select::new(key, options)
+            .map(ParsedValue::Select)
+            .map_err(|err| self.error(err))
+    }
+
+    fn parse_plural(&mut self, key: Key, rule_type: PluralRuleType) -> Result<ParsedValue> {
+        let mut options = self.parse_options(Some(&key))?;
+
+        let Some(other_index) = options.iter().position(|(s, _)| *s == "other") else {
+            return Err(self.error("plural arguments require an \"other\" option"));
+        };
+        let (_, other) = options.remove(other_index);
+
+        // only exact selectors: no plural rules are needed, use ranges.
+        let only_exact = !options.is_empty() && options.iter().all(|(s, _)| s.starts_with('='));
+        if only_exact {
+            let exact_options = options
+                .into_iter()
+                .map(|(selector, value)| (&selector[1..], value))
+                .collect::<Vec<_>>();
+            let is_float = exact_options.iter().any(|(n, _)| n.contains('.'));
+            let inner = if is_float {
+                UntypedRangesInner::F64(new_ranges(exact_options, other)?)
+            } else {
+                UntypedRangesInner::I32(new_ranges(exact_options, other)?)
+            };
+            return Ok(ParsedValue::Ranges(Ranges {
+                count_key: key,
+                inner,
+            }));
+        }
+
+        if !cfg!(feature = "plurals") && !SKIP_ICU_CFG.get() {
+            return Err(Box::new(Error::DisabledPlurals {
+                locale: self.locale.clone(),
+                key_path: self.key_path.clone(),
+            }));
+        }
+        let mut forms = BTreeMap::new();
+        let mut exact = BTreeMap::new();
+        for (selector, value) in options {
+            if let Some(number) = selector.strip_prefix('=') {
+                let number = number.parse().map_err(|_| {
+                    self.error(format!(
+                        "invalid exact selector {:?}, only non negative integers can be mixed with plural categories",
+                        selector
+                    ))
+                })?;
+                exact.insert(number, value);
+                continue;
+            }
+            let Some(form) = PluralForm::try_from_str(selector) else {
+                return Err(self.error(format!(
+                    "unknown plural category {:?}, expected one of zero, one, two, few, many or other",
+                    selector
+                )));
+            };
+            forms.insert(form, value);
+        }
+        Ok(ParsedValue::Plurals(Plurals {
+            rule_type,
+            count_key: key,
+            other: Box::new(other),
+            forms,
+            exact,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::formatter::{
+        CompactDisplay, ComponentMonth, ComponentNumeric, CurrencyCode, CurrencyWidth, DateLength,
+        DateTimeComponents, DateTimeKind, GroupingStrategy, HourCycle, NumberPrecision,
+    };
+
+    fn new_key(key: &str) -> Key {
+        Key::new(key).unwrap()
+    }
+
+    fn parse(value: &str) -> Result<ParsedValue> {
+        let _guard = crate::utils::formatter::SkipIcuCfgGuard::new(true);
+        parse_message_format(value, &KeyPath::new(None), &new_key("en"))
+    }
+
+    fn lit(s: &str) -> ParsedValue {
+        ParsedValue::Literal(Literal::String(s.to_string(), usize::MAX))
+    }
+
+    fn var(name: &str, formatter: Formatter) -> ParsedValue {
+        ParsedValue::Variable {
+            key: new_key(&format!("var_{}", name)),
+            formatter,
+        }
+    }
+
+    #[test]
+    fn parse_arguments() {
+        let value = parse("Hello {name}, you have {n, number, ::group-off} new messages").unwrap();
+        assert_eq!(
+            value,
+            ParsedValue::Bloc(vec![
+                lit("Hello "),
+                var("name", Formatter::None),
+                lit(", you have "),
+                var(
+                    "n",
+                    Formatter::Number(GroupingStrategy::Never, NumberPrecision::default())
+                ),
+                lit(" new messages"),
+            ])
+        );
+        let value = parse("{d, date, long} {p, number, ::currency/EUR}").unwrap();
+        assert_eq!(
+            value,
+            ParsedValue::Bloc(vec![
+                var("d", Formatter::Date(DateLength::Long)),
+                lit(" "),
+                var(
+                    "p",
+                    Formatter::Currency(
+                        CurrencyWidth::Short,
+                        CurrencyCode(tinystr::tinystr!(3, "EUR")),
+                        NumberPrecision::default()
+                    )
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    fn parse_number_styles() {
+        let value =
+            parse("{a, number, percent} {b, number, ::compact-long} {c, number, ::K}").unwrap();
+        assert_eq!(
+            value,
+            ParsedValue::Bloc(vec![
+                var("a", Formatter::Percent(GroupingStrategy::Auto)),
+                lit(" "),
+                var("b", Formatter::CompactNumber(CompactDisplay::Long)),
+                lit(" "),
+                var("c", Formatter::CompactNumber(CompactDisplay::Short)),
+            ])
+        );
+    }
+
+    #[test]
+    fn parse_date_skeletons() {
+        let value = parse("{d, date, ::MMMd} {t, time, ::Hms}").unwrap();
+        let date = DateTimeComponents {
+            month: Some(ComponentMonth::Short),
+            day: Some(ComponentNumeric::Numeric),
+            ..Default::default()
+        };
+        let time = DateTimeComponents {
+            hour: Some(ComponentNumeric::Numeric),
+            minute: Some(ComponentNumeric::Numeric),
+            second: Some(ComponentNumeric::Numeric),
+            hour_cycle: Some(HourCycle::H23),
+            ..Default::default()
+        };
+        assert_eq!(
+            value,
+            ParsedValue::Bloc(vec![
+                var("d", Formatter::DateTimeComponents(DateTimeKind::Date, date)),
+                lit(" "),
+                var("t", Formatter::DateTimeComponents(DateTimeKind::Time, time)),
+            ])
+        );
+        assert_eq!(
+            Formatter::DateTimeComponents(DateTimeKind::Date, date).to_string(),
+            "date(month: short; day: numeric)"
+        );
+
+        assert!(parse("{d, date, ::MMMdX}").is_err());
+        assert!(parse("{d, date, ::dd d}").is_err());
+        assert!(parse("{d, date, ::Hm}").is_err());
+    }
+
+    #[test]
+    fn parse_apostrophes() {
+        assert_eq!(parse("It''s '{literal}'").unwrap(), lit("It's {literal}"));
+        assert_eq!(parse("l'été").unwrap(), lit("l'été"));
+    }
+
+    #[test]
+    fn parse_plural() {
+        let value = parse("{count, plural, one {# item} other {<b>#</b> items}}").unwrap();
+        let ParsedValue::Plurals(plurals) = value else {
+            panic!("expected plurals, got {:?}", value);
+        };
+        assert_eq!(plurals.rule_type, PluralRuleType::Cardinal);
+        assert_eq!(plurals.count_key, Key::count());
+        assert_eq!(
+            plurals.forms[&PluralForm::One],
+            ParsedValue::Bloc(vec![var("count", Formatter::None), lit(" item")])
+        );
+        assert_eq!(
+            *plurals.other,
+            ParsedValue::Bloc(vec![
+                ParsedValue::Component {
+                    key: new_key("comp_b"),
+                    inner: Some(Box::new(var("count", Formatter::None))),
+                    attrs: vec![],
+                },
+                lit(" items"),
+            ])
+        );
+    }
+
+    #[test]
+    fn parse_exact_selectors() {
+        let value = parse("{n, plural, =0 {none} =1 {one} other {many}}").unwrap();
+        let ParsedValue::Ranges(ranges) = value else {
+            panic!("expected ranges, got {:?}", value);
+        };
+        assert_eq!(ranges.count_key, new_key("var_n"));
+        assert_eq!(
+            ranges.inner,
+            UntypedRangesInner::I32(vec![
+                (Range::Exact(0), lit("none")),
+                (Range::Exact(1), lit("one")),
+                (Range::Fallback, lit("many")),
+            ])
+        );
+    }
+
+    #[test]
+    fn parse_mixed_selectors() {
+        let value = parse("{n, plural, =0 {none} one {# item} other {# items}}").unwrap();
+        let ParsedValue::Plurals(plurals) = value else {
+            panic!("expected plurals, got {:?}", value);
+        };
+        assert_eq!(plurals.count_key, new_key("var_n"));
+        assert_eq!(plurals.exact[&0], lit("none"));
+        assert_eq!(
+            plurals.forms[&PluralForm::One],
+            ParsedValue::Bloc(vec![var("n", Formatter::None), lit(" item")])
+        );
+        assert!(parse("{n, plural, =0.5 {half} one {one} other {many}}").is_err());
+    }
+
+    #[test]
+    fn parse_select() {
+        let value = parse("{gender, select, male {He} female {She} other {They}} replied").unwrap();
+        let ParsedValue::Bloc(values) = value else {
+            panic!("expected a bloc, got {:?}", value);
+        };
+        let ParsedValue::Select(select) = &values[0] else {
+            panic!("expected a select, got {:?}", values[0]);
+        };
+        assert_eq!(select.select_key, new_key("var_gender"));
+        assert_eq!(select.arms["male"], lit("He"));
+        assert_eq!(select.arms["female"], lit("She"));
+        assert_eq!(*select.other, lit("They"));
+        assert!(parse("{gender, select, male {He}}").is_err());
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert!(parse("{n, plural, one {item}}").is_err());
+        assert!(parse("{n, number").is_err());
+        assert!(parse("<b>unclosed").is_err());
+        assert!(parse("{n, plural, other {<b>x}}").is_err());
+        assert!(parse("unmatched }").is_err());
+    }
+}
@@ -0,0 +1,280 @@
+use std::{collections::BTreeMap, convert::Infallible};
+
+use serde::de::{IgnoredAny, MapAccess, Visitor};
+
+use super::{
+    locale::{Locale, LocalesOrNamespaces},
+    parsed_value::{ForeignKey, Literal, ParsedValue},
+    warning::{Warning, Warnings},
+};
+use crate::utils::{Key, KeyPath};
+
+/// Prefix of the keys holding the metadata of another key, `"@key"` describes `"key"`.
+pub const METADATA_PREFIX: char = '@';
+
+/// Informations for translators attached to a key, declared with an ARB-style `"@key"` entry.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct KeyMetadata {
+    /// What the key means and where it is used.
+    pub description: Option<String>,
+    /// Maximum number of characters of the translations, a warning is emitted if a translation is longer.
+    pub max_length: Option<usize>,
+    /// Path or URL to a screenshot showing the key in context.
+    pub screenshot: Option<String>,
+}
+
+impl KeyMetadata {
+    /// Return the key described by a metadata entry, `None` if the entry is a regular key.
+    pub fn strip_prefix(key: &str) -> Option<&str> {
+        key.strip_prefix(METADATA_PREFIX)
+    }
+
+    /// ARB global attributes such as `"@@locale"` describe the file and not a key, they are ignored.
+    pub fn is_global_attribute(key: &str) -> bool {
+        key.starts_with("@@")
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for KeyMetadata {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_map(KeyMetadataVisitor)
+    }
+}
+
+struct KeyMetadataVisitor;
+
+impl<'de> Visitor<'de> for KeyMetadataVisitor {
+    type Value = KeyMetadata;
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut metadata = KeyMetadata::default();
+        while let Some(field) = map.next_key::<String>()? {
+            // the `@` prefix is optional for the fields, `{ "@description": "..." }` is also accepted.
+            match field.strip_prefix(METADATA_PREFIX).unwrap_or(&field) {
+                "description" => metadata.description = Some(map.next_value()?),
+                "maxLength" => metadata.max_length = Some(map.next_value()?),
+                "screenshot" => metadata.screenshot = Some(map.next_value()?),
+                // ARB files can contain other fields (type, placeholders, ...), they are not used.
+                _ => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+        Ok(metadata)
+    }
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            formatter,
+            "a map with optional \"description\", \"maxLength\" and \"screenshot\" fields"
+        )
+    }
+}
+
+// Length of the static text of a value, the longest branch is taken for plurals, ranges and selects.
+fn text_length(value: &ParsedValue) -> usize {
+    match value {
+        ParsedValue::Literal(Literal::String(s, _)) => s.chars().count(),
+        ParsedValue::Default
+        | ParsedValue::Literal(_)
+        | ParsedValue::Variable { .. }
+        | ParsedValue::Subkeys(_) => 0,
+        ParsedValue::Component { inner, .. } => inner.as_deref().map_or(0, text_length),
+        ParsedValue::Bloc(values) => values.iter().map(text_length).sum(),
+        ParsedValue::ForeignKey(foreign_key) => match &*foreign_key.borrow() {
+            ForeignKey::Set(value) => text_length(value),
+            ForeignKey::NotSet(..) => 0,
+        },
+        ParsedValue::Ranges(ranges) => {
+            let mut max = 0;
+            let _ = ranges.try_for_each_value(|value| {
+                max = max.max(text_length(value));
+                Ok::<_, Infallible>(())
+            });
+            max
+        }
+        ParsedValue::Plurals(plurals) => plurals.values().map(text_length).max().unwrap_or(0),
+        ParsedValue::Select(select) => select.values().map(text_length).max().unwrap_or(0),
+    }
+}
+
+fn check_max_lengths_inner(
+    locale: &Locale,
+    default_locale: Option<&Locale>,
+    key_path: &mut KeyPath,
+    warnings: &Warnings,
+) {
+    for (key, value) in &locale.keys {
+        key_path.push_key(key.clone());
+        let default_value = default_locale.and_then(|locale| locale.keys.get(key));
+        match value {
+            ParsedValue::Subkeys(Some(subkeys)) => {
+                let default_subkeys = match default_value {
+                    Some(ParsedValue::Subkeys(Some(subkeys))) => Some(subkeys),
+                    _ => None,
+                };
+                check_max_lengths_inner(subkeys, default_subkeys, key_path, warnings);
+            }
+            value => {
+                // a limit declared in a locale overrides the one of the default locale.
+                let max_length = locale
+                    .metadata
+                    .get(key)
+                    .and_then(|metadata| metadata.max_length)
+                    .or_else(|| default_locale?.metadata.get(key)?.max_length);
+                let length = text_length(value);
+                if let Some(max_length) = max_length.filter(|max| length > *max) {
+                    warnings.emit_warning(Warning::TranslationTooLong {
+                        locale: locale.top_locale_name.clone(),
+                        key_path: key_path.clone(),
+                        length,
+                        max_length,
+                    });
+                }
+            }
+        }
+        key_path.pop_key();
+    }
+}
+
+fn check_locales_max_lengths(locales: &[Locale], namespace: Option<&Key>, warnings: &Warnings) {
+    let default_locale = locales.first();
+    for locale in locales {
+        let mut key_path = KeyPath::new(namespace.cloned());
+        check_max_lengths_inner(locale, default_locale, &mut key_path, warnings);
+    }
+}
+
+/// Emit a warning for each translation longer than the `maxLength` declared for its key.
+pub fn check_max_lengths(locales: &LocalesOrNamespaces, warnings: &Warnings) {
+    match locales {
+        LocalesOrNamespaces::NameSpaces(namespaces) => {
+            for namespace in namespaces {
+                check_locales_max_lengths(&namespace.locales, Some(&namespace.key), warnings);
+            }
+        }
+        LocalesOrNamespaces::Locales(locales) => check_locales_max_lengths(locales, None, warnings),
+    }
+}
+
+/// Collect the metadata of the keys of a locale, indexed by key path.
+pub fn collect_metadata<'a>(
+    locale: &'a Locale,
+    key_path: &mut KeyPath,
+    output: &mut BTreeMap<KeyPath, &'a KeyMetadata>,
+) {
+    for (key, metadata) in &locale.metadata {
+        key_path.push_key(key.clone());
+        output.insert(key_path.clone(), metadata);
+        key_path.pop_key();
+    }
+    for (key, value) in &locale.keys {
+        if let ParsedValue::Subkeys(Some(subkeys)) = value {
+            key_path.push_key(key.clone());
+            collect_metadata(subkeys, key_path, output);
+            key_path.pop_key();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_locales::{locale::LocaleSeed, ForeignKeysPaths};
+
+    fn new_key(key: &str) -> Key {
+        Key::new(key).unwrap()
+    }
+
+    fn parse_locale(locale: &str, source: &str, key_separator: Option<&str>) -> Locale {
+        let _guard = crate::utils::formatter::SkipIcuCfgGuard::new(true);
+        let foreign_keys_paths = ForeignKeysPaths::new();
+        let seed = LocaleSeed {
+            name: new_key(locale),
+            top_locale_name: new_key(locale),
+            key_path: KeyPath::new(None),
+            foreign_keys_paths: &foreign_keys_paths,
+            message_format: Default::default(),
+            key_separator,
+        };
+        let mut deserializer = serde_json::Deserializer::from_str(source);
+        serde::de::DeserializeSeed::deserialize(seed, &mut deserializer).unwrap()
+    }
+
+    #[test]
+    fn metadata_entries() {
+        let locale = parse_locale(
+            "en",
+            r#"{
+                "@@locale": "en",
+                "title": "Profile",
+                "@title": { "description": "Page title", "maxLength": 10, "type": "text" },
+                "sub": { "@key": { "@description": "Nested" }, "key": "value" }
+            }"#,
+            None,
+        );
+        assert_eq!(locale.keys.len(), 2);
+        assert_eq!(
+            locale.metadata.get(&new_key("title")),
+            Some(&KeyMetadata {
+                description: Some("Page title".to_string()),
+                max_length: Some(10),
+                screenshot: None,
+            })
+        );
+        let Some(ParsedValue::Subkeys(Some(sub))) = locale.keys.get(&new_key("sub")) else {
+            panic!("expected subkeys");
+        };
+        let description = sub
+            .metadata
+            .get(&new_key("key"))
+            .unwrap()
+            .description
+            .as_deref();
+        assert_eq!(description, Some("Nested"));
+    }
+
+    #[test]
+    fn flattened_metadata() {
+        let locale = parse_locale(
+            "en",
+            r#"{ "@settings.title": { "maxLength": 5 }, "settings.title": "Title" }"#,
+            Some("."),
+        );
+        let Some(ParsedValue::Subkeys(Some(settings))) = locale.keys.get(&new_key("settings"))
+        else {
+            panic!("expected subkeys");
+        };
+        let max_length = settings.metadata.get(&new_key("title")).unwrap().max_length;
+        assert_eq!(max_length, Some(5));
+    }
+
+    #[test]
+    fn max_length_warning() {
+        let en = parse_locale(
+            "en",
+            r#"{ "title": "Profile", "@title": { "maxLength": 10 }, "other": "Other" }"#,
+            None,
+        );
+        let fr = parse_locale(
+            "fr",
+            r#"{ "title": "Profil utilisateur", "other": "Autre" }"#,
+            None,
+        );
+        let locales = LocalesOrNamespaces::Locales(vec![en, fr]);
+        let warnings = Warnings::new();
+        check_max_lengths(&locales, &warnings);
+        let warnings = warnings.into_inner();
+        assert_eq!(warnings.len(), 1);
+        assert!(matches!(
+            &warnings[0],
+            Warning::TranslationTooLong { locale, length: 18, max_length: 10, .. } if locale == &new_key("fr")
+        ));
+    }
+}
@@ -10,10 +10,14 @@ use locale::{BuildersKeys, BuildersKeysInner, DefaultTo, Locale, LocalesOrNamesp
 
 pub mod cfg_file;
 pub mod error;
+pub mod ftl;
 pub mod locale;
+pub mod message_format;
+pub mod metadata;
 pub mod parsed_value;
 pub mod plurals;
 pub mod ranges;
+pub mod select;
 pub mod warning;
 
 use error::{Error, Result};
@@ -53,13 +57,14 @@ pub fn parse_locales_raw(
 
     let foreign_keys_paths = ForeignKeysPaths::new();
 
-    let cfg_file = ConfigFile::new(&mut cargo_manifest_dir)?;
+    let mut tracked_files = Vec::new();
+
+    let cfg_file = ConfigFile::new(&mut cargo_manifest_dir, &mut tracked_files)?;
 
     let warnings = Warnings::new();
 
-    let mut tracked_files = Vec::with_capacity(
-        cfg_file.locales.len() * cfg_file.name_spaces.as_ref().map(Vec::len).unwrap_or(1),
-    );
+    tracked_files
+        .reserve(cfg_file.locales.len() * cfg_file.name_spaces.as_ref().map(Vec::len).unwrap_or(1));
 
     let locales = LocalesOrNamespaces::new(
         &mut cargo_manifest_dir,
@@ -91,6 +96,8 @@ pub fn make_builder_keys(
 
     resolve_foreign_keys(&locales, &cfg_file.default, foreign_keys_paths.into_inner())?;
 
+    metadata::check_max_lengths(&locales, warnings);
+
     check_locales(locales, &cfg_file.extensions, warnings)
 }
 
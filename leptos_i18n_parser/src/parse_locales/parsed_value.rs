@@ -5,31 +5,149 @@ use serde::{
     Deserialize,
 };
 
-use crate::utils::{formatter::Formatter, Key, KeyPath, UnwrapAt};
+use crate::utils::{
+    formatter::{Formatter, FormatterError},
+    Key, KeyPath, UnwrapAt,
+};
 
 use super::{
+    cfg_file::MessageFormat,
     error::{Error, Result},
     locale::{
-        DefaultTo, DefaultedLocales, InterpolOrLit, InterpolationKeys, LiteralType, Locale,
-        LocaleSeed, LocaleValue, LocalesOrNamespaces, RangeOrPlural,
+        ComponentKind, DefaultTo, DefaultedLocales, InterpolOrLit, InterpolationKeys, LiteralType,
+        Locale, LocaleSeed, LocaleValue, LocalesOrNamespaces, RangeOrPlural,
     },
-    plurals::Plurals,
-    ranges::Ranges,
+    message_format,
+    plurals::{Plurals, INLINE_PLURAL},
+    ranges::{Ranges, TypeOrRange, TypeOrRangeSeed},
+    select::Select,
     warning::Warnings,
     ForeignKeysPaths, StringIndexer,
 };
 
+/// Tokens starting the interpolation syntax, a backslash before them makes them literal text.
+/// The backslashes before a token escape each other by pairs, `\\<b>` is a backslash followed by a component.
+const ESCAPABLE_TOKENS: &[&str] = &["{{", "<", "$t("];
+
+const ESCAPE_CHAR: char = '\\';
+
+fn starts_with_token(value: &str) -> Option<&'static str> {
+    ESCAPABLE_TOKENS
+        .iter()
+        .copied()
+        .find(|token| value.starts_with(token))
+}
+
+// Number of backslashes at the end of the value.
+fn trailing_escapes(value: &str) -> usize {
+    value.len() - value.trim_end_matches(ESCAPE_CHAR).len()
+}
+
+// A token is escaped if it directly follows an odd number of backslashes.
+fn is_escaped(value: &str, index: usize) -> bool {
+    trailing_escapes(&value[..index]) % 2 == 1
+}
+
+// Text before an unescaped token, the backslashes before it escape each other by pairs.
+fn strip_escaped_escapes(before: &str) -> &str {
+    &before[..before.len() - trailing_escapes(before) / 2]
+}
+
+fn split_once_unescaped<'a>(value: &'a str, token: &str) -> Option<(&'a str, &'a str)> {
+    let (index, _) = value
+        .match_indices(token)
+        .find(|(index, _)| !is_escaped(value, *index))?;
+    Some((&value[..index], &value[index + token.len()..]))
+}
+
+// Split at the `}}` closing a variable, the ones inside quoted formatter arguments are skipped
+// so inline plurals can contain variables: `{{ count, plural(one: '{{ count }} boy'; other: 'boys') }}`.
+// Falls back to the first `}}` if a quote is never closed.
+fn split_variable_end(value: &str) -> Option<(&str, &str)> {
+    let mut quote = None;
+    for (index, c) in value.char_indices() {
+        match (quote, c) {
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(q), c) if q == c => quote = None,
+            (None, '}') if value[index..].starts_with("}}") => {
+                return Some((&value[..index], &value[index + 2..]))
+            }
+            _ => {}
+        }
+    }
+    value.split_once("}}")
+}
+
+// Remove the backslashes escaping a token or another backslash before a token,
+// the other backslashes are kept as is.
+fn unescape(value: &str) -> String {
+    let mut output = String::with_capacity(value.len());
+    let mut rest = value;
+    while let Some(index) = rest.find(ESCAPE_CHAR) {
+        output.push_str(&rest[..index]);
+        let after = rest[index..].trim_start_matches(ESCAPE_CHAR);
+        let count = rest.len() - index - after.len();
+        let kept = if starts_with_token(after).is_some() {
+            count / 2
+        } else {
+            count
+        };
+        output.extend(std::iter::repeat_n(ESCAPE_CHAR, kept));
+        rest = after;
+    }
+    output.push_str(rest);
+    output
+}
+
+/// Escape a text so it is parsed back as is, the reverse of what the parser does for literal strings.
+///
+/// `before_token` must be set if the text is directly followed by a variable, a component or a foreign key,
+/// the backslashes ending the text are then doubled so they don't escape it.
+pub fn escape_literal(value: &str, before_token: bool) -> String {
+    let mut output = String::with_capacity(value.len());
+    let mut rest = value;
+    while let Some(c) = rest.chars().next() {
+        if c == ESCAPE_CHAR {
+            let after = rest.trim_start_matches(ESCAPE_CHAR);
+            let count = rest.len() - after.len();
+            let escaped = starts_with_token(after).is_some() || (after.is_empty() && before_token);
+            let count = if escaped { count * 2 } else { count };
+            output.extend(std::iter::repeat_n(ESCAPE_CHAR, count));
+            rest = after;
+            continue;
+        }
+        let token = starts_with_token(rest);
+        if token.is_some() {
+            output.push(ESCAPE_CHAR);
+        }
+        let len = token.map_or(c.len_utf8(), str::len);
+        output.push_str(&rest[..len]);
+        rest = &rest[len..];
+    }
+    output
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum ParsedValue {
     Default,
     ForeignKey(RefCell<ForeignKey>),
     Ranges(Ranges),
     Literal(Literal),
-    Variable { key: Key, formatter: Formatter },
-    Component { key: Key, inner: Box<Self> },
+    Variable {
+        key: Key,
+        formatter: Formatter,
+    },
+    Component {
+        key: Key,
+        /// `None` for a self-closing component such as `<br/>`.
+        inner: Option<Box<Self>>,
+        /// String attributes declared in the tag, `<a href="/about">` gives `[("href", "/about")]`.
+        attrs: Vec<(String, String)>,
+    },
     Bloc(Vec<Self>),
     Subkeys(Option<Locale>),
     Plurals(Plurals),
+    Select(Select),
 }
 
 impl Default for ParsedValue {
@@ -38,6 +156,55 @@ impl Default for ParsedValue {
     }
 }
 
+/// The content of an opening tag, `<name attr="value">` or `<name attr="value" />`.
+#[derive(Debug, PartialEq)]
+struct Tag<'a> {
+    name: &'a str,
+    attrs: Vec<(String, String)>,
+    self_closing: bool,
+}
+
+impl<'a> Tag<'a> {
+    /// Parse what is between `<` and `>`, attributes values must be quoted strings.
+    fn parse(tag: &'a str) -> Option<Self> {
+        let (tag, self_closing) = match tag.trim().strip_suffix('/') {
+            Some(tag) => (tag, true),
+            None => (tag, false),
+        };
+        let tag = tag.trim();
+        let (name, mut rest) = tag.split_at(tag.find(char::is_whitespace).unwrap_or(tag.len()));
+        if name.is_empty() || name.starts_with('/') {
+            return None;
+        }
+        let mut attrs = vec![];
+        loop {
+            rest = rest.trim_start();
+            if rest.is_empty() {
+                break;
+            }
+            let (attr_name, value) = rest.split_once('=')?;
+            let attr_name = attr_name.trim_end();
+            let valid_name = !attr_name.is_empty()
+                && attr_name
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | ':' | '.'));
+            if !valid_name {
+                return None;
+            }
+            let value = value.trim_start();
+            let quote = value.chars().next().filter(|c| matches!(c, '"' | '\''))?;
+            let (value, after) = value[1..].split_once(quote)?;
+            attrs.push((attr_name.to_string(), value.to_string()));
+            rest = after;
+        }
+        Some(Tag {
+            name,
+            attrs,
+            self_closing,
+        })
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum ForeignKey {
     NotSet(KeyPath, BTreeMap<String, ParsedValue>),
@@ -139,7 +306,7 @@ impl ParsedValue {
             parsed_value
         } else {
             Ok(ParsedValue::Literal(Literal::String(
-                value.to_string(),
+                unescape(value),
                 usize::MAX,
             )))
         }
@@ -174,18 +341,24 @@ impl ParsedValue {
                 key_path: key_path.clone(),
             }
             .into()),
-            Err(formatter) => Err(Error::DisabledFormatter {
+            Err(FormatterError::Disabled(formatter)) => Err(Error::DisabledFormatter {
                 formatter,
                 locale: locale.clone(),
                 key_path: key_path.clone(),
             }
             .into()),
+            Err(FormatterError::InvalidArgs(message)) => Err(Error::InvalidFormatterArgs {
+                locale: locale.clone(),
+                key_path: key_path.clone(),
+                message,
+            }
+            .into()),
         }
     }
 
     fn parse_key_path(path: &str) -> Option<KeyPath> {
         let (mut key_path, path) = if let Some((namespace, rest)) = path.split_once(':') {
-            let namespace = Key::new(namespace)?;
+            let namespace = Key::new_namespace(namespace)?;
 
             (KeyPath::new(Some(namespace)), rest)
         } else {
@@ -287,7 +460,7 @@ impl ParsedValue {
         locale: &Key,
         foreign_keys_paths: &ForeignKeysPaths,
     ) -> Option<Result<Self>> {
-        let (before, rest) = value.split_once("$t(")?;
+        let (before, rest) = split_once_unescaped(value, "$t(")?;
         let next_split = rest.find([',', ')'])?;
         let keypath = rest.get(..next_split)?;
         let sep = rest[next_split..].chars().next()?;
@@ -304,6 +477,7 @@ impl ParsedValue {
         } else {
             (BTreeMap::new(), after)
         };
+        let before = strip_escaped_escapes(before);
 
         let this = ParsedValue::ForeignKey(RefCell::new(ForeignKey::new(
             key_path.clone(),
@@ -324,18 +498,36 @@ impl ParsedValue {
         locale: &Key,
         foreign_keys_paths: &ForeignKeysPaths,
     ) -> Option<Result<Self>> {
-        let (before, rest) = value.split_once("{{")?;
-        let (ident, after) = rest.split_once("}}")?;
+        let (before, rest) = split_once_unescaped(value, "{{")?;
+        let (ident, after) = split_variable_end(rest)?;
 
         let ident = ident.trim();
+        let before = strip_escaped_escapes(before);
 
         let before = nested_result_try!(Self::new(before, key_path, locale, foreign_keys_paths));
         let after = nested_result_try!(Self::new(after, key_path, locale, foreign_keys_paths));
 
         let this = if let Some((ident, formatter)) = ident.split_once(',') {
-            let formatter = nested_result_try!(Self::parse_formatter(formatter, locale, key_path));
             let key = Key::new(&format!("var_{}", ident.trim()))?;
-            ParsedValue::Variable { key, formatter }
+            let inline_plural = formatter
+                .trim()
+                .strip_prefix(INLINE_PLURAL)
+                .and_then(|args| args.trim_start().strip_prefix('('))
+                .and_then(|args| args.trim_end().strip_suffix(')'));
+            if let Some(args) = inline_plural {
+                let plurals = nested_result_try!(Plurals::from_inline(
+                    key,
+                    args,
+                    key_path,
+                    locale,
+                    foreign_keys_paths
+                ));
+                ParsedValue::Plurals(plurals)
+            } else {
+                let formatter =
+                    nested_result_try!(Self::parse_formatter(formatter, locale, key_path));
+                ParsedValue::Variable { key, formatter }
+            }
         } else {
             let key = Key::new(&format!("var_{}", ident))?;
             ParsedValue::Variable {
@@ -347,17 +539,26 @@ impl ParsedValue {
         Some(Ok(ParsedValue::Bloc(vec![before, this, after])))
     }
 
-    fn find_valid_component(value: &str) -> Option<(Key, &str, &str, &str)> {
+    #[allow(clippy::type_complexity)]
+    fn find_valid_component(value: &str) -> Option<(Key, Tag<'_>, &str, Option<&str>, &str)> {
         let mut skip_sum = 0;
         loop {
-            let (before, key, after, skip) = Self::find_opening_tag(&value[skip_sum..])?;
-            if let Some((key, beetween, after)) = Self::find_closing_tag(after, key) {
-                let before_len = skip_sum + before.len();
-                let before = &value[..before_len];
-                break Some((key, before, beetween, after));
-            } else {
-                skip_sum += skip;
+            let (before, tag, after, skip) = Self::find_opening_tag(&value[skip_sum..])?;
+            let before = &value[..skip_sum + before.len()];
+            let tag = Tag::parse(tag)
+                .and_then(|tag| Some((Key::new(&format!("comp_{}", tag.name))?, tag)));
+            match tag {
+                Some((key, tag)) if tag.self_closing => {
+                    break Some((key, tag, before, None, after))
+                }
+                Some((key, tag)) => {
+                    if let Some((beetween, after)) = Self::find_closing_tag(after, tag.name) {
+                        break Some((key, tag, before, Some(beetween), after));
+                    }
+                }
+                None => {}
             }
+            skip_sum += skip;
         }
     }
 
@@ -367,7 +568,9 @@ impl ParsedValue {
         locale: &Key,
         foreign_keys_paths: &ForeignKeysPaths,
     ) -> Option<Result<Self>> {
-        let (key, before, beetween, after) = Self::find_valid_component(value)?;
+        let (key, tag, before, beetween, after) = Self::find_valid_component(value)?;
+        let before = strip_escaped_escapes(before);
+        let beetween = beetween.map(strip_escaped_escapes);
 
         let before = nested_result_try!(ParsedValue::new(
             before,
@@ -375,12 +578,15 @@ impl ParsedValue {
             locale,
             foreign_keys_paths
         ));
-        let beetween = nested_result_try!(ParsedValue::new(
-            beetween,
-            key_path,
-            locale,
-            foreign_keys_paths
-        ));
+        let beetween = match beetween {
+            Some(beetween) => Some(nested_result_try!(ParsedValue::new(
+                beetween,
+                key_path,
+                locale,
+                foreign_keys_paths
+            ))),
+            None => None,
+        };
         let after = nested_result_try!(ParsedValue::new(
             after,
             key_path,
@@ -390,21 +596,24 @@ impl ParsedValue {
 
         let this = ParsedValue::Component {
             key,
-            inner: beetween.into(),
+            inner: beetween.map(Box::new),
+            attrs: tag.attrs,
         };
 
         Some(Ok(ParsedValue::Bloc(vec![before, this, after])))
     }
 
-    fn find_closing_tag<'a>(value: &'a str, key: &str) -> Option<(Key, &'a str, &'a str)> {
-        let key_ident = Key::new(&format!("comp_{}", key))?;
+    fn find_closing_tag<'a>(value: &'a str, key: &str) -> Option<(&'a str, &'a str)> {
         let mut indices = None;
         let mut depth = 0;
-        let iter = value.match_indices('<').filter_map(|(i, _)| {
-            value[i + 1..]
-                .split_once('>')
-                .map(|(ident, _)| (i, ident.trim()))
-        });
+        let iter = value
+            .match_indices('<')
+            .filter(|(i, _)| !is_escaped(value, *i))
+            .filter_map(|(i, _)| {
+                value[i + 1..]
+                    .split_once('>')
+                    .map(|(ident, _)| (i, ident.trim()))
+            });
         for (i, ident) in iter {
             if let Some(closing_tag) = ident.strip_prefix('/').map(str::trim_start) {
                 if closing_tag != key {
@@ -416,7 +625,7 @@ impl ParsedValue {
                 } else {
                     depth -= 1;
                 }
-            } else if ident == key {
+            } else if Tag::parse(ident).is_some_and(|tag| tag.name == key && !tag.self_closing) {
                 depth += 1;
             }
         }
@@ -426,11 +635,11 @@ impl ParsedValue {
         let before = &value[..start];
         let after = &value[end..];
 
-        Some((key_ident, before, after))
+        Some((before, after))
     }
 
     fn find_opening_tag(value: &str) -> Option<(&str, &str, &str, usize)> {
-        let (before, rest) = value.split_once('<')?;
+        let (before, rest) = split_once_unescaped(value, "<")?;
         let (ident, after) = rest.split_once('>')?;
 
         let skip = before.len() + ident.len() + 2;
@@ -505,9 +714,10 @@ impl ParsedValue {
             ParsedValue::Ranges(inner) => {
                 inner.resolve_foreign_keys(values, top_locale, default_locale, path)
             }
-            ParsedValue::Component { inner, .. } => {
-                inner.resolve_foreign_key(values, top_locale, default_locale, path)
-            }
+            ParsedValue::Component { inner: None, .. } => Ok(()),
+            ParsedValue::Component {
+                inner: Some(inner), ..
+            } => inner.resolve_foreign_key(values, top_locale, default_locale, path),
             ParsedValue::Bloc(bloc) => {
                 for value in bloc {
                     value.resolve_foreign_key(values, top_locale, default_locale, path)?;
@@ -531,11 +741,14 @@ impl ParsedValue {
                     path,
                 )
             }
-            ParsedValue::Plurals(Plurals { forms, other, .. }) => {
-                for value in forms.values() {
+            ParsedValue::Plurals(plurals) => {
+                for value in plurals.values() {
                     value.resolve_foreign_key(values, top_locale, default_locale, path)?;
                 }
-                other.resolve_foreign_key(values, top_locale, default_locale, path)
+                Ok(())
+            }
+            ParsedValue::Select(select) => {
+                select.resolve_foreign_keys(values, top_locale, default_locale, path)
             }
         }
     }
@@ -558,9 +771,18 @@ impl ParsedValue {
                     formatter: *formatter,
                 }),
             },
-            ParsedValue::Component { key, inner } => Ok(ParsedValue::Component {
+            ParsedValue::Component { key, inner, attrs } => Ok(ParsedValue::Component {
                 key: key.clone(),
-                inner: Box::new(inner.populate(args, foreign_key, locale, key_path)?),
+                inner: match inner {
+                    Some(inner) => Some(Box::new(inner.populate(
+                        args,
+                        foreign_key,
+                        locale,
+                        key_path,
+                    )?)),
+                    None => None,
+                },
+                attrs: attrs.clone(),
             }),
             ParsedValue::Bloc(bloc) => bloc
                 .iter()
@@ -569,6 +791,7 @@ impl ParsedValue {
                 .map(ParsedValue::Bloc),
             ParsedValue::Ranges(ranges) => ranges.populate(args, foreign_key, locale, key_path),
             ParsedValue::Plurals(plurals) => plurals.populate(args, foreign_key, locale, key_path),
+            ParsedValue::Select(select) => select.populate(args, foreign_key, locale, key_path),
             ParsedValue::Subkeys(_) => Err(Error::InvalidForeignKey {
                 foreign_key: foreign_key.to_owned(),
                 locale: locale.clone(),
@@ -602,6 +825,7 @@ impl ParsedValue {
                     top_locale_name: top_locale.clone(),
                     name: default_locale.name.clone(),
                     keys: dummy_keys,
+                    metadata: BTreeMap::new(),
                     strings: vec![],
                     top_locale_string_count: 0,
                 };
@@ -650,6 +874,7 @@ impl ParsedValue {
                 | ParsedValue::Ranges(_)
                 | ParsedValue::Variable { .. }
                 | ParsedValue::Plurals(_)
+                | ParsedValue::Select(_)
                 | ParsedValue::ForeignKey(_),
                 LocaleValue::Value {
                     value: interpol_or_lit,
@@ -689,7 +914,11 @@ impl ParsedValue {
                     })
                     .unwrap_at("reduce_1");
             }
-            ParsedValue::Component { inner, .. } => inner.reduce(),
+            ParsedValue::Component { inner, .. } => {
+                if let Some(inner) = inner {
+                    inner.reduce();
+                }
+            }
             ParsedValue::Subkeys(Some(subkeys)) => {
                 for value in subkeys.keys.values_mut() {
                     value.reduce();
@@ -709,8 +938,13 @@ impl ParsedValue {
                     _ => {}
                 }
             }
-            ParsedValue::Plurals(Plurals { forms, other, .. }) => {
-                for value in forms.values_mut().chain(Some(&mut **other)) {
+            ParsedValue::Plurals(plurals) => {
+                for value in plurals.values_mut() {
+                    value.reduce();
+                }
+            }
+            ParsedValue::Select(select) => {
+                for value in select.values_mut() {
                     value.reduce();
                 }
             }
@@ -721,7 +955,9 @@ impl ParsedValue {
         match self {
             ParsedValue::Default => {}    // default in a bloc ? skip
             ParsedValue::Subkeys(_) => {} // same for subkeys
-            mut plurals_like @ (ParsedValue::Ranges(_) | ParsedValue::Plurals(_)) => {
+            mut plurals_like @ (ParsedValue::Ranges(_)
+            | ParsedValue::Plurals(_)
+            | ParsedValue::Select(_)) => {
                 plurals_like.reduce();
                 bloc.push(plurals_like);
             }
@@ -744,9 +980,15 @@ impl ParsedValue {
             ParsedValue::Variable { key, formatter } => {
                 bloc.push(ParsedValue::Variable { key, formatter })
             }
-            ParsedValue::Component { key, mut inner } => {
-                inner.reduce();
-                bloc.push(ParsedValue::Component { key, inner });
+            ParsedValue::Component {
+                key,
+                mut inner,
+                attrs,
+            } => {
+                if let Some(inner) = &mut inner {
+                    inner.reduce();
+                }
+                bloc.push(ParsedValue::Component { key, inner, attrs });
             }
             ParsedValue::Bloc(inner) => {
                 for value in inner {
@@ -801,9 +1043,20 @@ impl ParsedValue {
                 keys.get_interpol_keys_mut()
                     .push_var(key.clone(), *formatter);
             }
-            ParsedValue::Component { key, inner } => {
-                keys.get_interpol_keys_mut().push_comp(key.clone());
-                inner.get_keys_inner(key_path, keys, false)?;
+            ParsedValue::Component { key, inner, attrs } => {
+                let kind = match inner {
+                    Some(_) => ComponentKind::Paired,
+                    None => ComponentKind::SelfClosing,
+                };
+                let interpol_keys = keys.get_interpol_keys_mut();
+                if attrs.is_empty() {
+                    interpol_keys.push_comp(key_path, key.clone(), kind)?;
+                } else {
+                    interpol_keys.push_comp_with_attrs(key_path, key.clone(), kind)?;
+                }
+                if let Some(inner) = inner {
+                    inner.get_keys_inner(key_path, keys, false)?;
+                }
             }
             ParsedValue::Bloc(values) => {
                 for value in values {
@@ -825,22 +1078,17 @@ impl ParsedValue {
                     .as_inner("get_keys_inner")
                     .get_keys_inner(key_path, keys, false)?;
             }
-            ParsedValue::Plurals(Plurals {
-                forms,
-                other,
-                count_key,
-                ..
-            }) => {
+            ParsedValue::Plurals(plurals) => {
                 keys.get_interpol_keys_mut().push_count(
                     key_path,
                     RangeOrPlural::Plural,
-                    count_key.clone(),
+                    plurals.count_key.clone(),
                 )?;
-                for value in forms.values() {
+                for value in plurals.values() {
                     value.get_keys_inner(key_path, keys, false)?;
                 }
-                other.get_keys_inner(key_path, keys, false)?;
             }
+            ParsedValue::Select(select) => select.get_keys_inner(key_path, keys)?,
         }
         Ok(())
     }
@@ -859,9 +1107,12 @@ impl ParsedValue {
             }
             ParsedValue::Ranges(ranges) => ranges.index_strings(strings),
             ParsedValue::Component { inner, .. } => {
-                inner.index_strings(strings);
+                if let Some(inner) = inner {
+                    inner.index_strings(strings);
+                }
             }
             ParsedValue::Plurals(plurals) => plurals.index_strings(strings),
+            ParsedValue::Select(select) => select.index_strings(strings),
             ParsedValue::Bloc(vec) => {
                 for value in vec {
                     value.index_strings(strings);
@@ -922,6 +1173,8 @@ pub struct ParsedValueSeed<'a> {
     pub key_path: &'a KeyPath,
     pub key: &'a Key,
     pub foreign_keys_paths: &'a ForeignKeysPaths,
+    pub message_format: MessageFormat,
+    pub key_separator: Option<&'a str>,
 }
 
 impl<'de> serde::de::DeserializeSeed<'de> for ParsedValueSeed<'_> {
@@ -942,12 +1195,17 @@ impl<'de> serde::de::Visitor<'de> for ParsedValueSeed<'_> {
     where
         E: serde::de::Error,
     {
-        ParsedValue::new(
-            v,
-            self.key_path,
-            self.top_locale_name,
-            self.foreign_keys_paths,
-        )
+        match self.message_format {
+            MessageFormat::Default => ParsedValue::new(
+                v,
+                self.key_path,
+                self.top_locale_name,
+                self.foreign_keys_paths,
+            ),
+            MessageFormat::Icu => {
+                message_format::parse_message_format(v, self.key_path, self.top_locale_name)
+            }
+        }
         .map_err(|err| serde::de::Error::custom(err))
     }
 
@@ -994,6 +1252,8 @@ impl<'de> serde::de::Visitor<'de> for ParsedValueSeed<'_> {
             top_locale_name: self.top_locale_name.clone(),
             key_path: self.key_path.to_owned(),
             foreign_keys_paths: self.foreign_keys_paths,
+            message_format: self.message_format,
+            key_separator: self.key_separator,
         };
 
         seed.deserialize(map_de).map(Some).map(ParsedValue::Subkeys)
@@ -1006,7 +1266,7 @@ impl<'de> serde::de::Visitor<'de> for ParsedValueSeed<'_> {
         Ok(ParsedValue::Default)
     }
 
-    fn visit_seq<A>(mut self, map: A) -> Result<Self::Value, A::Error>
+    fn visit_seq<A>(mut self, mut map: A) -> Result<Self::Value, A::Error>
     where
         A: serde::de::SeqAccess<'de>,
     {
@@ -1015,7 +1275,16 @@ impl<'de> serde::de::Visitor<'de> for ParsedValueSeed<'_> {
         if std::mem::replace(&mut self.in_range, true) {
             return Err(serde::de::Error::custom(Error::NestedRanges));
         }
-        let ranges = Ranges::from_serde_seq(map, self)?;
+        let Some(type_or_range) = map.next_element_seed(TypeOrRangeSeed(self))? else {
+            return Err(serde::de::Error::custom(Error::EmptyRange));
+        };
+
+        let ranges = match type_or_range {
+            TypeOrRange::Select(select_key) => {
+                return Select::from_serde_seq(select_key, map, self).map(ParsedValue::Select)
+            }
+            type_or_range => Ranges::from_serde_seq(type_or_range, map, self)?,
+        };
 
         let (invalid_fallback, fallback_count, should_have_fallback) =
             ranges.check_deserialization();
@@ -1108,6 +1377,7 @@ impl Visitor<'_> for LiteralVisitor {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::parse_locales::plurals::{PluralForm, PluralRuleType};
 
     fn new_parsed_value(value: &str) -> ParsedValue {
         let key_path = KeyPath::new(None);
@@ -1148,6 +1418,22 @@ mod tests {
         )
     }
 
+    #[test]
+    fn unit_formatter_unsupported() {
+        let key_path = KeyPath::new(None);
+        let locale = new_key("locale_key");
+        let foreign_keys_paths = ForeignKeysPaths::new();
+
+        let err = ParsedValue::new(
+            "{{ v, unit(unit: kilometer; width: short) }}",
+            &key_path,
+            &locale,
+            &foreign_keys_paths,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("unit formatter is not supported"));
+    }
+
     #[test]
     fn parse_comp() {
         let value = new_parsed_value("before <comp>inner</comp> after");
@@ -1158,10 +1444,11 @@ mod tests {
                 ParsedValue::Literal(Literal::String("before ".to_string(), usize::MAX)),
                 ParsedValue::Component {
                     key: new_key("comp_comp"),
-                    inner: Box::new(ParsedValue::Literal(Literal::String(
+                    inner: Some(Box::new(ParsedValue::Literal(Literal::String(
                         "inner".to_string(),
                         usize::MAX
-                    )))
+                    )))),
+                    attrs: vec![],
                 },
                 ParsedValue::Literal(Literal::String(" after".to_string(), usize::MAX))
             ])
@@ -1180,23 +1467,25 @@ mod tests {
                 ParsedValue::Literal(Literal::String("before ".to_string(), usize::MAX)),
                 ParsedValue::Component {
                     key: new_key("comp_comp"),
-                    inner: Box::new(ParsedValue::Bloc(vec![
+                    inner: Some(Box::new(ParsedValue::Bloc(vec![
                         ParsedValue::Literal(Literal::String(
                             "inner before".to_string(),
                             usize::MAX
                         )),
                         ParsedValue::Component {
                             key: new_key("comp_comp"),
-                            inner: Box::new(ParsedValue::Literal(Literal::String(
+                            inner: Some(Box::new(ParsedValue::Literal(Literal::String(
                                 "inner inner".to_string(),
                                 usize::MAX
-                            )))
+                            )))),
+                            attrs: vec![],
                         },
                         ParsedValue::Literal(Literal::String(
                             "inner after".to_string(),
                             usize::MAX
                         )),
-                    ]))
+                    ]))),
+                    attrs: vec![],
                 },
                 ParsedValue::Literal(Literal::String(" after".to_string(), usize::MAX))
             ])
@@ -1213,13 +1502,249 @@ mod tests {
                 ParsedValue::Literal(Literal::String("<p>test".to_string(), usize::MAX)),
                 ParsedValue::Component {
                     key: new_key("comp_h3"),
-                    inner: Box::new(ParsedValue::Literal(Literal::String(
+                    inner: Some(Box::new(ParsedValue::Literal(Literal::String(
                         "this is a h3".to_string(),
                         usize::MAX
-                    )))
+                    )))),
+                    attrs: vec![],
                 },
                 ParsedValue::Literal(Literal::String("not closing p".to_string(), usize::MAX))
             ])
         )
     }
+
+    #[test]
+    fn parse_self_closing_comp() {
+        let mut value = new_parsed_value("line<br/>next <br /> <b>bold<br/></b>");
+        value.reduce();
+
+        assert_eq!(
+            value,
+            ParsedValue::Bloc(vec![
+                ParsedValue::Literal(Literal::String("line".to_string(), usize::MAX)),
+                ParsedValue::Component {
+                    key: new_key("comp_br"),
+                    inner: None,
+                    attrs: vec![],
+                },
+                ParsedValue::Literal(Literal::String("next ".to_string(), usize::MAX)),
+                ParsedValue::Component {
+                    key: new_key("comp_br"),
+                    inner: None,
+                    attrs: vec![],
+                },
+                ParsedValue::Literal(Literal::String(" ".to_string(), usize::MAX)),
+                ParsedValue::Component {
+                    key: new_key("comp_b"),
+                    inner: Some(Box::new(ParsedValue::Bloc(vec![
+                        ParsedValue::Literal(Literal::String("bold".to_string(), usize::MAX)),
+                        ParsedValue::Component {
+                            key: new_key("comp_br"),
+                            inner: None,
+                            attrs: vec![],
+                        },
+                    ]))),
+                    attrs: vec![],
+                },
+            ])
+        )
+    }
+
+    #[test]
+    fn parse_comp_attributes() {
+        let mut value = new_parsed_value(
+            r#"<link href="/terms" target='_blank'>terms</link> <icon name="check" data-size="2"/>"#,
+        );
+        value.reduce();
+
+        assert_eq!(
+            value,
+            ParsedValue::Bloc(vec![
+                ParsedValue::Component {
+                    key: new_key("comp_link"),
+                    inner: Some(Box::new(ParsedValue::Literal(Literal::String(
+                        "terms".to_string(),
+                        usize::MAX
+                    )))),
+                    attrs: vec![
+                        ("href".to_string(), "/terms".to_string()),
+                        ("target".to_string(), "_blank".to_string()),
+                    ],
+                },
+                ParsedValue::Literal(Literal::String(" ".to_string(), usize::MAX)),
+                ParsedValue::Component {
+                    key: new_key("comp_icon"),
+                    inner: None,
+                    attrs: vec![
+                        ("name".to_string(), "check".to_string()),
+                        ("data-size".to_string(), "2".to_string()),
+                    ],
+                },
+            ])
+        );
+
+        // unquoted values and boolean attributes are not supported, the tag is kept as text.
+        let value = new_parsed_value("<a href=x>link</a> <input disabled/>");
+        assert_eq!(
+            value,
+            ParsedValue::Literal(Literal::String(
+                "<a href=x>link</a> <input disabled/>".to_string(),
+                usize::MAX
+            ))
+        );
+    }
+
+    #[test]
+    fn parse_escaped_tokens() {
+        let value = new_parsed_value(r"a \< b, \{{ var }} and \$t(key) <b>bold\</b></b>");
+
+        assert_eq!(
+            value,
+            ParsedValue::Bloc(vec![
+                ParsedValue::Literal(Literal::String(
+                    "a < b, {{ var }} and $t(key) ".to_string(),
+                    usize::MAX
+                )),
+                ParsedValue::Component {
+                    key: new_key("comp_b"),
+                    inner: Some(Box::new(ParsedValue::Literal(Literal::String(
+                        "bold</b>".to_string(),
+                        usize::MAX
+                    )))),
+                    attrs: vec![],
+                },
+                ParsedValue::Literal(Literal::String(String::new(), usize::MAX))
+            ])
+        )
+    }
+
+    #[test]
+    fn parse_escaped_backslash() {
+        let value = new_parsed_value(r"C:\\{{ dir }}");
+
+        assert_eq!(
+            value,
+            ParsedValue::Bloc(vec![
+                ParsedValue::Literal(Literal::String(r"C:\".to_string(), usize::MAX)),
+                ParsedValue::Variable {
+                    key: new_key("var_dir"),
+                    formatter: Formatter::None
+                },
+                ParsedValue::Literal(Literal::String(String::new(), usize::MAX))
+            ])
+        );
+
+        let value = new_parsed_value(r"C:\\\{{ dir }}");
+
+        assert_eq!(
+            value,
+            ParsedValue::Literal(Literal::String(r"C:\{{ dir }}".to_string(), usize::MAX))
+        )
+    }
+
+    #[test]
+    fn parse_inline_plural() {
+        let _guard = crate::utils::formatter::SkipIcuCfgGuard::new(true);
+        let mut value = new_parsed_value(
+            "{{ boys_count, plural(=0: 'no boy'; one: '{{ boys_count }} boy'; other: \"boys; plenty\") }}",
+        );
+        value.reduce();
+
+        let ParsedValue::Plurals(plurals) = value else {
+            panic!("expected plurals, got {:?}", value);
+        };
+        assert_eq!(plurals.count_key, new_key("var_boys_count"));
+        assert_eq!(plurals.rule_type, PluralRuleType::Cardinal);
+        assert_eq!(
+            plurals.exact.get(&0),
+            Some(&ParsedValue::Literal(Literal::String(
+                "no boy".to_string(),
+                usize::MAX
+            )))
+        );
+        assert_eq!(
+            plurals.forms.get(&PluralForm::One),
+            Some(&ParsedValue::Bloc(vec![
+                ParsedValue::Variable {
+                    key: new_key("var_boys_count"),
+                    formatter: Formatter::None
+                },
+                ParsedValue::Literal(Literal::String(" boy".to_string(), usize::MAX))
+            ]))
+        );
+        assert_eq!(
+            *plurals.other,
+            ParsedValue::Literal(Literal::String("boys; plenty".to_string(), usize::MAX))
+        );
+
+        let key_path = KeyPath::new(None);
+        let locale = new_key("locale_key");
+        let foreign_keys_paths = ForeignKeysPaths::new();
+        let missing_other = ParsedValue::new(
+            "{{ count, plural(one: 'boy') }}",
+            &key_path,
+            &locale,
+            &foreign_keys_paths,
+        );
+        assert!(matches!(
+            missing_other.map_err(|err| *err),
+            Err(Error::InvalidInlinePlural { .. })
+        ));
+    }
+
+    #[test]
+    fn backslashes_not_before_token() {
+        for s in [r"C:\dir\", r"C:\\server\share", r"a \ b", r"\\"] {
+            assert_eq!(
+                new_parsed_value(s),
+                ParsedValue::Literal(Literal::String(s.to_string(), usize::MAX))
+            );
+        }
+    }
+
+    #[test]
+    fn escaped_backslash_before_token() {
+        let comp = ParsedValue::Component {
+            key: new_key("comp_b"),
+            inner: Some(Box::new(ParsedValue::Literal(Literal::String(
+                "x".to_string(),
+                usize::MAX,
+            )))),
+            attrs: vec![],
+        };
+        let expected = ParsedValue::Bloc(vec![
+            ParsedValue::Literal(Literal::String(r"C:\".to_string(), usize::MAX)),
+            comp,
+            ParsedValue::Literal(Literal::String(String::new(), usize::MAX)),
+        ]);
+        assert_eq!(new_parsed_value(r"C:\\<b>x</b>"), expected);
+        let source = format!("{}<b>x</b>", escape_literal(r"C:\", true));
+        assert_eq!(new_parsed_value(&source), expected);
+        assert_eq!(
+            new_parsed_value(r"\\\<b>"),
+            ParsedValue::Literal(Literal::String(r"\<b>".to_string(), usize::MAX))
+        );
+    }
+
+    #[test]
+    fn escape_literal_roundtrip() {
+        for s in [
+            "a < b",
+            "{{ not a var }}",
+            "{{{ x }}",
+            "$t(key)",
+            r"trailing \",
+            r"a \ b",
+            r"\{{",
+            r"\\<b>",
+        ] {
+            let escaped = escape_literal(s, false);
+            assert_eq!(
+                new_parsed_value(&escaped),
+                ParsedValue::Literal(Literal::String(s.to_string(), usize::MAX)),
+                "escaped: {:?}",
+                escaped
+            );
+        }
+    }
 }
@@ -1,4 +1,5 @@
 use std::{
+    borrow::Cow,
     collections::{BTreeMap, BTreeSet},
     fmt::Display,
 };
@@ -10,9 +11,9 @@ use super::{
     error::{Error, Result},
     parsed_value::Literal,
     warning::{Warning, Warnings},
-    StringIndexer,
+    ForeignKeysPaths, StringIndexer,
 };
-use crate::utils::{Key, KeyPath, UnwrapAt};
+use crate::utils::{formatter::SKIP_ICU_CFG, Key, KeyPath, UnwrapAt};
 
 use super::parsed_value::ParsedValue;
 
@@ -25,6 +26,8 @@ pub struct Plurals {
     // but that makes `ParsedValue::Plurals(Plurals { .. })` impossible in match patterns.
     pub other: Box<ParsedValue>,
     pub forms: BTreeMap<PluralForm, ParsedValue>,
+    /// Exact-number overrides (`items_=0`), checked before the plural category.
+    pub exact: BTreeMap<u64, ParsedValue>,
 }
 
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
@@ -68,6 +71,87 @@ impl PluralForm {
     }
 }
 
+/// Suffix of a plural key, either a plural category or an exact number.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum PluralSuffix {
+    Form(PluralForm),
+    Exact(u64),
+}
+
+/// Suffix of the exact-number overrides once the key is normalized, `items_0_exact`.
+const EXACT_SUFFIX: &str = "exact";
+
+impl PluralSuffix {
+    /// Parse the part of a key after the base key, `"one"`, `"0_exact"` or `"zero_exact"`.
+    pub fn try_from_str(s: &str) -> Option<Self> {
+        let Some(number) = s
+            .strip_suffix(EXACT_SUFFIX)
+            .and_then(|s| s.strip_suffix('_'))
+        else {
+            return PluralForm::try_from_str(s).map(PluralSuffix::Form);
+        };
+        let exact = match number {
+            "zero" => 0,
+            "one" => 1,
+            "two" => 2,
+            number => number.parse().ok()?,
+        };
+        Some(PluralSuffix::Exact(exact))
+    }
+
+    /// Split a key into its base key and plural suffix, `"items_one"` gives `("items", "one")`
+    /// and `"items_0_exact"` gives `("items", "0_exact")`.
+    pub fn split_key(key: &str) -> Option<(&str, &str)> {
+        let (base_key, suffix) = key.rsplit_once('_')?;
+        if suffix != EXACT_SUFFIX {
+            return Some((base_key, suffix));
+        }
+        let (base_key, _) = base_key.rsplit_once('_')?;
+        Some((base_key, &key[base_key.len() + 1..]))
+    }
+
+    /// `items_=0` can't be used as an identifier, it is renamed to `items_0_exact`.
+    pub fn normalize_key(key: &str) -> Cow<'_, str> {
+        match key.rsplit_once("_=") {
+            Some((base_key, number)) if number.trim().parse::<u64>().is_ok() => {
+                format!("{}_{}_{}", base_key, number.trim(), EXACT_SUFFIX).into()
+            }
+            _ => key.into(),
+        }
+    }
+}
+
+/// Name of the formatter declaring an inline plural, `{{ boys_count, plural(one: 'boy'; other: 'boys') }}`.
+pub const INLINE_PLURAL: &str = "plural";
+
+// Split the arguments of an inline plural on `;`, ignoring the ones inside quoted values.
+fn split_inline_args(args: &str) -> Option<Vec<&str>> {
+    let mut parts = vec![];
+    let mut quote = None;
+    let mut start = 0;
+    for (i, c) in args.char_indices() {
+        match (quote, c) {
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(q), c) if q == c => quote = None,
+            (None, ';') => {
+                parts.push(&args[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    if quote.is_some() {
+        return None;
+    }
+    parts.push(&args[start..]);
+    Some(parts.into_iter().filter(|s| !s.trim().is_empty()).collect())
+}
+
+fn unquote(value: &str) -> Option<&str> {
+    let quote = value.chars().next().filter(|c| matches!(c, '"' | '\''))?;
+    value[1..].strip_suffix(quote)
+}
+
 impl From<PluralRuleType> for IcuRuleType {
     fn from(value: PluralRuleType) -> Self {
         match value {
@@ -110,6 +194,84 @@ impl Plurals {
         Ok(())
     }
 
+    /// Parse the arguments of an inline plural, `one: 'boy'; other: 'boys'`.
+    ///
+    /// Each argument is a plural category or an exact number (`=0`) with a quoted value,
+    /// `type: ordinal` selects the ordinal rules.
+    pub fn from_inline(
+        count_key: Key,
+        args: &str,
+        key_path: &KeyPath,
+        locale: &Key,
+        foreign_keys_paths: &ForeignKeysPaths,
+    ) -> Result<Self> {
+        let error = |message: String| {
+            Box::new(Error::InvalidInlinePlural {
+                locale: locale.clone(),
+                key_path: key_path.clone(),
+                message,
+            })
+        };
+        if !cfg!(feature = "plurals") && !SKIP_ICU_CFG.get() {
+            return Err(Box::new(Error::DisabledPlurals {
+                locale: locale.clone(),
+                key_path: key_path.clone(),
+            }));
+        }
+        let args = split_inline_args(args).ok_or_else(|| error("unclosed quote".to_string()))?;
+        let mut rule_type = PluralRuleType::Cardinal;
+        let mut forms = BTreeMap::new();
+        let mut exact = BTreeMap::new();
+        let mut other = None;
+        for arg in args {
+            let Some((name, value)) = arg.split_once(':') else {
+                return Err(error(format!(
+                    "expected `name: value`, found {:?}",
+                    arg.trim()
+                )));
+            };
+            let (name, value) = (name.trim(), value.trim());
+            if name == "type" {
+                rule_type = match value {
+                    "cardinal" => PluralRuleType::Cardinal,
+                    "ordinal" => PluralRuleType::Ordinal,
+                    _ => return Err(error(format!("unknown plural type {:?}", value))),
+                };
+                continue;
+            }
+            let value = unquote(value)
+                .ok_or_else(|| error(format!("the value of {:?} must be quoted", name)))?;
+            let value = ParsedValue::new(value, key_path, locale, foreign_keys_paths)?;
+            let duplicate = if let Some(number) = name.strip_prefix('=') {
+                let number = number
+                    .trim()
+                    .parse()
+                    .map_err(|_| error(format!("invalid exact number {:?}", name)))?;
+                exact.insert(number, value).is_some()
+            } else {
+                match PluralForm::try_from_str(name) {
+                    Some(PluralForm::Other) => other.replace(value).is_some(),
+                    Some(form) => forms.insert(form, value).is_some(),
+                    None => return Err(error(format!(
+                        "unknown plural category {:?}, expected one of zero, one, two, few, many or other",
+                        name
+                    ))),
+                }
+            };
+            if duplicate {
+                return Err(error(format!("{:?} is declared multiple times", name)));
+            }
+        }
+        let other = other.ok_or_else(|| error("the \"other\" category is required".to_string()))?;
+        Ok(Plurals {
+            rule_type,
+            count_key,
+            other: Box::new(other),
+            forms,
+            exact,
+        })
+    }
+
     fn populate_with_new_key(
         &self,
         new_key: Key,
@@ -124,15 +286,35 @@ impl Plurals {
             let value = value.populate(args, foreign_key, locale, key_path)?;
             forms.insert(*form, value);
         }
+        let mut exact = BTreeMap::new();
+        for (number, value) in &self.exact {
+            let value = value.populate(args, foreign_key, locale, key_path)?;
+            exact.insert(*number, value);
+        }
 
         Ok(ParsedValue::Plurals(Plurals {
             rule_type: self.rule_type,
             count_key: new_key,
             other: Box::new(other),
             forms,
+            exact,
         }))
     }
 
+    pub fn values(&self) -> impl Iterator<Item = &ParsedValue> {
+        self.exact
+            .values()
+            .chain(self.forms.values())
+            .chain(Some(&*self.other))
+    }
+
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut ParsedValue> {
+        self.exact
+            .values_mut()
+            .chain(self.forms.values_mut())
+            .chain(Some(&mut *self.other))
+    }
+
     pub fn find_variable(
         values: &[ParsedValue],
         locale: &Key,
@@ -190,24 +372,14 @@ impl Plurals {
         locale: &Key,
         key_path: &KeyPath,
     ) -> Result<ParsedValue> {
-        fn get_category<I: Into<PluralOperands>>(
-            plurals: &Plurals,
-            locale: &Key,
-            input: I,
-        ) -> Result<PluralCategory> {
-            let plural_rules = plurals.get_plural_rules(locale)?;
-            let cat = plural_rules.category_for(input);
-            Ok(cat)
-        }
-
-        let category = match count_arg {
+        let operands: PluralOperands = match count_arg {
             ParsedValue::Literal(Literal::Float(count)) => {
                 let count = FixedDecimal::try_from_f64(*count, FloatPrecision::Floating)
                     .unwrap_at("populate_with_count_arg_1");
-                get_category(self, locale, &count)
+                (&count).into()
             }
-            ParsedValue::Literal(Literal::Unsigned(count)) => get_category(self, locale, *count),
-            ParsedValue::Literal(Literal::Signed(count)) => get_category(self, locale, *count),
+            ParsedValue::Literal(Literal::Unsigned(count)) => (*count).into(),
+            ParsedValue::Literal(Literal::Signed(count)) => (*count).into(),
             ParsedValue::Bloc(values) => {
                 let new_key = Self::find_variable(values, locale, key_path, foreign_key)?;
                 return self.populate_with_new_key(new_key, args, foreign_key, locale, key_path);
@@ -225,7 +397,15 @@ impl Plurals {
             }
         };
 
-        let category = category?;
+        if let Some((_, value)) = self
+            .exact
+            .iter()
+            .find(|(number, _)| PluralOperands::from(**number) == operands)
+        {
+            return value.populate(args, foreign_key, locale, key_path);
+        }
+
+        let category = self.get_plural_rules(locale)?.category_for(operands);
 
         match PluralForm::from_icu_category(category) {
             PluralForm::Other => self.other.populate(args, foreign_key, locale, key_path),
@@ -253,10 +433,9 @@ impl Plurals {
     }
 
     pub fn index_strings(&mut self, strings: &mut StringIndexer) {
-        for form in self.forms.values_mut() {
-            form.index_strings(strings);
+        for value in self.values_mut() {
+            value.index_strings(strings);
         }
-        self.other.index_strings(strings);
     }
 }
 
@@ -259,23 +259,24 @@ impl RangeType {
 
 impl Ranges {
     pub fn from_serde_seq<'de, A>(
-        mut seq: A,
+        type_or_range: TypeOrRange,
+        seq: A,
         parsed_value_seed: ParsedValueSeed,
     ) -> Result<Self, A::Error>
     where
         A: serde::de::SeqAccess<'de>,
     {
-        let type_or_range = seq
-            .next_element_seed(TypeOrRangeSeed(parsed_value_seed))?
-            .ok_or(Error::EmptyRange)
-            .map_err(serde::de::Error::custom)?;
-
         let mut ranges = match type_or_range {
             TypeOrRange::Type(range_type) => Self::from_type(range_type),
             TypeOrRange::Range(range) => Ranges {
                 count_key: Key::count(),
                 inner: UntypedRangesInner::I32(vec![range]),
             },
+            TypeOrRange::Select(_) => {
+                return Err(serde::de::Error::custom(Error::InvalidRangeType(
+                    "select".to_string(),
+                )))
+            }
         };
 
         ranges.deserialize_inner(seq, parsed_value_seed)?;
@@ -797,11 +798,16 @@ impl Ranges {
 pub enum TypeOrRange {
     Type(RangeType),
     Range((Range<DefaultRangeType>, ParsedValue)),
+    // `"select:<variable>"`, the sequence is a select instead of ranges
+    Select(Key),
 }
-struct TypeOrRangeSeed<'a>(pub ParsedValueSeed<'a>);
+pub struct TypeOrRangeSeed<'a>(pub ParsedValueSeed<'a>);
 
 impl TypeOrRange {
     pub fn from_string(s: &str) -> Option<Self> {
+        if let Some(variable) = s.trim().strip_prefix("select:") {
+            return Key::new(&format!("var_{}", variable.trim())).map(TypeOrRange::Select);
+        }
         match s.trim() {
             "i8" => Some(TypeOrRange::Type(RangeType::I8)),
             "i16" => Some(TypeOrRange::Type(RangeType::I16)),
@@ -0,0 +1,299 @@
+use std::collections::BTreeMap;
+
+use super::{
+    error::{Error, Result},
+    locale::{InterpolOrLit, LocalesOrNamespaces},
+    parsed_value::{Literal, ParsedValue, ParsedValueSeed},
+    plurals::Plurals,
+    StringIndexer,
+};
+use crate::utils::{Key, KeyPath};
+
+pub const SELECT_OTHER: &str = "other";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Select {
+    pub select_key: Key,
+    pub arms: BTreeMap<String, ParsedValue>,
+    // Box for the same reason as `Plurals::other`.
+    pub other: Box<ParsedValue>,
+}
+
+fn to_upper_camel_case(s: &str) -> String {
+    let mut output = String::with_capacity(s.len());
+    for part in s.split('_').filter(|part| !part.is_empty()) {
+        let mut chars = part.chars();
+        if let Some(first) = chars.next() {
+            output.extend(first.to_uppercase());
+            output.push_str(chars.as_str());
+        }
+    }
+    output
+}
+
+impl Select {
+    /// Create a new select from the selectors and their values,
+    /// the `"other"` selector is required and used as the fallback.
+    pub fn new<'a, I>(select_key: Key, arms: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = (&'a str, ParsedValue)>,
+    {
+        let mut other = None;
+        let mut parsed_arms = BTreeMap::new();
+        let mut variants = BTreeMap::new();
+        for (selector, value) in arms {
+            if selector == SELECT_OTHER {
+                if other.replace(value).is_some() {
+                    return Err(Error::DuplicateSelector(selector.to_string()).into());
+                }
+                continue;
+            }
+            let variant = Self::variant_name(selector)
+                .ok_or_else(|| Error::InvalidSelector(selector.to_string()))?;
+            if variant == "Other" || variants.insert(variant, selector).is_some() {
+                return Err(Error::DuplicateSelector(selector.to_string()).into());
+            }
+            parsed_arms.insert(selector.to_string(), value);
+        }
+        let other = other.ok_or(Error::MissingSelectOther)?;
+        Ok(Select {
+            select_key,
+            arms: parsed_arms,
+            other: Box::new(other),
+        })
+    }
+
+    /// Return the name of the enum variant for the given selector, `None` if the selector is invalid.
+    pub fn variant_name(selector: &str) -> Option<String> {
+        let mut chars = selector.chars();
+        let valid = chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+            && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+        valid.then(|| to_upper_camel_case(selector))
+    }
+
+    /// Return the name of the enum generated for the given select variable.
+    pub fn enum_name(select_key: &Key) -> String {
+        let name = select_key
+            .name
+            .strip_prefix("var_")
+            .unwrap_or(&select_key.name);
+        to_upper_camel_case(name)
+    }
+
+    pub fn from_serde_seq<'de, A>(
+        select_key: Key,
+        mut seq: A,
+        parsed_value_seed: ParsedValueSeed,
+    ) -> Result<Self, A::Error>
+    where
+        A: serde::de::SeqAccess<'de>,
+    {
+        let mut arms = vec![];
+        while let Some((selectors, value)) =
+            seq.next_element_seed(SelectArmSeed(parsed_value_seed))?
+        {
+            for selector in selectors {
+                arms.push((selector, value.clone()));
+            }
+        }
+        if arms.is_empty() {
+            return Err(serde::de::Error::custom(Error::EmptySelect));
+        }
+        Self::new(
+            select_key,
+            arms.iter()
+                .map(|(selector, value)| (&**selector, value.clone())),
+        )
+        .map_err(serde::de::Error::custom)
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &ParsedValue> {
+        self.arms.values().chain(Some(&*self.other))
+    }
+
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut ParsedValue> {
+        self.arms.values_mut().chain(Some(&mut *self.other))
+    }
+
+    pub fn resolve_foreign_keys(
+        &self,
+        values: &LocalesOrNamespaces,
+        top_locale: &Key,
+        default_locale: &Key,
+        path: &KeyPath,
+    ) -> Result<()> {
+        for value in self.values() {
+            value.resolve_foreign_key(values, top_locale, default_locale, path)?;
+        }
+        Ok(())
+    }
+
+    fn populate_with_new_key(
+        &self,
+        new_key: Key,
+        args: &BTreeMap<String, ParsedValue>,
+        foreign_key: &KeyPath,
+        locale: &Key,
+        key_path: &KeyPath,
+    ) -> Result<ParsedValue> {
+        let other = self.other.populate(args, foreign_key, locale, key_path)?;
+        let mut arms = BTreeMap::new();
+        for (selector, value) in &self.arms {
+            let value = value.populate(args, foreign_key, locale, key_path)?;
+            arms.insert(selector.clone(), value);
+        }
+        Ok(ParsedValue::Select(Select {
+            select_key: new_key,
+            arms,
+            other: Box::new(other),
+        }))
+    }
+
+    pub fn populate(
+        &self,
+        args: &BTreeMap<String, ParsedValue>,
+        foreign_key: &KeyPath,
+        locale: &Key,
+        key_path: &KeyPath,
+    ) -> Result<ParsedValue> {
+        let new_key = match args.get(&*self.select_key.name) {
+            None => self.select_key.clone(),
+            Some(ParsedValue::Literal(Literal::String(selector, _))) => {
+                let value = self.arms.get(selector.trim()).unwrap_or(&self.other);
+                return value.populate(args, foreign_key, locale, key_path);
+            }
+            Some(ParsedValue::Variable { key, .. }) => key.clone(),
+            Some(ParsedValue::Bloc(values)) => {
+                Plurals::find_variable(values, locale, key_path, foreign_key)?
+            }
+            Some(_) => {
+                return Err(Error::InvalidSelectArg {
+                    locale: locale.clone(),
+                    key_path: key_path.to_owned(),
+                    foreign_key: foreign_key.to_owned(),
+                }
+                .into())
+            }
+        };
+        self.populate_with_new_key(new_key, args, foreign_key, locale, key_path)
+    }
+
+    pub fn get_keys_inner(&self, key_path: &mut KeyPath, keys: &mut InterpolOrLit) -> Result<()> {
+        keys.get_interpol_keys_mut().push_select(
+            key_path,
+            self.select_key.clone(),
+            self.arms.keys(),
+        )?;
+        for value in self.values() {
+            value.get_keys_inner(key_path, keys, false)?;
+        }
+        Ok(())
+    }
+
+    pub fn index_strings(&mut self, strings: &mut StringIndexer) {
+        for value in self.values_mut() {
+            value.index_strings(strings);
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct SelectArmSeed<'a>(pub ParsedValueSeed<'a>);
+
+impl<'de> serde::de::DeserializeSeed<'de> for SelectArmSeed<'_> {
+    type Value = (Vec<String>, ParsedValue);
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de> serde::de::Visitor<'de> for SelectArmSeed<'_> {
+    type Value = (Vec<String>, ParsedValue);
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            formatter,
+            "a sequence with the first element being the value and the other elements being the selectors"
+        )
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::SeqAccess<'de>,
+    {
+        let Some(value) = seq.next_element_seed(self.0)? else {
+            return Err(serde::de::Error::invalid_length(0, &"at least 2 elements"));
+        };
+        let mut selectors = vec![];
+        while let Some(selector) = seq.next_element::<String>()? {
+            selectors.push(selector.trim().to_string());
+        }
+        if selectors.is_empty() {
+            return Err(serde::de::Error::invalid_length(1, &"at least 2 elements"));
+        }
+        Ok((selectors, value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lit(s: &str) -> ParsedValue {
+        ParsedValue::Literal(Literal::String(s.to_string(), usize::MAX))
+    }
+
+    #[test]
+    fn variant_names() {
+        assert_eq!(Select::variant_name("male").as_deref(), Some("Male"));
+        assert_eq!(
+            Select::variant_name("non_binary").as_deref(),
+            Some("NonBinary")
+        );
+        assert_eq!(Select::variant_name("1st"), None);
+        assert_eq!(Select::variant_name("with space"), None);
+        assert_eq!(
+            Select::enum_name(&Key::new("var_user_gender").unwrap()),
+            "UserGender"
+        );
+    }
+
+    #[test]
+    fn new_select() {
+        let key = Key::new("var_gender").unwrap();
+        let select = Select::new(
+            key.clone(),
+            [
+                ("male", lit("he")),
+                ("female", lit("she")),
+                ("other", lit("they")),
+            ],
+        )
+        .unwrap();
+        assert_eq!(select.arms.len(), 2);
+        assert_eq!(*select.other, lit("they"));
+
+        let missing_other = Select::new(key.clone(), [("male", lit("he"))]);
+        assert!(matches!(
+            missing_other.map_err(|err| *err),
+            Err(Error::MissingSelectOther)
+        ));
+
+        let duplicate = Select::new(
+            key,
+            [
+                ("male", lit("he")),
+                ("Male", lit("he")),
+                ("other", lit("they")),
+            ],
+        );
+        assert!(matches!(
+            duplicate.map_err(|err| *err),
+            Err(Error::DuplicateSelector(_))
+        ));
+    }
+}
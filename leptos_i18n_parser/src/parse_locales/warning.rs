@@ -24,6 +24,12 @@ pub enum Warning {
         namespace: Option<Key>,
         path: std::path::PathBuf,
     },
+    TranslationTooLong {
+        locale: Key,
+        key_path: KeyPath,
+        length: usize,
+        max_length: usize,
+    },
 }
 
 #[derive(Default)]
@@ -59,6 +65,7 @@ impl Display for Warning {
             },
             Warning::NonUnicodePath { locale, namespace: None, path } => write!(f, "File path for locale {:?} is not valid Unicode, can't add it to proc macro depedencies. Path: {:?}", locale, path),
             Warning::NonUnicodePath { locale, namespace: Some(ns), path } => write!(f, "File path for locale {:?} in namespace {:?} is not valid Unicode, can't add it to proc macro depedencies. Path: {:?}", locale, ns, path),
+            Warning::TranslationTooLong { locale, key_path, length, max_length } => write!(f, "At key \"{}\", the translation of locale {:?} is {} characters long but the max length is {}.", key_path, locale, length, max_length),
         }
     }
 }
@@ -1,4 +1,4 @@
-use std::cell::Cell;
+use std::{cell::Cell, fmt::Display};
 
 use tinystr::{tinystr, TinyAsciiStr};
 
@@ -25,12 +25,19 @@ impl Drop for SkipIcuCfgGuard {
 pub enum Formatter {
     #[default]
     None,
-    Number(GroupingStrategy),
+    Number(GroupingStrategy, NumberPrecision),
+    CompactNumber(CompactDisplay),
+    Percent(GroupingStrategy),
     Date(DateLength),
     Time(TimeLength),
     DateTime(DateLength, TimeLength),
-    List(ListType, ListStyle),
-    Currency(CurrencyWidth, CurrencyCode),
+    DateTimeComponents(DateTimeKind, DateTimeComponents),
+    ZonedDateTime(DateLength, TimeLength),
+    List(ListType, ListStyle, ListSort),
+    Currency(CurrencyWidth, CurrencyCode, NumberPrecision),
+    RelativeTime(RelativeTimeStyle, RelativeTimeNumeric),
+    LanguageName(DisplayNameStyle),
+    RegionName(DisplayNameStyle),
 }
 
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
@@ -49,6 +56,28 @@ pub enum CurrencyWidth {
     Narrow,
 }
 
+#[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum RelativeTimeStyle {
+    #[default]
+    Long,
+    Short,
+    Narrow,
+}
+
+#[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DisplayNameStyle {
+    #[default]
+    Long,
+    Short,
+}
+
+#[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum RelativeTimeNumeric {
+    #[default]
+    Always,
+    Auto,
+}
+
 #[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub enum GroupingStrategy {
     #[default]
@@ -58,6 +87,43 @@ pub enum GroupingStrategy {
     Min2,
 }
 
+/// Digits and rounding applied to the number before formatting it, unset digits leave the number untouched.
+#[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct NumberPrecision {
+    pub minimum_integer_digits: Option<u8>,
+    pub minimum_fraction_digits: Option<u8>,
+    pub maximum_fraction_digits: Option<u8>,
+    pub rounding_mode: RoundingMode,
+}
+
+#[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum RoundingMode {
+    Ceil,
+    Floor,
+    Expand,
+    Trunc,
+    HalfCeil,
+    HalfFloor,
+    #[default]
+    HalfExpand,
+    HalfTrunc,
+    HalfEven,
+}
+
+#[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum NumberNotation {
+    #[default]
+    Standard,
+    Compact,
+}
+
+#[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum CompactDisplay {
+    #[default]
+    Short,
+    Long,
+}
+
 #[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub enum DateLength {
     Full,
@@ -76,6 +142,69 @@ pub enum TimeLength {
     Short,
 }
 
+/// Input of a formatter using components, they can only use the fields present in their input.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DateTimeKind {
+    Date,
+    Time,
+    DateTime,
+    ZonedDateTime,
+}
+
+/// Fields to display with the `date`, `time`, `datetime` and `zoned_datetime` formatters, from a skeleton or explicit arguments.
+#[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct DateTimeComponents {
+    pub era: Option<ComponentText>,
+    pub year: Option<ComponentNumeric>,
+    pub month: Option<ComponentMonth>,
+    pub day: Option<ComponentNumeric>,
+    pub weekday: Option<ComponentText>,
+    pub hour: Option<ComponentNumeric>,
+    pub minute: Option<ComponentNumeric>,
+    pub second: Option<ComponentNumeric>,
+    pub hour_cycle: Option<HourCycle>,
+    pub time_zone_name: Option<ComponentTimeZoneName>,
+}
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ComponentText {
+    Long,
+    Short,
+    Narrow,
+}
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ComponentNumeric {
+    Numeric,
+    TwoDigit,
+}
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ComponentMonth {
+    Numeric,
+    TwoDigit,
+    Long,
+    Short,
+    Narrow,
+}
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum HourCycle {
+    H11,
+    H12,
+    H23,
+    H24,
+}
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ComponentTimeZoneName {
+    ShortSpecific,
+    LongSpecific,
+    GmtOffset,
+    ShortGeneric,
+    LongGeneric,
+}
+
 #[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub enum ListType {
     And,
@@ -92,56 +221,162 @@ pub enum ListStyle {
     Narrow,
 }
 
+#[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ListSort {
+    #[default]
+    Unsorted,
+    Sorted,
+}
+
+/// Reason a formatter can't be used.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FormatterError {
+    /// The feature enabling the formatter is disabled.
+    Disabled(Formatter),
+    /// An argument of the formatter is invalid.
+    InvalidArgs(String),
+}
+
+impl Display for FormatterError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FormatterError::Disabled(formatter) => f.write_str(formatter.err_message()),
+            FormatterError::InvalidArgs(message) => f.write_str(message),
+        }
+    }
+}
+
+impl From<Formatter> for FormatterError {
+    fn from(formatter: Formatter) -> Self {
+        FormatterError::Disabled(formatter)
+    }
+}
+
 impl Formatter {
     pub fn from_name_and_args<'a, S: PartialEq + PartialEq<&'a str> + ToString>(
         name: S,
         args: Option<&[(S, S)]>,
-    ) -> Result<Option<Formatter>, Formatter> {
+    ) -> Result<Option<Formatter>, FormatterError> {
         if name == "currency" {
             let formatter = Formatter::Currency(
                 CurrencyWidth::from_args(args),
                 CurrencyCode::from_args(args),
+                NumberPrecision::from_args(args),
             );
             if cfg!(feature = "format_currency") || SKIP_ICU_CFG.get() {
                 Ok(Some(formatter))
             } else {
-                Err(formatter)
+                Err(formatter.into())
             }
         } else if name == "number" {
-            if cfg!(feature = "format_nums") || SKIP_ICU_CFG.get() {
-                Ok(Some(Formatter::Number(GroupingStrategy::from_args(args))))
+            let (formatter, enabled) = match NumberNotation::from_args(args) {
+                NumberNotation::Standard => (
+                    Formatter::Number(
+                        GroupingStrategy::from_args(args),
+                        NumberPrecision::from_args(args),
+                    ),
+                    cfg!(feature = "format_nums"),
+                ),
+                NumberNotation::Compact => (
+                    Formatter::CompactNumber(CompactDisplay::from_args(args)),
+                    cfg!(feature = "format_compact_nums"),
+                ),
+            };
+            if enabled || SKIP_ICU_CFG.get() {
+                Ok(Some(formatter))
             } else {
-                Err(Formatter::Number(GroupingStrategy::from_args(args)))
+                Err(formatter.into())
             }
-        } else if name == "datetime" {
-            let formatter =
-                Formatter::DateTime(DateLength::from_args(args), TimeLength::from_args(args));
-            if cfg!(feature = "format_datetime") || SKIP_ICU_CFG.get() {
+        } else if name == "percent" {
+            let formatter = Formatter::Percent(GroupingStrategy::from_args(args));
+            if cfg!(feature = "format_percent") || SKIP_ICU_CFG.get() {
                 Ok(Some(formatter))
             } else {
-                Err(formatter)
+                Err(formatter.into())
             }
-        } else if name == "date" {
-            let formatter = Formatter::Date(DateLength::from_args(args));
-            if cfg!(feature = "format_datetime") || SKIP_ICU_CFG.get() {
+        } else if name == "datetime" || name == "date" || name == "time" {
+            let kind = if name == "datetime" {
+                DateTimeKind::DateTime
+            } else if name == "date" {
+                DateTimeKind::Date
+            } else {
+                DateTimeKind::Time
+            };
+            let (formatter, enabled) = match DateTimeComponents::from_args(args, kind)? {
+                Some(components) => (
+                    Formatter::DateTimeComponents(kind, components),
+                    cfg!(feature = "format_datetime_components"),
+                ),
+                None => {
+                    let formatter = match kind {
+                        DateTimeKind::Date => Formatter::Date(DateLength::from_args(args)),
+                        DateTimeKind::Time => Formatter::Time(TimeLength::from_args(args)),
+                        _ => Formatter::DateTime(
+                            DateLength::from_args(args),
+                            TimeLength::from_args(args),
+                        ),
+                    };
+                    (formatter, cfg!(feature = "format_datetime"))
+                }
+            };
+            if enabled || SKIP_ICU_CFG.get() {
                 Ok(Some(formatter))
             } else {
-                Err(formatter)
+                Err(formatter.into())
             }
-        } else if name == "time" {
-            let formatter = Formatter::Time(TimeLength::from_args(args));
-            if cfg!(feature = "format_datetime") || SKIP_ICU_CFG.get() {
+        } else if name == "zoned_datetime" {
+            let kind = DateTimeKind::ZonedDateTime;
+            let formatter = match DateTimeComponents::from_args(args, kind)? {
+                Some(components) => Formatter::DateTimeComponents(kind, components),
+                None => {
+                    Formatter::ZonedDateTime(DateLength::from_args(args), zoned_time_length(args))
+                }
+            };
+            if cfg!(feature = "format_zoned_datetime") || SKIP_ICU_CFG.get() {
                 Ok(Some(formatter))
             } else {
-                Err(formatter)
+                Err(formatter.into())
             }
         } else if name == "list" {
-            let formatter = Formatter::List(ListType::from_args(args), ListStyle::from_args(args));
-            if cfg!(feature = "format_list") || SKIP_ICU_CFG.get() {
+            let list_sort = ListSort::from_args(args);
+            let formatter = Formatter::List(
+                ListType::from_args(args),
+                ListStyle::from_args(args),
+                list_sort,
+            );
+            let sort_enabled = list_sort == ListSort::Unsorted || cfg!(feature = "collation");
+            if (cfg!(feature = "format_list") && sort_enabled) || SKIP_ICU_CFG.get() {
                 Ok(Some(formatter))
             } else {
-                Err(formatter)
+                Err(formatter.into())
             }
+        } else if name == "relative_time" {
+            let formatter = Formatter::RelativeTime(
+                RelativeTimeStyle::from_args(args),
+                RelativeTimeNumeric::from_args(args),
+            );
+            if cfg!(feature = "format_relative_time") || SKIP_ICU_CFG.get() {
+                Ok(Some(formatter))
+            } else {
+                Err(formatter.into())
+            }
+        } else if name == "language_name" || name == "region_name" {
+            let style = DisplayNameStyle::from_args(args);
+            let formatter = if name == "language_name" {
+                Formatter::LanguageName(style)
+            } else {
+                Formatter::RegionName(style)
+            };
+            if cfg!(feature = "format_display_names") || SKIP_ICU_CFG.get() {
+                Ok(Some(formatter))
+            } else {
+                Err(formatter.into())
+            }
+        } else if name == "unit" {
+            // ICU4X 1.5 only ships the unit conversion data, not the localized unit names and patterns.
+            Err(FormatterError::InvalidArgs(
+                "the unit formatter is not supported, ICU4X 1.5 has no measurement unit formatting data".to_string(),
+            ))
         } else {
             Ok(None)
         }
@@ -150,16 +385,176 @@ impl Formatter {
     pub fn err_message(&self) -> &'static str {
         match self {
             Formatter::None => "",
-            Formatter::Number(_) => "Formatting numbers is not enabled, enable the \"format_nums\" feature to do so",
-            Formatter::Currency(_, _) => "Formatting currencies is not enabled, enable the \"format_currency\" feature to do so",
+            Formatter::Number(_, _) => "Formatting numbers is not enabled, enable the \"format_nums\" feature to do so",
+            Formatter::CompactNumber(_) => "Formatting compact numbers is not enabled, enable the \"format_compact_nums\" feature to do so",
+            Formatter::Percent(_) => "Formatting percentages is not enabled, enable the \"format_percent\" feature to do so",
+            Formatter::Currency(_, _, _) => "Formatting currencies is not enabled, enable the \"format_currency\" feature to do so",
             Formatter::Date(_) => "Formatting dates is not enabled, enable the \"format_datetime\" feature to do so",
             Formatter::Time(_) => "Formatting time is not enabled, enable the \"format_datetime\" feature to do so",
+            Formatter::DateTimeComponents(DateTimeKind::ZonedDateTime, _) | Formatter::ZonedDateTime(_, _) => "Formatting zoned datetime is not enabled, enable the \"format_zoned_datetime\" feature to do so",
             Formatter::DateTime(_, _) => "Formatting datetime is not enabled, enable the \"format_datetime\" feature to do so",
-            Formatter::List(_, _) => "Formatting lists is not enabled, enable the \"format_list\" feature to do so",
+            Formatter::DateTimeComponents(_, _) => "Formatting datetime components is not enabled, enable the \"format_datetime_components\" feature to do so",
+            Formatter::List(_, _, ListSort::Sorted) if cfg!(feature = "format_list") => "Sorting lists is not enabled, enable the \"collation\" feature to do so",
+            Formatter::List(_, _, _) => "Formatting lists is not enabled, enable the \"format_list\" feature to do so",
+            Formatter::RelativeTime(_, _) => "Formatting relative time is not enabled, enable the \"format_relative_time\" feature to do so",
+            Formatter::LanguageName(_) | Formatter::RegionName(_) => "Formatting display names is not enabled, enable the \"format_display_names\" feature to do so",
+        }
+    }
+}
+
+fn write_formatter<'a>(
+    f: &mut std::fmt::Formatter<'_>,
+    name: &str,
+    args: impl IntoIterator<Item = Option<(&'a str, &'a str)>>,
+) -> std::fmt::Result {
+    f.write_str(name)?;
+    let mut args = args.into_iter().flatten().peekable();
+    if args.peek().is_none() {
+        return Ok(());
+    }
+    f.write_str("(")?;
+    for (i, (arg_name, value)) in args.enumerate() {
+        if i > 0 {
+            f.write_str("; ")?;
+        }
+        write!(f, "{}: {}", arg_name, value)?;
+    }
+    f.write_str(")")
+}
+
+fn non_default_arg<T: Default + PartialEq>(
+    value: T,
+    to_arg: impl FnOnce(T) -> (&'static str, &'static str),
+) -> Option<(&'static str, &'static str)> {
+    (value != T::default()).then(|| to_arg(value))
+}
+
+fn borrow_args<'a>(
+    args: &'a [Option<(&'static str, String)>],
+) -> impl Iterator<Item = Option<(&'a str, &'a str)>> {
+    args.iter()
+        .map(|arg| arg.as_ref().map(|(name, value)| (*name, value.as_str())))
+}
+
+// Write the formatter back in the syntax used in translations, omitting default arguments.
+impl Display for Formatter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Formatter::None => Ok(()),
+            Formatter::Number(grouping_strategy, precision) => {
+                let precision_args = precision.to_args();
+                write_formatter(
+                    f,
+                    "number",
+                    [non_default_arg(grouping_strategy, GroupingStrategy::to_arg)]
+                        .into_iter()
+                        .chain(borrow_args(&precision_args)),
+                )
+            }
+            Formatter::CompactNumber(compact_display) => write_formatter(
+                f,
+                "number",
+                [
+                    Some(NumberNotation::Compact.to_arg()),
+                    non_default_arg(compact_display, CompactDisplay::to_arg),
+                ],
+            ),
+            Formatter::Percent(grouping_strategy) => write_formatter(
+                f,
+                "percent",
+                [non_default_arg(grouping_strategy, GroupingStrategy::to_arg)],
+            ),
+            Formatter::Date(date_length) => write_formatter(
+                f,
+                "date",
+                [non_default_arg(date_length, DateLength::to_arg)],
+            ),
+            Formatter::Time(time_length) => write_formatter(
+                f,
+                "time",
+                [non_default_arg(time_length, TimeLength::to_arg)],
+            ),
+            Formatter::DateTime(date_length, time_length) => write_formatter(
+                f,
+                "datetime",
+                [
+                    non_default_arg(date_length, DateLength::to_arg),
+                    non_default_arg(time_length, TimeLength::to_arg),
+                ],
+            ),
+            Formatter::DateTimeComponents(kind, components) => {
+                let name = match kind {
+                    DateTimeKind::Date => "date",
+                    DateTimeKind::Time => "time",
+                    DateTimeKind::DateTime => "datetime",
+                    DateTimeKind::ZonedDateTime => "zoned_datetime",
+                };
+                write_formatter(f, name, components.to_args())
+            }
+            Formatter::ZonedDateTime(date_length, time_length) => write_formatter(
+                f,
+                "zoned_datetime",
+                [
+                    non_default_arg(date_length, DateLength::to_arg),
+                    (time_length != TimeLength::Long).then(|| time_length.to_arg()),
+                ],
+            ),
+            Formatter::List(list_type, list_style, list_sort) => write_formatter(
+                f,
+                "list",
+                [
+                    non_default_arg(list_type, ListType::to_arg),
+                    non_default_arg(list_style, ListStyle::to_arg),
+                    non_default_arg(list_sort, ListSort::to_arg),
+                ],
+            ),
+            Formatter::Currency(width, ref code, precision) => {
+                let precision_args = precision.to_args();
+                write_formatter(
+                    f,
+                    "currency",
+                    [
+                        non_default_arg(width, CurrencyWidth::to_arg),
+                        (*code != CurrencyCode::default())
+                            .then(|| ("currency_code", code.0.as_str())),
+                    ]
+                    .into_iter()
+                    .chain(borrow_args(&precision_args)),
+                )
+            }
+            Formatter::RelativeTime(style, numeric) => write_formatter(
+                f,
+                "relative_time",
+                [
+                    non_default_arg(style, RelativeTimeStyle::to_arg),
+                    non_default_arg(numeric, RelativeTimeNumeric::to_arg),
+                ],
+            ),
+            Formatter::LanguageName(style) => write_formatter(
+                f,
+                "language_name",
+                [non_default_arg(style, DisplayNameStyle::to_arg)],
+            ),
+            Formatter::RegionName(style) => write_formatter(
+                f,
+                "region_name",
+                [non_default_arg(style, DisplayNameStyle::to_arg)],
+            ),
         }
     }
 }
 
+// The short time length has no time zone, the zoned datetime formatter defaults to the long one.
+fn zoned_time_length<'a, S: PartialEq + PartialEq<&'a str>>(args: Option<&[(S, S)]>) -> TimeLength {
+    let has_time_length =
+        args.is_some_and(|args| args.iter().any(|(name, _)| name == &"time_length"));
+    if has_time_length {
+        TimeLength::from_args(args)
+    } else {
+        TimeLength::Long
+    }
+}
+
 fn from_args_helper<'a, T: Default, S: PartialEq + PartialEq<&'a str>>(
     args: Option<&[(S, S)]>,
     name: &'a str,
@@ -182,17 +577,26 @@ fn from_args_helper<'a, T: Default, S: PartialEq + PartialEq<&'a str>>(
 macro_rules! impl_from_args {
     ($name:literal, $($arg_name:literal => $value:expr,)*) => {
         pub fn from_args<'a, S: PartialEq + PartialEq<&'a str>>(args: Option<&[(S, S)]>) -> Self {
-        from_args_helper(args, $name, |arg| {
+            from_args_helper(args, $name, |arg| {
+                $(
+                    if arg == &$arg_name {
+                        Some($value)
+                    } else
+                )*
+                {
+                    None
+                }
+            })
+        }
+
+        pub fn to_arg(self) -> (&'static str, &'static str) {
             $(
-                if arg == &$arg_name {
-                    Some($value)
-                } else
+                if self == $value {
+                    return ($name, $arg_name);
+                }
             )*
-            {
-                None
-            }
-        })
-    }
+            unreachable!("all variants are covered by from_args.")
+        }
     }
 }
 
@@ -234,6 +638,31 @@ impl CurrencyWidth {
     }
 }
 
+impl RelativeTimeStyle {
+    impl_from_args! {
+        "style",
+        "long" => Self::Long,
+        "short" => Self::Short,
+        "narrow" => Self::Narrow,
+    }
+}
+
+impl DisplayNameStyle {
+    impl_from_args! {
+        "style",
+        "long" => Self::Long,
+        "short" => Self::Short,
+    }
+}
+
+impl RelativeTimeNumeric {
+    impl_from_args! {
+        "numeric",
+        "always" => Self::Always,
+        "auto" => Self::Auto,
+    }
+}
+
 impl GroupingStrategy {
     impl_from_args! {
         "grouping_strategy",
@@ -244,6 +673,355 @@ impl GroupingStrategy {
     }
 }
 
+impl NumberPrecision {
+    pub fn from_args<'a, S: PartialEq + PartialEq<&'a str> + ToString>(
+        args: Option<&[(S, S)]>,
+    ) -> Self {
+        fn digits_from_args<'a, S: PartialEq + PartialEq<&'a str> + ToString>(
+            args: Option<&[(S, S)]>,
+            name: &'a str,
+        ) -> Option<u8> {
+            from_args_helper(args, name, |arg| arg.to_string().parse().ok().map(Some))
+        }
+
+        NumberPrecision {
+            minimum_integer_digits: digits_from_args(args, "minimum_integer_digits"),
+            minimum_fraction_digits: digits_from_args(args, "minimum_fraction_digits"),
+            maximum_fraction_digits: digits_from_args(args, "maximum_fraction_digits"),
+            rounding_mode: RoundingMode::from_args(args),
+        }
+    }
+
+    fn to_args(self) -> [Option<(&'static str, String)>; 4] {
+        let digits_arg = |name, digits: Option<u8>| digits.map(|digits| (name, digits.to_string()));
+        [
+            digits_arg("minimum_integer_digits", self.minimum_integer_digits),
+            digits_arg("minimum_fraction_digits", self.minimum_fraction_digits),
+            digits_arg("maximum_fraction_digits", self.maximum_fraction_digits),
+            non_default_arg(self.rounding_mode, RoundingMode::to_arg)
+                .map(|(name, value)| (name, value.to_string())),
+        ]
+    }
+}
+
+impl RoundingMode {
+    impl_from_args! {
+        "rounding_mode",
+        "ceil" => Self::Ceil,
+        "floor" => Self::Floor,
+        "expand" => Self::Expand,
+        "trunc" => Self::Trunc,
+        "half_ceil" => Self::HalfCeil,
+        "half_floor" => Self::HalfFloor,
+        "half_expand" => Self::HalfExpand,
+        "half_trunc" => Self::HalfTrunc,
+        "half_even" => Self::HalfEven,
+    }
+}
+
+impl NumberNotation {
+    impl_from_args! {
+        "notation",
+        "standard" => Self::Standard,
+        "compact" => Self::Compact,
+    }
+}
+
+impl CompactDisplay {
+    impl_from_args! {
+        "compact_display",
+        "short" => Self::Short,
+        "long" => Self::Long,
+    }
+}
+
+macro_rules! impl_component_arg {
+    ($t:ty, $($arg_name:literal => $value:expr,)*) => {
+        impl $t {
+            fn from_arg(arg: &str) -> Option<Self> {
+                match arg {
+                    $($arg_name => Some($value),)*
+                    _ => None,
+                }
+            }
+
+            fn to_arg(self) -> &'static str {
+                $(
+                    if self == $value {
+                        return $arg_name;
+                    }
+                )*
+                unreachable!("all variants are covered by from_arg.")
+            }
+        }
+    };
+}
+
+impl_component_arg! {
+    ComponentText,
+    "long" => Self::Long,
+    "short" => Self::Short,
+    "narrow" => Self::Narrow,
+}
+
+impl_component_arg! {
+    ComponentNumeric,
+    "numeric" => Self::Numeric,
+    "two_digit" => Self::TwoDigit,
+}
+
+impl_component_arg! {
+    ComponentMonth,
+    "numeric" => Self::Numeric,
+    "two_digit" => Self::TwoDigit,
+    "long" => Self::Long,
+    "short" => Self::Short,
+    "narrow" => Self::Narrow,
+}
+
+impl_component_arg! {
+    HourCycle,
+    "h11" => Self::H11,
+    "h12" => Self::H12,
+    "h23" => Self::H23,
+    "h24" => Self::H24,
+}
+
+impl_component_arg! {
+    ComponentTimeZoneName,
+    "short_specific" => Self::ShortSpecific,
+    "long_specific" => Self::LongSpecific,
+    "gmt_offset" => Self::GmtOffset,
+    "short_generic" => Self::ShortGeneric,
+    "long_generic" => Self::LongGeneric,
+}
+
+const COMPONENT_ARGS: &[&str] = &[
+    "era",
+    "year",
+    "month",
+    "day",
+    "weekday",
+    "hour",
+    "minute",
+    "second",
+    "hour_cycle",
+    "time_zone_name",
+];
+
+impl DateTimeComponents {
+    /// Parse the components from the `skeleton` argument, overridden by the component arguments.
+    /// Return `None` if none of them are present.
+    pub fn from_args<'a, S: PartialEq + PartialEq<&'a str> + ToString>(
+        args: Option<&[(S, S)]>,
+        kind: DateTimeKind,
+    ) -> Result<Option<Self>, FormatterError> {
+        fn set_arg<T>(
+            slot: &mut Option<T>,
+            name: &str,
+            value: &str,
+            from_arg: impl Fn(&str) -> Option<T>,
+        ) -> Result<(), FormatterError> {
+            match from_arg(value) {
+                Some(value) => {
+                    *slot = Some(value);
+                    Ok(())
+                }
+                None => Err(FormatterError::InvalidArgs(format!(
+                    "invalid value {:?} for the {:?} argument",
+                    value, name
+                ))),
+            }
+        }
+
+        let Some(args) = args else {
+            return Ok(None);
+        };
+        let args = args
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect::<Vec<_>>();
+
+        let mut components = None;
+        if let Some((_, skeleton)) = args.iter().find(|(name, _)| name == "skeleton") {
+            let skeleton = skeleton.trim_matches(|c| c == '"' || c == '\'');
+            let from_skeleton =
+                Self::from_skeleton(skeleton).map_err(FormatterError::InvalidArgs)?;
+            components = Some(from_skeleton);
+        }
+        for (name, value) in &args {
+            let name = name.as_str();
+            if !COMPONENT_ARGS.contains(&name) {
+                continue;
+            }
+            let c = components.get_or_insert_with(Self::default);
+            match name {
+                "era" => set_arg(&mut c.era, name, value, ComponentText::from_arg)?,
+                "year" => set_arg(&mut c.year, name, value, ComponentNumeric::from_arg)?,
+                "month" => set_arg(&mut c.month, name, value, ComponentMonth::from_arg)?,
+                "day" => set_arg(&mut c.day, name, value, ComponentNumeric::from_arg)?,
+                "weekday" => set_arg(&mut c.weekday, name, value, ComponentText::from_arg)?,
+                "hour" => set_arg(&mut c.hour, name, value, ComponentNumeric::from_arg)?,
+                "minute" => set_arg(&mut c.minute, name, value, ComponentNumeric::from_arg)?,
+                "second" => set_arg(&mut c.second, name, value, ComponentNumeric::from_arg)?,
+                "hour_cycle" => set_arg(&mut c.hour_cycle, name, value, HourCycle::from_arg)?,
+                _ => set_arg(
+                    &mut c.time_zone_name,
+                    name,
+                    value,
+                    ComponentTimeZoneName::from_arg,
+                )?,
+            }
+        }
+
+        let Some(mut components) = components else {
+            return Ok(None);
+        };
+        components.check_kind(kind)?;
+        if kind == DateTimeKind::ZonedDateTime {
+            components
+                .time_zone_name
+                .get_or_insert(ComponentTimeZoneName::ShortSpecific);
+        }
+        Ok(Some(components))
+    }
+
+    /// Parse a skeleton such as `"yMMMd"` or `"Hms"`, see the [`Unicode date field symbols`](https://unicode.org/reports/tr35/tr35-dates.html#Date_Field_Symbol_Table).
+    pub fn from_skeleton(skeleton: &str) -> Result<Self, String> {
+        fn set<T>(slot: &mut Option<T>, value: T, field: &str) -> Result<(), String> {
+            if slot.is_some() {
+                return Err(format!("field {:?} is specified more than once", field));
+            }
+            *slot = Some(value);
+            Ok(())
+        }
+
+        if skeleton.is_empty() {
+            return Err("the skeleton is empty".to_string());
+        }
+        let mut components = Self::default();
+        let mut chars = skeleton.chars().peekable();
+        while let Some(c) = chars.next() {
+            let mut len = 1;
+            while chars.next_if_eq(&c).is_some() {
+                len += 1;
+            }
+            let field = c.to_string().repeat(len);
+            let numeric = match len {
+                1 => Some(ComponentNumeric::Numeric),
+                2 => Some(ComponentNumeric::TwoDigit),
+                _ => None,
+            };
+            let text = match len {
+                1..=3 => Some(ComponentText::Short),
+                4 => Some(ComponentText::Long),
+                5 => Some(ComponentText::Narrow),
+                _ => None,
+            };
+            let res = match c {
+                'G' => text.map(|text| set(&mut components.era, text, &field)),
+                'y' => {
+                    let year = if len == 2 {
+                        ComponentNumeric::TwoDigit
+                    } else {
+                        ComponentNumeric::Numeric
+                    };
+                    Some(set(&mut components.year, year, &field))
+                }
+                'M' | 'L' => {
+                    let month = match len {
+                        1 => Some(ComponentMonth::Numeric),
+                        2 => Some(ComponentMonth::TwoDigit),
+                        3 => Some(ComponentMonth::Short),
+                        4 => Some(ComponentMonth::Long),
+                        5 => Some(ComponentMonth::Narrow),
+                        _ => None,
+                    };
+                    month.map(|month| set(&mut components.month, month, &field))
+                }
+                'd' => numeric.map(|day| set(&mut components.day, day, &field)),
+                'E' => text.map(|text| set(&mut components.weekday, text, &field)),
+                'h' | 'H' | 'K' | 'k' | 'j' => numeric.map(|hour| {
+                    set(&mut components.hour, hour, &field)?;
+                    let hour_cycle = match c {
+                        'h' => HourCycle::H12,
+                        'H' => HourCycle::H23,
+                        'K' => HourCycle::H11,
+                        'k' => HourCycle::H24,
+                        // `j` is the preferred hour cycle of the locale, so it is left unset for ICU4X to pick,
+                        // ICU4X 1.5 does not resolve it for components yet and uses `h23` (icu4x#594).
+                        _ => return Ok(()),
+                    };
+                    set(&mut components.hour_cycle, hour_cycle, &field)
+                }),
+                'm' => numeric.map(|minute| set(&mut components.minute, minute, &field)),
+                's' => numeric.map(|second| set(&mut components.second, second, &field)),
+                'z' | 'O' | 'v' => {
+                    let time_zone_name = match (c, len) {
+                        ('z', 1..=3) => Some(ComponentTimeZoneName::ShortSpecific),
+                        ('z', 4) => Some(ComponentTimeZoneName::LongSpecific),
+                        ('O', 1 | 4) => Some(ComponentTimeZoneName::GmtOffset),
+                        ('v', 1) => Some(ComponentTimeZoneName::ShortGeneric),
+                        ('v', 4) => Some(ComponentTimeZoneName::LongGeneric),
+                        _ => None,
+                    };
+                    time_zone_name.map(|name| set(&mut components.time_zone_name, name, &field))
+                }
+                _ => None,
+            };
+            match res {
+                Some(res) => res?,
+                None => {
+                    return Err(format!(
+                        "unsupported field {:?} in skeleton {:?}",
+                        field, skeleton
+                    ))
+                }
+            }
+        }
+        Ok(components)
+    }
+
+    fn check_kind(&self, kind: DateTimeKind) -> Result<(), FormatterError> {
+        let has_date_fields = self.era.is_some()
+            || self.year.is_some()
+            || self.month.is_some()
+            || self.day.is_some()
+            || self.weekday.is_some();
+        let has_time_fields = self.hour.is_some()
+            || self.minute.is_some()
+            || self.second.is_some()
+            || self.hour_cycle.is_some();
+        let message = match kind {
+            DateTimeKind::Date if has_time_fields => "the date formatter can't display time fields",
+            DateTimeKind::Time if has_date_fields => "the time formatter can't display date fields",
+            DateTimeKind::ZonedDateTime => return Ok(()),
+            _ if self.time_zone_name.is_some() => {
+                "only the zoned_datetime formatter can display a time zone"
+            }
+            _ => return Ok(()),
+        };
+        Err(FormatterError::InvalidArgs(message.to_string()))
+    }
+
+    fn to_args(self) -> [Option<(&'static str, &'static str)>; 10] {
+        [
+            self.era.map(|era| ("era", era.to_arg())),
+            self.year.map(|year| ("year", year.to_arg())),
+            self.month.map(|month| ("month", month.to_arg())),
+            self.day.map(|day| ("day", day.to_arg())),
+            self.weekday.map(|weekday| ("weekday", weekday.to_arg())),
+            self.hour.map(|hour| ("hour", hour.to_arg())),
+            self.minute.map(|minute| ("minute", minute.to_arg())),
+            self.second.map(|second| ("second", second.to_arg())),
+            self.hour_cycle
+                .map(|hour_cycle| ("hour_cycle", hour_cycle.to_arg())),
+            self.time_zone_name
+                .map(|time_zone_name| ("time_zone_name", time_zone_name.to_arg())),
+        ]
+    }
+}
+
 impl ListType {
     impl_from_args! {
         "list_type",
@@ -261,3 +1039,11 @@ impl ListStyle {
         "narrow" => Self::Narrow,
     }
 }
+
+impl ListSort {
+    impl_from_args! {
+        "sort",
+        "false" => Self::Unsorted,
+        "true" => Self::Sorted,
+    }
+}
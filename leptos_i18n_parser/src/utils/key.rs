@@ -32,6 +32,41 @@ impl Key {
         })
     }
 
+    /// Create the key of a namespace, nested namespaces are separated by `/` or `.` ("admin/users"),
+    /// the name is normalized to use `/` and the ident joins the segments with `__`.
+    #[cfg(not(feature = "quote"))]
+    pub fn new_namespace(name: &str) -> Option<Self> {
+        let segments = Self::namespace_segments_of(name)?;
+        Some(Key {
+            name: Rc::from(segments.join("/")),
+        })
+    }
+
+    /// Create the key of a namespace, nested namespaces are separated by `/` or `.` ("admin/users"),
+    /// the name is normalized to use `/` and the ident joins the segments with `__`.
+    #[cfg(feature = "quote")]
+    pub fn new_namespace(name: &str) -> Option<Self> {
+        let segments = Self::namespace_segments_of(name)?;
+        let ident_repr = segments.join("__").replace('-', "_");
+        let ident = syn::parse_str::<syn::Ident>(&ident_repr).ok()?;
+        Some(Key {
+            name: Rc::from(segments.join("/")),
+            ident: Rc::new(ident),
+        })
+    }
+
+    fn namespace_segments_of(name: &str) -> Option<Vec<&str>> {
+        name.trim()
+            .split(['/', '.'])
+            .map(|segment| Self::new(segment).map(|_| segment.trim()))
+            .collect()
+    }
+
+    /// Return the segments of a namespace name, `["admin", "users"]` for "admin/users".
+    pub fn namespace_segments(&self) -> impl Iterator<Item = &str> {
+        self.name.split('/')
+    }
+
     pub fn try_new(name: &str) -> Result<Self> {
         Self::new(name).ok_or_else(|| Error::InvalidKey(name.to_string()).into())
     }
@@ -0,0 +1,24 @@
+-brand = Leptos
+
+click_to_change_lang = Click to change language
+click_count = You clicked { $count } times
+click_to_inc = Click to increment the counter
+about = About { -brand }
+cat_count = { $count ->
+    [one] { $count } cat
+   *[other] { $count } cats
+}
+place = { NUMBER($place, type: "ordinal") ->
+    [one] { $place }st
+    [two] { $place }nd
+    [few] { $place }rd
+   *[other] { $place }th
+}
+exact = { $count ->
+    [0] zero
+    [1] one
+   *[other] { $count }
+}
+login = Login
+    .placeholder = Email
+referenced = { click_to_change_lang }!
@@ -0,0 +1,21 @@
+-brand = Leptos
+
+click_to_change_lang = Cliquez pour changez de langue
+click_count = Vous avez cliqué { $count } fois
+click_to_inc = Cliquez pour incrémenter le compteur
+about = À propos de { -brand }
+cat_count = { $count ->
+    [one] { $count } chat
+   *[other] { $count } chats
+}
+place = { NUMBER($place, type: "ordinal") ->
+    [one] { $place }er
+   *[other] { $place }e
+}
+exact = { $count ->
+    [0] zéro
+   *[other] { $count }
+}
+login = Connexion
+    .placeholder = Courriel
+referenced = { click_to_change_lang } !
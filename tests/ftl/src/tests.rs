@@ -0,0 +1,90 @@
+use crate::i18n::*;
+use tests_common::*;
+
+#[test]
+fn click_to_change_lang() {
+    let en = td!(Locale::en, click_to_change_lang);
+    assert_eq_rendered!(en, "Click to change language");
+    let fr = td!(Locale::fr, click_to_change_lang);
+    assert_eq_rendered!(fr, "Cliquez pour changez de langue");
+}
+
+#[test]
+fn click_count() {
+    for count in -5..5 {
+        let en = td!(Locale::en, click_count, count);
+        assert_eq_rendered!(en, format!("You clicked {} times", count));
+        let fr = td!(Locale::fr, click_count, count);
+        assert_eq_rendered!(fr, format!("Vous avez cliqué {} fois", count));
+    }
+}
+
+#[test]
+fn term() {
+    let en = td!(Locale::en, about);
+    assert_eq_rendered!(en, "About Leptos");
+    let fr = td!(Locale::fr, about);
+    assert_eq_rendered!(fr, "À propos de Leptos");
+}
+
+#[test]
+fn cardinal_selector() {
+    let count = move || 1;
+    let en = td!(Locale::en, cat_count, count);
+    assert_eq_rendered!(en, "1 cat");
+    let fr = td!(Locale::fr, cat_count, count);
+    assert_eq_rendered!(fr, "1 chat");
+
+    let count = move || 3;
+    let en = td!(Locale::en, cat_count, count);
+    assert_eq_rendered!(en, "3 cats");
+    let fr = td!(Locale::fr, cat_count, count);
+    assert_eq_rendered!(fr, "3 chats");
+}
+
+#[test]
+fn ordinal_selector() {
+    for (place, en_expected, fr_expected) in [
+        (1, "1st", "1er"),
+        (2, "2nd", "2e"),
+        (3, "3rd", "3e"),
+        (4, "4th", "4e"),
+    ] {
+        let place = move || place;
+        let en = td!(Locale::en, place, place);
+        assert_eq_rendered!(en, en_expected);
+        let fr = td!(Locale::fr, place, place);
+        assert_eq_rendered!(fr, fr_expected);
+    }
+}
+
+#[test]
+fn numeric_selector() {
+    let count = move || 0;
+    let en = td!(Locale::en, exact, count);
+    assert_eq_rendered!(en, "zero");
+    let fr = td!(Locale::fr, exact, count);
+    assert_eq_rendered!(fr, "zéro");
+
+    let count = move || 1;
+    let en = td!(Locale::en, exact, count);
+    assert_eq_rendered!(en, "one");
+    let fr = td!(Locale::fr, exact, count);
+    assert_eq_rendered!(fr, "1");
+}
+
+#[test]
+fn attributes() {
+    let en = td!(Locale::en, login.value);
+    assert_eq_rendered!(en, "Login");
+    let fr = td!(Locale::fr, login.placeholder);
+    assert_eq_rendered!(fr, "Courriel");
+}
+
+#[test]
+fn message_reference() {
+    let en = td!(Locale::en, referenced);
+    assert_eq_rendered!(en, "Click to change language!");
+    let fr = td!(Locale::fr, referenced);
+    assert_eq_rendered!(fr, "Cliquez pour changez de langue !");
+}
@@ -0,0 +1,50 @@
+use tests_common::*;
+
+leptos_i18n::declare_locales! {
+    path: leptos_i18n,
+    bidi_isolation,
+    default: "en",
+    locales: ["en", "ar"],
+    en: {
+        greeting: "Hello {{ name }}!",
+        count: "{{ num, number }} messages",
+    },
+    ar: {
+        greeting: "مرحبا {{ name }}!",
+        count: "{{ num, number }} رسائل",
+    },
+}
+
+use i18n::*;
+use leptos_i18n::{td, td_string};
+
+#[test]
+fn isolate_string() {
+    let en = td_string!(Locale::en, greeting, name = "John");
+    assert_eq!(en.to_string(), "Hello John!");
+    let en = td_string!(Locale::en, greeting, name = "محمد");
+    assert_eq!(en.to_string(), "Hello \u{2068}محمد\u{2069}!");
+
+    let ar = td_string!(Locale::ar, greeting, name = "John");
+    assert_eq!(ar.to_string(), "مرحبا \u{2068}John\u{2069}!");
+    let ar = td_string!(Locale::ar, greeting, name = "محمد");
+    assert_eq!(ar.to_string(), "مرحبا محمد!");
+
+    let ar = td_string!(Locale::ar, count, num = 12);
+    assert_eq!(ar.to_string(), "\u{2068}12\u{2069} رسائل");
+}
+
+#[test]
+fn isolate_view() {
+    let en = td!(Locale::en, greeting, name = "John");
+    assert_eq_rendered!(en, "Hello John!");
+    let en = td!(Locale::en, greeting, name = "محمد");
+    assert_eq_rendered!(en, "Hello <bdi>محمد</bdi>!");
+
+    let ar = td!(Locale::ar, greeting, name = "John");
+    assert_eq_rendered!(ar, "مرحبا <bdi>John</bdi>!");
+    let ar = td!(Locale::ar, greeting, name = "محمد");
+    assert_eq_rendered!(ar, "مرحبا محمد!");
+    let ar = td!(Locale::ar, count, num = || 12);
+    assert_eq_rendered!(ar, "<bdi>12</bdi> رسائل");
+}
@@ -1,7 +1,11 @@
 use crate::i18n::*;
-use leptos_i18n::reexports::{
-    fixed_decimal::FixedDecimal,
-    icu::calendar::{Date, DateTime, Time},
+use leptos_i18n::{
+    formatting::RelativeTimeUnit,
+    reexports::{
+        fixed_decimal::FixedDecimal,
+        icu::calendar::{Date, DateTime, Time},
+        icu::timezone::{CustomTimeZone, MetazoneCalculator, TimeZoneIdMapper, ZoneVariant},
+    },
 };
 use tests_common::*;
 
@@ -15,6 +19,26 @@ fn list_formatting() {
     assert_eq_rendered!(fr, "A, B ou C");
 }
 
+#[test]
+fn sorted_list_formatting() {
+    let list = move || ["Zoé", "éclair", "eau", "apple"];
+
+    let en = td!(Locale::en, sorted_list_formatting, list);
+    assert_eq_rendered!(en, "apple, eau, éclair, and Zoé");
+    let fr = td!(Locale::fr, sorted_list_formatting, list);
+    assert_eq_rendered!(fr, "apple, eau, éclair et Zoé");
+
+    let en = td_string!(Locale::en, sorted_list_formatting, list = ["b", "C", "a"]);
+    assert_eq!(en.to_string(), "a, b, and C");
+}
+
+#[test]
+fn sort_by_locale() {
+    let mut words = vec![("zèbre", 0), ("Zoé", 1), ("eau", 2), ("éclair", 3)];
+    leptos_i18n::formatting::sort_by_locale(Locale::fr, &mut words, |(word, _)| word);
+    assert_eq!(words, [("eau", 2), ("éclair", 3), ("zèbre", 0), ("Zoé", 1)]);
+}
+
 #[test]
 fn date_formatting() {
     let date = move || Date::try_new_iso_date(1970, 1, 2).unwrap().to_any();
@@ -49,6 +73,79 @@ fn datetime_formatting() {
     assert_eq_rendered!(fr, "2 janv. 1970, 14:34");
 }
 
+#[test]
+fn date_formatting_skeleton() {
+    let date = move || Date::try_new_iso_date(1970, 1, 2).unwrap().to_any();
+
+    let en = td!(Locale::en, date_formatting_skeleton, date);
+    assert_eq_rendered!(en, "Jan 2");
+    let fr = td!(Locale::fr, date_formatting_skeleton, date);
+    assert_eq_rendered!(fr, "2 janv.");
+}
+
+#[test]
+fn time_formatting_components() {
+    let time = move || Time::try_new(14, 4, 8, 0).unwrap();
+
+    let en = td!(Locale::en, time_formatting_components, time);
+    assert_eq_rendered!(en, "14:04:08");
+    let fr = td!(Locale::fr, time_formatting_components, time);
+    assert_eq_rendered!(fr, "14:04:08");
+}
+
+#[test]
+fn datetime_formatting_components() {
+    let date = move || {
+        let date = Date::try_new_iso_date(1970, 1, 2).unwrap().to_any();
+        let time = Time::try_new(14, 34, 28, 0).unwrap();
+        DateTime::new(date, time)
+    };
+
+    let en = td!(Locale::en, datetime_formatting_components, date);
+    assert_eq_rendered!(en, "Fri, Jan 2, 14:34");
+    let fr = td!(Locale::fr, datetime_formatting_components, date);
+    assert_eq_rendered!(fr, "ven. 2 janv., 14:34");
+}
+
+fn paris_time_zone() -> CustomTimeZone {
+    let mut time_zone: CustomTimeZone = "+01:00".parse().unwrap();
+    time_zone.time_zone_id = TimeZoneIdMapper::new()
+        .as_borrowed()
+        .iana_to_bcp47("Europe/Paris");
+    let datetime = DateTime::try_new_iso_datetime(1970, 1, 2, 14, 34, 28).unwrap();
+    time_zone.maybe_calculate_metazone(&MetazoneCalculator::new(), &datetime);
+    time_zone.zone_variant = Some(ZoneVariant::standard());
+    time_zone
+}
+
+#[test]
+fn zoned_datetime_formatting() {
+    let when = move || {
+        let date = Date::try_new_iso_date(1970, 1, 2).unwrap().to_any();
+        let time = Time::try_new(14, 34, 28, 0).unwrap();
+        (DateTime::new(date, time), paris_time_zone())
+    };
+
+    let en = td!(Locale::en, zoned_datetime_formatting, when);
+    assert_eq_rendered!(en, "Jan 2, 1970, 2:34:28\u{202f}PM GMT+01:00");
+    let fr = td!(Locale::fr, zoned_datetime_formatting, when);
+    assert_eq_rendered!(fr, "2 janv. 1970, 14:34:28 UTC+01:00");
+}
+
+#[test]
+fn zoned_datetime_formatting_components() {
+    let when = move || {
+        let date = Date::try_new_iso_date(1970, 1, 2).unwrap().to_any();
+        let time = Time::try_new(14, 34, 28, 0).unwrap();
+        (DateTime::new(date, time), paris_time_zone())
+    };
+
+    let en = td!(Locale::en, zoned_datetime_formatting_components, when);
+    assert_eq_rendered!(en, "14:34 Central European Standard Time");
+    let fr = td!(Locale::fr, zoned_datetime_formatting_components, when);
+    assert_eq_rendered!(fr, "14:34 heure normale d’Europe centrale");
+}
+
 #[test]
 fn number_formatting() {
     let num = move || FixedDecimal::from(200050).multiplied_pow10(-2);
@@ -92,3 +189,151 @@ fn currency_formatting() {
     let fr = td!(Locale::fr, currency_formatting_width, num);
     assert_eq_rendered!(fr, "2000.5\u{a0}€");
 }
+
+#[test]
+fn currency_formatting_runtime_code() {
+    let price = move || (2000.5f64, "JPY");
+
+    let en = td!(Locale::en, currency_formatting, num = price);
+    assert_eq_rendered!(en, "¥2000.5");
+    let fr = td!(Locale::fr, currency_formatting, num = price);
+    assert_eq_rendered!(fr, "2000.5\u{a0}JPY");
+
+    let en = td_string!(
+        Locale::en,
+        currency_formatting,
+        num = (12, "GBP".to_string())
+    );
+    assert_eq!(en.to_string(), "£12");
+
+    let en = td_string!(Locale::en, currency_formatting, num = (12, "gbp"));
+    assert_eq!(en.to_string(), "£12");
+    // malformed codes are rendered as is after the amount.
+    let en = td_string!(Locale::en, currency_formatting, num = (12, "POUND"));
+    assert_eq!(en.to_string(), "12 POUND");
+    let en = td_string!(
+        Locale::en,
+        currency_formatting,
+        num = (12, "G1P".to_string())
+    );
+    assert_eq!(en.to_string(), "12 G1P");
+}
+
+#[test]
+fn number_formatting_precision() {
+    let num = move || 2.56f64;
+
+    let en = td!(Locale::en, number_formatting_precision, num);
+    assert_eq_rendered!(en, "02.6");
+    let fr = td!(Locale::fr, number_formatting_precision, num);
+    assert_eq_rendered!(fr, "2,560");
+}
+
+#[test]
+fn currency_formatting_precision() {
+    let num = move || 2000.5f64;
+
+    let en = td!(Locale::en, currency_formatting_precision, num);
+    assert_eq_rendered!(en, "€2000.50");
+    let fr = td!(Locale::fr, currency_formatting_precision, num);
+    assert_eq_rendered!(fr, "2000\u{a0}€");
+
+    let en = td_string!(Locale::en, currency_formatting_precision, num = 2000.555f64);
+    assert_eq!(en.to_string(), "€2000.56");
+}
+
+#[test]
+fn compact_number_formatting() {
+    let num = move || 2_200;
+
+    let en = td!(Locale::en, compact_number_formatting, num);
+    assert_eq_rendered!(en, "2.2K");
+    let fr = td!(Locale::fr, compact_number_formatting, num);
+    assert_eq_rendered!(fr, "2,2\u{a0}k");
+
+    let en = td_string!(Locale::en, compact_number_formatting_long, num = 1_500_000);
+    assert_eq!(en.to_string(), "1.5 million");
+    let fr = td_string!(Locale::fr, compact_number_formatting_long, num = 1_500_000);
+    assert_eq!(fr.to_string(), "1,5 million");
+}
+
+#[test]
+fn percent_formatting() {
+    let num = move || FixedDecimal::from(45).multiplied_pow10(-2);
+
+    let en = td!(Locale::en, percent_formatting, num);
+    assert_eq_rendered!(en, "45%");
+    let fr = td!(Locale::fr, percent_formatting, num);
+    assert_eq_rendered!(fr, "45\u{a0}%");
+
+    let en = td_string!(Locale::en, percent_formatting, num = 0.125f64);
+    assert_eq!(en.to_string(), "12.5%");
+    let fr = td_string!(Locale::fr, percent_formatting, num = -0.45f64);
+    assert_eq!(fr.to_string(), "-45\u{a0}%");
+}
+
+#[test]
+fn relative_time_formatting() {
+    let when = move || (-3, RelativeTimeUnit::Day);
+
+    let en = td!(Locale::en, relative_time_formatting, when);
+    assert_eq_rendered!(en, "3 days ago");
+    let fr = td!(Locale::fr, relative_time_formatting, when);
+    assert_eq_rendered!(fr, "il y a 3 jours");
+
+    let when = move || std::time::Duration::from_secs(2 * 3600 + 59);
+
+    let en = td!(Locale::en, relative_time_formatting, when);
+    assert_eq_rendered!(en, "in 2 hours");
+    let fr = td!(Locale::fr, relative_time_formatting, when);
+    assert_eq_rendered!(fr, "dans 2 heures");
+
+    let en = td_string!(
+        Locale::en,
+        relative_time_formatting_auto,
+        when = (1, RelativeTimeUnit::Day)
+    );
+    assert_eq!(en.to_string(), "tomorrow");
+    let fr = td_string!(
+        Locale::fr,
+        relative_time_formatting_auto,
+        when = (-1, RelativeTimeUnit::Day)
+    );
+    assert_eq!(fr.to_string(), "hier");
+}
+
+#[test]
+fn language_name_formatting() {
+    let code = move || "fr";
+
+    let en = td!(Locale::en, language_name_formatting, code);
+    assert_eq_rendered!(en, "French");
+    let fr = td!(Locale::fr, language_name_formatting, code);
+    assert_eq_rendered!(fr, "français");
+
+    let en = td_string!(Locale::en, language_name_formatting, code = "en-GB");
+    assert_eq!(en.to_string(), "British English");
+    let fr = td_string!(Locale::fr, language_name_formatting, code = "not a code");
+    assert_eq!(fr.to_string(), "not a code");
+}
+
+#[test]
+fn region_name_formatting() {
+    let code = move || "GB";
+
+    let en = td!(Locale::en, region_name_formatting, code);
+    assert_eq_rendered!(en, "UK");
+    let fr = td!(Locale::fr, region_name_formatting, code);
+    assert_eq_rendered!(fr, "R.-U.");
+
+    let en = td_string!(Locale::en, region_name_formatting, code = "FR");
+    assert_eq!(en.to_string(), "France");
+}
+
+#[test]
+fn locale_display_names() {
+    assert_eq!(Locale::en.native_name(), "English");
+    assert_eq!(Locale::fr.native_name(), "français");
+    assert_eq!(Locale::fr.display_name_in(Locale::en), "French");
+    assert_eq!(Locale::en.display_name_in(Locale::fr), "anglais");
+}
@@ -15,6 +15,8 @@ mod ranges;
 #[cfg(test)]
 mod scoped;
 #[cfg(test)]
+mod select;
+#[cfg(test)]
 mod subkeys;
 #[cfg(test)]
 mod tests;
@@ -23,3 +25,7 @@ mod tests;
 mod t_format;
 #[cfg(test)]
 mod t_plural;
+#[cfg(test)]
+mod locale_extensions;
+#[cfg(test)]
+mod bidi_isolation;
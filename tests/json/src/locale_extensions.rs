@@ -0,0 +1,53 @@
+use leptos_i18n::reexports::icu::calendar::{Date, Time};
+use tests_common::*;
+
+leptos_i18n::declare_locales! {
+    path: leptos_i18n,
+    default: "en",
+    locales: ["en", "th-TH-u-ca-buddhist", "ar-EG-u-nu-arab", "en-US-u-hc-h23", "ja-JP-u-ca-japanese-nu-latn"],
+    en: {},
+    th_TH_u_ca_buddhist: {},
+    ar_EG_u_nu_arab: {},
+    en_US_u_hc_h23: {},
+    ja_JP_u_ca_japanese_nu_latn: {},
+}
+
+use i18n::*;
+use leptos_i18n::{td_format, Locale as _};
+
+#[test]
+fn extension_locales() {
+    assert_eq!(Locale::th_TH_u_ca_buddhist.as_str(), "th-TH-u-ca-buddhist");
+    assert_eq!(
+        Locale::ja_JP_u_ca_japanese_nu_latn
+            .as_icu_locale()
+            .to_string(),
+        "ja-JP-u-ca-japanese-nu-latn"
+    );
+    assert_eq!(Locale::find_locale(&["th-TH"]), Locale::th_TH_u_ca_buddhist);
+}
+
+#[test]
+fn calendar_extension() {
+    let date = move || Date::try_new_iso_date(1970, 1, 2).unwrap().to_any();
+
+    let th = td_format!(Locale::th_TH_u_ca_buddhist, date, formatter: date);
+    assert_eq_rendered!(th, "2 ม.ค. 2513");
+    let ja =
+        td_format!(Locale::ja_JP_u_ca_japanese_nu_latn, date, formatter: date(date_length: long));
+    assert_eq_rendered!(ja, "昭和45年1月2日");
+}
+
+#[test]
+fn numbering_system_extension() {
+    let num = move || 1234;
+    let ar = td_format!(Locale::ar_EG_u_nu_arab, num, formatter: number);
+    assert_eq_rendered!(ar, "١٬٢٣٤");
+}
+
+#[test]
+fn hour_cycle_extension() {
+    let time = move || Time::try_new(14, 34, 28, 0).unwrap();
+    let en = td_format!(Locale::en_US_u_hc_h23, time, formatter: time);
+    assert_eq_rendered!(en, "14:34");
+}
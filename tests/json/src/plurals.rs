@@ -99,3 +99,72 @@ fn renamed_plurals_count() {
     let fr = td!(Locale::fr, renamed_plurals_count, first_count, second_count);
     assert_eq_rendered!(fr, "0 1re place");
 }
+
+#[test]
+fn exact_plural() {
+    // count = 0
+    let count = move || 0;
+    let en = td!(Locale::en, exact_plural, count);
+    assert_eq_rendered!(en, "no items");
+    let fr = td!(Locale::fr, exact_plural, count);
+    assert_eq_rendered!(fr, "aucun élément");
+
+    // count = 1
+    let count = move || 1;
+    let en = td!(Locale::en, exact_plural, count);
+    assert_eq_rendered!(en, "one item");
+    let fr = td!(Locale::fr, exact_plural, count);
+    assert_eq_rendered!(fr, "1 élément");
+
+    // count = 2
+    let count = move || 2;
+    let en = td!(Locale::en, exact_plural, count);
+    assert_eq_rendered!(en, "2 items");
+    let fr = td!(Locale::fr, exact_plural, count);
+    assert_eq_rendered!(fr, "2 éléments");
+
+    let en = td_string!(Locale::en, exact_plural, count = 0);
+    assert_eq!(en, "no items");
+    let fr = td_string!(Locale::fr, exact_plural, count = 0);
+    assert_eq!(fr, "aucun élément");
+}
+
+#[test]
+fn exact_ordinal_plural() {
+    // count = 1
+    let count = move || 1;
+    let en = td!(Locale::en, exact_ordinal, count);
+    assert_eq_rendered!(en, "first place");
+    let fr = td!(Locale::fr, exact_ordinal, count);
+    assert_eq_rendered!(fr, "premier");
+
+    // count = 21
+    let count = move || 21;
+    let en = td!(Locale::en, exact_ordinal, count);
+    assert_eq_rendered!(en, "21st place");
+    let fr = td!(Locale::fr, exact_ordinal, count);
+    assert_eq_rendered!(fr, "21e place");
+}
+
+#[test]
+fn count_arg_to_exact_plural() {
+    let en = td!(Locale::en, count_arg_to_exact_plural);
+    assert_eq_rendered!(en, "no items");
+    let fr = td!(Locale::fr, count_arg_to_exact_plural);
+    assert_eq_rendered!(fr, "aucun élément");
+}
+
+#[test]
+fn inline_plurals() {
+    let boys_count = move || 1;
+    let girls_count = move || 0;
+    let en = td!(Locale::en, inline_plurals, boys_count, girls_count);
+    assert_eq_rendered!(en, "1 boy and 0 girls");
+    let fr = td!(Locale::fr, inline_plurals, boys_count, girls_count);
+    assert_eq_rendered!(fr, "1 garçon et 0 fille");
+
+    let en = td_string!(Locale::en, inline_plurals, boys_count = 3, girls_count = 1);
+    assert_eq!(en.to_string(), "3 boys and 1 girl");
+    let fr = td_string!(Locale::fr, inline_plurals, boys_count = 3, girls_count = 2);
+    assert_eq!(fr.to_string(), "3 garçons et 2 filles");
+}
@@ -0,0 +1,50 @@
+use crate::i18n::*;
+use tests_common::*;
+
+#[test]
+fn select() {
+    let name = "Ferris";
+    let en = td!(Locale::en, invited, name, gender = Gender::Female);
+    assert_eq_rendered!(en, "Ferris invited you to her party");
+    let en = td!(Locale::en, invited, name, gender = Gender::Male);
+    assert_eq_rendered!(en, "Ferris invited you to his party");
+    let en = td!(Locale::en, invited, name, gender = Gender::Other);
+    assert_eq_rendered!(en, "Ferris invited you to their party");
+    let fr = td!(Locale::fr, invited, name, gender = Gender::Female);
+    assert_eq_rendered!(fr, "Ferris vous a invité à sa fête");
+}
+
+#[test]
+fn select_closure() {
+    let name = "Ferris";
+    let gender = move || "male";
+    let en = td!(Locale::en, invited, name, gender);
+    assert_eq_rendered!(en, "Ferris invited you to his party");
+    let gender = move || "unknown";
+    let en = td!(Locale::en, invited, name, gender);
+    assert_eq_rendered!(en, "Ferris invited you to their party");
+}
+
+#[test]
+fn select_string() {
+    let en = td_string!(Locale::en, invited, name = "Ferris", gender = "female");
+    assert_eq!(en.to_string(), "Ferris invited you to her party");
+    let en = td_string!(Locale::en, invited, name = "Ferris", gender = Gender::Male);
+    assert_eq!(en.to_string(), "Ferris invited you to his party");
+}
+
+#[test]
+fn select_foreign_key() {
+    let name = "Ferris";
+    let en = td!(Locale::en, invited_by_her, name);
+    assert_eq_rendered!(en, "Ferris invited you to her party");
+    let fr = td!(Locale::fr, invited_by_her, name);
+    assert_eq_rendered!(fr, "Ferris vous a invité à sa fête");
+}
+
+#[test]
+fn select_enum() {
+    assert_eq!(Gender::from("female"), Gender::Female);
+    assert_eq!(Gender::from("unknown"), Gender::Other);
+    assert_eq!(Gender::Male.as_str(), "male");
+}
@@ -2,6 +2,7 @@ use crate::i18n::*;
 use leptos_i18n::formatting::*;
 use leptos_i18n::reexports::fixed_decimal::FixedDecimal;
 use leptos_i18n::reexports::icu::calendar::{Date, DateTime, Time};
+use leptos_i18n::reexports::icu::timezone::CustomTimeZone;
 use tests_common::*;
 
 #[test]
@@ -48,6 +49,31 @@ fn datetime_formatting() {
     assert_eq_rendered!(fr, "2 janv. 1970, 14:34");
 }
 
+#[test]
+fn date_formatting_skeleton() {
+    let date = move || Date::try_new_iso_date(1970, 1, 2).unwrap().to_any();
+
+    let en = td_format!(Locale::en, date, formatter: date(skeleton: "yMMMd"));
+    assert_eq_rendered!(en, "Jan 2, 1970");
+    let fr = td_format!(Locale::fr, date, formatter: date(month: long; day: numeric));
+    assert_eq_rendered!(fr, "2 janvier");
+}
+
+#[test]
+fn zoned_datetime_formatting() {
+    let when = move || {
+        let date = Date::try_new_iso_date(1970, 1, 2).unwrap().to_any();
+        let time = Time::try_new(14, 34, 28, 0).unwrap();
+        let time_zone: CustomTimeZone = "-05:00".parse().unwrap();
+        (DateTime::new(date, time), time_zone)
+    };
+
+    let en = td_format!(Locale::en, when, formatter: zoned_datetime(skeleton: "HHmmO"));
+    assert_eq_rendered!(en, "14:34 GMT-05:00");
+    let fr = td_format!(Locale::fr, when, formatter: zoned_datetime(date_length: short; time_length: full));
+    assert_eq_rendered!(fr, "02/01/1970 14:34:28 UTC\u{2212}05:00");
+}
+
 #[test]
 fn number_formatting() {
     let num = move || FixedDecimal::from(200050).multiplied_pow10(-2);
@@ -64,3 +90,13 @@ fn number_formatting() {
     let fr = td_format!(Locale::fr, num, formatter: number);
     assert_eq_rendered!(fr, "2\u{202f}000,5");
 }
+
+#[test]
+fn number_formatting_precision() {
+    let num = move || 1.005f64;
+
+    let en = td_format!(Locale::en, num, formatter: number(maximum_fraction_digits: 2; rounding_mode: half_even));
+    assert_eq_rendered!(en, "1");
+    let fr = td_format!(Locale::fr, num, formatter: number(minimum_fraction_digits: 2; maximum_fraction_digits: 2; rounding_mode: ceil));
+    assert_eq_rendered!(fr, "1,01");
+}
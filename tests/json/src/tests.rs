@@ -103,3 +103,95 @@ fn mixed_lit_type() {
     let fr = td!(Locale::fr, mixed_lit_type);
     assert_eq_rendered!(fr, "true");
 }
+
+#[test]
+fn escaped() {
+    let b = |children: ChildrenFn| view! { <b>{move || children()}</b> };
+    let en = td!(Locale::en, escaped, count = 3, <b>);
+    assert_eq_rendered!(
+        en,
+        "{{ not_a_var }}, a &lt; b and $t(click_to_inc) with <b>3</b>"
+    );
+    let fr = td!(Locale::fr, escaped, count = 3, <b>);
+    assert_eq_rendered!(
+        fr,
+        "{{ pas_une_var }}, a &lt; b et $t(click_to_inc) avec <b>3</b>"
+    );
+}
+
+#[test]
+fn escaped_string() {
+    let en = td_string!(Locale::en, escaped, count = 3, <b> = "i");
+    assert_eq!(
+        en,
+        "{{ not_a_var }}, a < b and $t(click_to_inc) with <i>3</i>"
+    );
+    let fr = td_string!(Locale::fr, escaped, count = 3, <b> = "i");
+    assert_eq!(
+        fr,
+        "{{ pas_une_var }}, a < b et $t(click_to_inc) avec <i>3</i>"
+    );
+}
+
+#[test]
+fn self_closing() {
+    let br = || view! { <br /> };
+    let link = |children: ChildrenFn, attrs: &[(&'static str, &'static str)]| {
+        let href = attrs
+            .iter()
+            .find(|(name, _)| *name == "href")
+            .map(|(_, value)| *value);
+        view! { <a href=href>{move || children()}</a> }
+    };
+    let en = td!(Locale::en, self_closing, <br>, <link>);
+    assert_eq_rendered!(en, "line<br>break and <a href=\"/terms\">the terms</a>");
+    let fr = td!(Locale::fr, self_closing, <br/> = <hr/>, <link> = <a class="link" />);
+    assert_eq_rendered!(
+        fr,
+        "ligne<hr>suivante et <a href=\"/conditions\" class=\"link\">les conditions</a>"
+    );
+}
+
+#[test]
+fn attrs_on_component() {
+    // the attributes are given to the component, it decides where they go.
+    let br = || view! { <br /> };
+    let link = |children: ChildrenFn, attrs: &[(&'static str, &'static str)]| {
+        let title = attrs
+            .iter()
+            .map(|(name, value)| format!("{name}={value}"))
+            .collect::<String>();
+        view! { <span class="link"><a title=title>{move || children()}</a></span> }
+    };
+    let en = td!(Locale::en, self_closing, <br>, <link>);
+    assert_eq_rendered!(
+        en,
+        "line<br>break and <span class=\"link\"><a title=\"href=/terms\">the terms</a></span>"
+    );
+}
+
+#[test]
+fn quoted_attr_string() {
+    let en = td_string!(Locale::en, quoted_attr, <link> = "a");
+    assert_eq!(
+        en,
+        "<a title=\"say &quot;hi&quot; &amp; bye\">the terms</a>"
+    );
+    let fr = td_string!(Locale::fr, quoted_attr, <link> = "a");
+    assert_eq!(
+        fr,
+        "<a title=\"dire &quot;salut&quot; &amp; au revoir\">les conditions</a>"
+    );
+}
+
+#[test]
+fn self_closing_string() {
+    let en = td_string!(Locale::en, self_closing, <br> = "br", <link> = "a");
+    assert_eq!(en, "line<br />break and <a href=\"/terms\">the terms</a>");
+    let link = leptos_i18n::display::DisplayComp::new("a", &[("class", "link")]);
+    let fr = td_string!(Locale::fr, self_closing, <br> = "br", <link>);
+    assert_eq!(
+        fr,
+        "ligne<br />suivante et <a class=\"link\" href=\"/conditions\">les conditions</a>"
+    );
+}
@@ -0,0 +1,36 @@
+use crate::i18n::*;
+use tests_common::*;
+
+#[test]
+fn flattened_keys() {
+    let en = td!(Locale::en, flat.title);
+    assert_eq_rendered!(en, "Flat title");
+    let fr = td!(Locale::fr, flat.title);
+    assert_eq_rendered!(fr, "Titre aplati");
+
+    let en = td!(Locale::en, flat.nested.key);
+    assert_eq_rendered!(en, "Flat nested key");
+    let fr = td!(Locale::fr, flat.nested.key);
+    assert_eq_rendered!(fr, "Clé imbriquée aplatie");
+}
+
+#[test]
+fn flattened_key_merged_into_subkeys() {
+    let en = td!(Locale::en, subkeys.subkey_1);
+    assert_eq_rendered!(en, "subkey_1");
+    let fr = td!(Locale::fr, subkeys.subkey_1);
+    assert_eq_rendered!(fr, "sous-clé_1");
+
+    let en = td!(Locale::en, subkeys.subkey_2);
+    assert_eq_rendered!(en, "subkey_2");
+    let fr = td!(Locale::fr, subkeys.subkey_2);
+    assert_eq_rendered!(fr, "sous-clé_2");
+}
+
+#[test]
+fn foreign_key_to_flattened_key() {
+    let en = td!(Locale::en, foreign_key_to_flat_key);
+    assert_eq_rendered!(en, "before Flat nested key after");
+    let fr = td!(Locale::fr, foreign_key_to_flat_key);
+    assert_eq_rendered!(fr, "avant Clé imbriquée aplatie après");
+}
@@ -0,0 +1,5 @@
+#![deny(warnings)]
+leptos_i18n::load_locales!();
+
+#[cfg(test)]
+mod flattened;
@@ -0,0 +1,47 @@
+use crate::i18n::*;
+use tests_common::*;
+
+#[test]
+fn greeting() {
+    let name = || "John";
+    let en = td!(Locale::en, icu_namespace.greeting, name);
+    assert_eq_rendered!(en, "Hello John!");
+    let fr = td!(Locale::fr, icu_namespace.greeting, name);
+    assert_eq_rendered!(fr, "Bonjour John !");
+}
+
+#[test]
+fn click_count() {
+    let count = || 0;
+    let en = td!(Locale::en, icu_namespace.click_count, count);
+    assert_eq_rendered!(en, "You have not clicked yet");
+    let fr = td!(Locale::fr, icu_namespace.click_count, count);
+    assert_eq_rendered!(fr, "Vous n'avez pas encore cliqué");
+    let count = || 1;
+    let en = td!(Locale::en, icu_namespace.click_count, count);
+    assert_eq_rendered!(en, "You clicked once");
+    let fr = td!(Locale::fr, icu_namespace.click_count, count);
+    assert_eq_rendered!(fr, "Vous avez cliqué 1 fois");
+    let count = || 4;
+    let en = td!(Locale::en, icu_namespace.click_count, count);
+    assert_eq_rendered!(en, "You clicked 4 times");
+    let fr = td!(Locale::fr, icu_namespace.click_count, count);
+    assert_eq_rendered!(fr, "Vous avez cliqué 4 fois");
+}
+
+#[test]
+fn bold() {
+    let b = |children: ChildrenFn| view! { <b>{move || children()}</b> };
+    let en = td!(Locale::en, icu_namespace.bold, <b>);
+    assert_eq_rendered!(en, "Some <b>bold</b> text");
+    let fr = td!(Locale::fr, icu_namespace.bold, <b>);
+    assert_eq_rendered!(fr, "Du texte en <b>gras</b>");
+}
+
+#[test]
+fn quoted() {
+    let en = td!(Locale::en, icu_namespace.quoted);
+    assert_eq_rendered!(en, "It's {name}");
+    let fr = td!(Locale::fr, icu_namespace.quoted);
+    assert_eq_rendered!(fr, "C'est {name}");
+}
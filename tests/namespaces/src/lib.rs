@@ -4,6 +4,12 @@ leptos_i18n::load_locales!();
 #[cfg(test)]
 mod first_ns;
 #[cfg(test)]
+mod icu_ns;
+#[cfg(test)]
+mod nested_ns;
+#[cfg(test)]
 mod scoped;
 #[cfg(test)]
 mod second_ns;
+#[cfg(test)]
+mod yaml_ns;
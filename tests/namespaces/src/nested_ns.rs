@@ -0,0 +1,49 @@
+use crate::i18n::*;
+use tests_common::*;
+
+#[test]
+fn nested_namespace() {
+    let en = td!(Locale::en, admin.users.title);
+    assert_eq_rendered!(en, "Users");
+    let fr = td!(Locale::fr, admin.users.title);
+    assert_eq_rendered!(fr, "Utilisateurs");
+
+    let en = td!(Locale::en, admin.roles.title);
+    assert_eq_rendered!(en, "Roles");
+    let fr = td!(Locale::fr, admin.roles.title);
+    assert_eq_rendered!(fr, "Rôles");
+}
+
+#[test]
+fn nested_namespace_interpolation() {
+    let count = move || 3;
+    let en = td!(Locale::en, admin.users.user_count, count);
+    assert_eq_rendered!(en, "3 users");
+    let fr = td!(Locale::fr, admin.users.user_count, count);
+    assert_eq_rendered!(fr, "3 utilisateurs");
+}
+
+#[test]
+fn foreign_key_to_nested_namespace() {
+    let en = td!(Locale::en, admin.users.foreign_key_to_nested_namespace);
+    assert_eq_rendered!(en, "Roles of the users");
+    let fr = td!(Locale::fr, admin.users.foreign_key_to_nested_namespace);
+    assert_eq_rendered!(fr, "Rôles des utilisateurs");
+}
+
+#[test]
+fn scoped_nested_namespace() {
+    let en_scope = scope_locale!(Locale::en, admin.users);
+    let en = td!(en_scope, title);
+    assert_eq_rendered!(en, "Users");
+
+    let fr_scope = scope_locale!(Locale::fr, admin);
+    let fr = td!(fr_scope, roles.title);
+    assert_eq_rendered!(fr, "Rôles");
+}
+
+#[test]
+fn translation_units() {
+    assert_eq!(I18nTranslationUnitsId::admin__users.as_str(), "admin/users");
+    assert_eq!(I18nTranslationUnitsId::admin__roles.as_str(), "admin/roles");
+}
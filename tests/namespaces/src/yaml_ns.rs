@@ -0,0 +1,37 @@
+use crate::i18n::*;
+use tests_common::*;
+
+#[test]
+fn common_key() {
+    let en = td!(Locale::en, yaml_namespace.common_key);
+    assert_eq_rendered!(en, "yaml namespace");
+    let fr = td!(Locale::fr, yaml_namespace.common_key);
+    assert_eq_rendered!(fr, "namespace yaml");
+}
+
+#[test]
+fn click_count() {
+    for i in -5..=5 {
+        let count = move || i;
+        let en = td!(Locale::en, yaml_namespace.click_count, count);
+        assert_eq_rendered!(en, format!("You clicked {} times", i));
+        let fr = td!(Locale::fr, yaml_namespace.click_count, count);
+        assert_eq_rendered!(fr, format!("Vous avez cliqué {} fois", i));
+    }
+}
+
+#[test]
+fn subkeys() {
+    let en = td!(Locale::en, yaml_namespace.subkeys.subkey_1);
+    assert_eq_rendered!(en, "subkey_1");
+    let fr = td!(Locale::fr, yaml_namespace.subkeys.subkey_1);
+    assert_eq_rendered!(fr, "subkey_1");
+}
+
+#[test]
+fn foreign_key_to_json_namespace() {
+    let en = td!(Locale::en, yaml_namespace.foreign_key_to_json_namespace);
+    assert_eq_rendered!(en, "before first namespace after");
+    let fr = td!(Locale::fr, yaml_namespace.foreign_key_to_json_namespace);
+    assert_eq_rendered!(fr, "avant premier namespace après");
+}